@@ -0,0 +1,30 @@
+use anyhow::Result;
+use axum::async_trait;
+use tokio::sync::broadcast;
+
+use crate::domain::value_objects::domain_events::DomainEvent;
+
+use super::domain_events::DomainEventSubscriber;
+
+// every open board stream of this server listens on the same channel
+pub type BoardEventsSender = broadcast::Sender<DomainEvent>;
+
+pub struct BoardEventsBroadcaster {
+    board_events: BoardEventsSender,
+}
+
+impl BoardEventsBroadcaster {
+    pub fn new(board_events: BoardEventsSender) -> Self {
+        Self { board_events }
+    }
+}
+
+#[async_trait]
+impl DomainEventSubscriber for BoardEventsBroadcaster {
+    async fn handle(&self, _event_id: i32, domain_event: &DomainEvent) -> Result<()> {
+        // sending only fails when no stream is open, nobody missed anything then
+        let _ = self.board_events.send(domain_event.clone());
+
+        Ok(())
+    }
+}
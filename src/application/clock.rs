@@ -0,0 +1,16 @@
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+// use cases that depend on the current time take a clock, so tests can pin the time
+#[automock]
+pub trait Clock {
+    fn now(&self) -> NaiveDateTime;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        chrono::Utc::now().naive_utc()
+    }
+}
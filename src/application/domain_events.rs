@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+use tracing::{error, info};
+
+use crate::domain::value_objects::domain_events::DomainEvent;
+
+// the outbox dispatcher hands every stored event to a publisher, an error means it is retried;
+// event_id is the outbox row id and stays the same across retries, handled_by names the
+// subscribers that already handled it on an earlier attempt
+#[async_trait]
+#[automock]
+pub trait DomainEventPublisher {
+    async fn publish(
+        &self,
+        event_id: i32,
+        domain_event: DomainEvent,
+        handled_by: Vec<String>,
+    ) -> PublishOutcome;
+}
+
+// the subscribers that handled the event on this attempt are recorded by the dispatcher even
+// when another one failed, so the retry does not reach them again
+pub struct PublishOutcome {
+    pub handled_by: Vec<String>,
+    pub result: Result<()>,
+}
+
+#[async_trait]
+#[automock]
+pub trait DomainEventSubscriber {
+    async fn handle(&self, event_id: i32, domain_event: &DomainEvent) -> Result<()>;
+}
+
+// in-process bus, every subscriber sees each event once and the publish only succeeds when all
+// of them handled it; the name a subscriber is registered under is what the dispatcher records,
+// so it has to stay the same across releases
+#[derive(Default)]
+pub struct DomainEventBus {
+    subscribers: Vec<(&'static str, Arc<dyn DomainEventSubscriber + Send + Sync>)>,
+}
+
+impl DomainEventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subscribe(
+        mut self,
+        name: &'static str,
+        subscriber: Arc<dyn DomainEventSubscriber + Send + Sync>,
+    ) -> Self {
+        self.subscribers.push((name, subscriber));
+        self
+    }
+}
+
+#[async_trait]
+impl DomainEventPublisher for DomainEventBus {
+    async fn publish(
+        &self,
+        event_id: i32,
+        domain_event: DomainEvent,
+        handled_by: Vec<String>,
+    ) -> PublishOutcome {
+        let mut handled_now: Vec<String> = Vec::new();
+        let mut failures = 0;
+
+        for (name, subscriber) in self.subscribers.iter() {
+            if handled_by.iter().any(|handled| handled == name) {
+                continue;
+            }
+
+            match subscriber.handle(event_id, &domain_event).await {
+                Ok(()) => handled_now.push(name.to_string()),
+                Err(e) => {
+                    error!(
+                        "🔴 {} failed to handle {} event: {}",
+                        name,
+                        domain_event.event_type(),
+                        e
+                    );
+                    failures += 1;
+                }
+            }
+        }
+
+        let result = if failures > 0 {
+            Err(anyhow::anyhow!(
+                "{} of {} subscribers failed to handle {} event",
+                failures,
+                self.subscribers.len(),
+                domain_event.event_type()
+            ))
+        } else {
+            Ok(())
+        };
+
+        PublishOutcome {
+            handled_by: handled_now,
+            result,
+        }
+    }
+}
+
+pub struct DomainEventLogger;
+
+#[async_trait]
+impl DomainEventSubscriber for DomainEventLogger {
+    async fn handle(&self, event_id: i32, domain_event: &DomainEvent) -> Result<()> {
+        info!("Domain event {}: {:?}", event_id, domain_event);
+
+        Ok(())
+    }
+}
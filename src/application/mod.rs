@@ -1 +1,6 @@
-pub mod usecases;
\ No newline at end of file
+pub mod board_events;
+pub mod clock;
+pub mod domain_events;
+pub mod usecases;
+pub mod webhook_host_resolver;
+pub mod webhook_sender;
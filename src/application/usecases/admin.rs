@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use serde_json::json;
+
+use crate::{
+    domain::{
+        repositories::{admin::AdminRepository, outbox::OutboxRepository, users::UsersRepository},
+        value_objects::{
+            admin_audit_actions::{AdminAuditActions, AdminAuditTargets},
+            admin_model::{
+                to_audit_log_entity, AdminAuditLogFilter, AdminAuditLogModel, AdminUserModel,
+                ForceQuestStatusModel, UserSearchFilter, FORCEABLE_QUEST_STATUSES,
+            },
+            outbox_model::OutboxBacklogModel,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::jwt_authentication::jwt_model::Roles,
+};
+
+pub struct AdminUseCase<T1, T2, T3>
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    admin_repository: Arc<T1>,
+    users_repository: Arc<T2>,
+    outbox_repository: Arc<T3>,
+}
+
+impl<T1, T2, T3> AdminUseCase<T1, T2, T3>
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    pub fn new(
+        admin_repository: Arc<T1>,
+        users_repository: Arc<T2>,
+        outbox_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            admin_repository,
+            users_repository,
+            outbox_repository,
+        }
+    }
+
+    pub async fn users(&self, filter: &UserSearchFilter) -> Result<Vec<AdminUserModel>> {
+        let users = self.admin_repository.search_users(filter).await?;
+
+        let roles = self
+            .admin_repository
+            .roles_by_user_ids(users.iter().map(|user| user.id).collect())
+            .await?;
+
+        users
+            .into_iter()
+            .map(|user| {
+                let user_roles = roles
+                    .iter()
+                    .filter(|user_role| user_role.user_id == user.id)
+                    .map(|user_role| Roles::try_from_str(&user_role.role))
+                    .collect::<Result<Vec<Roles>>>()?;
+
+                Ok(AdminUserModel {
+                    id: user.id,
+                    username: user.username,
+                    roles: user_roles,
+                    disabled_at: user.disabled_at,
+                    created_at: user.created_at,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn disable_user(&self, admin_user_id: i32, user_id: i32) -> Result<()> {
+        if admin_user_id == user_id {
+            return Err(anyhow::anyhow!("Admins cannot disable their own account"));
+        }
+
+        let user = self.users_repository.find_by_id(user_id).await?;
+
+        if user.disabled_at.is_some() {
+            return Err(anyhow::anyhow!("The user is already disabled"));
+        }
+
+        self.admin_repository
+            .set_user_disabled_at(
+                user_id,
+                Some(chrono::Utc::now().naive_utc()),
+                to_audit_log_entity(
+                    admin_user_id,
+                    AdminAuditActions::DisableUser,
+                    AdminAuditTargets::User,
+                    user_id,
+                    json!({ "username": user.username }),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn enable_user(&self, admin_user_id: i32, user_id: i32) -> Result<()> {
+        let user = self.users_repository.find_by_id(user_id).await?;
+
+        let Some(disabled_at) = user.disabled_at else {
+            return Err(anyhow::anyhow!("The user is not disabled"));
+        };
+
+        self.admin_repository
+            .set_user_disabled_at(
+                user_id,
+                None,
+                to_audit_log_entity(
+                    admin_user_id,
+                    AdminAuditActions::EnableUser,
+                    AdminAuditTargets::User,
+                    user_id,
+                    json!({ "username": user.username, "disabled_at": disabled_at }),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // bypasses the quest lifecycle for a stuck quest, the open attempt is closed as Failed and
+    // no rewards or experience change hands
+    pub async fn force_quest_status(
+        &self,
+        admin_user_id: i32,
+        quest_id: i32,
+        force_quest_status_model: ForceQuestStatusModel,
+    ) -> Result<()> {
+        let (quest, deleted_at) = self.admin_repository.find_quest(quest_id).await?;
+
+        if deleted_at.is_some() {
+            return Err(anyhow::anyhow!(
+                "Restore the quest before changing its status"
+            ));
+        }
+
+        if quest.status == QuestStatuses::Completed.to_string() {
+            return Err(anyhow::anyhow!(
+                "The crew of a Completed quest has been paid, its status cannot be forced"
+            ));
+        }
+
+        if !FORCEABLE_QUEST_STATUSES.contains(&force_quest_status_model.status) {
+            return Err(anyhow::anyhow!(
+                "A quest can only be forced to Open or Failed"
+            ));
+        }
+
+        let status = force_quest_status_model.status.to_string();
+
+        if quest.status == status {
+            return Err(anyhow::anyhow!("The quest is already {}", status));
+        }
+
+        self.admin_repository
+            .force_quest_status(
+                quest_id,
+                status.clone(),
+                to_audit_log_entity(
+                    admin_user_id,
+                    AdminAuditActions::ForceQuestStatus,
+                    AdminAuditTargets::Quest,
+                    quest_id,
+                    json!({
+                        "from": quest.status,
+                        "to": status,
+                        "reason": force_quest_status_model.reason,
+                    }),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn restore_quest(&self, admin_user_id: i32, quest_id: i32) -> Result<()> {
+        let (_, deleted_at) = self.admin_repository.find_quest(quest_id).await?;
+
+        let Some(deleted_at) = deleted_at else {
+            return Err(anyhow::anyhow!("The quest is not removed"));
+        };
+
+        self.admin_repository
+            .restore_quest(
+                quest_id,
+                to_audit_log_entity(
+                    admin_user_id,
+                    AdminAuditActions::RestoreQuest,
+                    AdminAuditTargets::Quest,
+                    quest_id,
+                    json!({ "deleted_at": deleted_at }),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn audit_logs(
+        &self,
+        filter: &AdminAuditLogFilter,
+    ) -> Result<Vec<AdminAuditLogModel>> {
+        let results = self.admin_repository.audit_logs(filter).await?;
+
+        Ok(results
+            .iter()
+            .map(|audit_log| audit_log.to_model())
+            .collect())
+    }
+
+    // events written but not yet delivered, a growing count or age means the dispatcher lags
+    pub async fn outbox_backlog(&self) -> Result<OutboxBacklogModel> {
+        let backlog = self.outbox_repository.backlog().await?;
+
+        Ok(backlog.to_model(chrono::Utc::now().naive_utc()))
+    }
+}
@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+
+    use serde_json::json;
+
+    use crate::{
+        application::usecases::{admin::AdminUseCase, tests::common::quest_entity},
+        domain::{
+            entities::quests::QuestEntity,
+            repositories::{
+                admin::MockAdminRepository, outbox::MockOutboxRepository,
+                users::MockUsersRepository,
+            },
+            value_objects::{
+                admin_audit_actions::{AdminAuditActions, AdminAuditTargets},
+                admin_model::ForceQuestStatusModel,
+                quest_statuses::QuestStatuses,
+            },
+        },
+    };
+
+    fn quest(id: i32, status: QuestStatuses) -> QuestEntity {
+        QuestEntity {
+            status: status.to_string(),
+            ..quest_entity(id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_disable_user_fails_for_own_account() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mut mock_users_repo = MockUsersRepository::new();
+
+        mock_users_repo.expect_find_by_id().times(0);
+        mock_admin_repo.expect_set_user_disabled_at().times(0);
+
+        let use_case = AdminUseCase::new(
+            Arc::new(mock_admin_repo),
+            Arc::new(mock_users_repo),
+            Arc::new(MockOutboxRepository::new()),
+        );
+
+        let result = use_case.disable_user(1, 1).await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Admins cannot disable their own account"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_force_quest_status_writes_audit_log() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mock_users_repo = MockUsersRepository::new();
+
+        mock_admin_repo.expect_find_quest().returning(|quest_id| {
+            Box::pin(async move { Ok((quest(quest_id, QuestStatuses::InJourney), None)) })
+        });
+
+        mock_admin_repo
+            .expect_force_quest_status()
+            .withf(|quest_id, status, add_admin_audit_log_entity| {
+                *quest_id == 7
+                    && *status == QuestStatuses::Failed.to_string()
+                    && add_admin_audit_log_entity.admin_user_id == 1
+                    && add_admin_audit_log_entity.action
+                        == AdminAuditActions::ForceQuestStatus.to_string()
+                    && add_admin_audit_log_entity.target_type
+                        == AdminAuditTargets::Quest.to_string()
+                    && add_admin_audit_log_entity.target_id == 7
+                    && add_admin_audit_log_entity.details
+                        == json!({
+                            "from": QuestStatuses::InJourney.to_string(),
+                            "to": QuestStatuses::Failed.to_string(),
+                            "reason": "stuck in journey",
+                        })
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = AdminUseCase::new(
+            Arc::new(mock_admin_repo),
+            Arc::new(mock_users_repo),
+            Arc::new(MockOutboxRepository::new()),
+        );
+
+        let result = use_case
+            .force_quest_status(
+                1,
+                7,
+                ForceQuestStatusModel {
+                    status: QuestStatuses::Failed,
+                    reason: Some("stuck in journey".to_string()),
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_force_quest_status_fails_for_completed() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mock_users_repo = MockUsersRepository::new();
+
+        mock_admin_repo.expect_find_quest().returning(|quest_id| {
+            Box::pin(async move { Ok((quest(quest_id, QuestStatuses::InJourney), None)) })
+        });
+        mock_admin_repo.expect_force_quest_status().times(0);
+
+        let use_case = AdminUseCase::new(
+            Arc::new(mock_admin_repo),
+            Arc::new(mock_users_repo),
+            Arc::new(MockOutboxRepository::new()),
+        );
+
+        let result = use_case
+            .force_quest_status(
+                1,
+                7,
+                ForceQuestStatusModel {
+                    status: QuestStatuses::Completed,
+                    reason: None,
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "A quest can only be forced to Open or Failed"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_force_quest_status_fails_when_completed() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mock_users_repo = MockUsersRepository::new();
+
+        mock_admin_repo.expect_find_quest().returning(|quest_id| {
+            Box::pin(async move { Ok((quest(quest_id, QuestStatuses::Completed), None)) })
+        });
+        mock_admin_repo.expect_force_quest_status().times(0);
+
+        let use_case = AdminUseCase::new(
+            Arc::new(mock_admin_repo),
+            Arc::new(mock_users_repo),
+            Arc::new(MockOutboxRepository::new()),
+        );
+
+        let result = use_case
+            .force_quest_status(
+                1,
+                7,
+                ForceQuestStatusModel {
+                    status: QuestStatuses::Open,
+                    reason: None,
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The crew of a Completed quest has been paid, its status cannot be forced"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restore_quest_fails_when_not_removed() {
+        let mut mock_admin_repo = MockAdminRepository::new();
+        let mock_users_repo = MockUsersRepository::new();
+
+        mock_admin_repo.expect_find_quest().returning(|quest_id| {
+            Box::pin(async move { Ok((quest(quest_id, QuestStatuses::Open), None)) })
+        });
+        mock_admin_repo.expect_restore_quest().times(0);
+
+        let use_case = AdminUseCase::new(
+            Arc::new(mock_admin_repo),
+            Arc::new(mock_users_repo),
+            Arc::new(MockOutboxRepository::new()),
+        );
+
+        let result = use_case.restore_quest(1, 7).await;
+
+        assert_eq!(result.unwrap_err().to_string(), "The quest is not removed");
+    }
+}
@@ -2,12 +2,9 @@ use std::sync::Arc;
 
 use anyhow::{Ok, Result};
 
-use crate::{
-    domain::{
-        repositories::adventurers::AdventurersRepository,
-        value_objects::adventurer_model::RegisterAdventurerModel,
-    },
-    infrastructure::argon2_hashing,
+use crate::domain::{
+    repositories::adventurers::AdventurersRepository,
+    value_objects::adventurer_model::AdventurerProfileModel,
 };
 
 // NOTE - we cannot pass directly on trait but we can solve dynamic dispatch - Box<dyn AdventurersRepository> or dyn AdventurersRepository but it will runtime allocate resource, if you want to performance static we will using Generic
@@ -40,22 +37,12 @@ where
         }
     }
 
-    pub async fn register(
-        &self,
-        mut register_adventurer_model: RegisterAdventurerModel,
-    ) -> Result<i32> {
-        let hashed_password = argon2_hashing::hash(register_adventurer_model.password.clone())?;
-
-        let password_new = hashed_password;
-        register_adventurer_model.password = password_new;
-
-        let register_entity = register_adventurer_model.to_entity();
-
-        let adventurer_id = self
+    pub async fn profile(&self, adventurer_id: i32) -> Result<AdventurerProfileModel> {
+        let adventurer = self
             .adventurers_repository
-            .register(register_entity)
+            .find_by_id(adventurer_id)
             .await?;
 
-        Ok(adventurer_id)
+        Ok(adventurer.to_profile_model())
     }
 }
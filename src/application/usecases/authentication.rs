@@ -1,10 +1,8 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use crate::{
-    config::config_loader::{get_adventurer_secret_env, get_guild_commanders_secret_env},
-    domain::repositories::{
-        adventurers::AdventurersRepository, guild_commanders::GuildCommandersRepository,
-    },
+    config::config_loader::get_user_secret_env,
+    domain::{entities::users::UserEntity, repositories::users::UsersRepository},
     infrastructure::{
         argon2_hashing,
         jwt_authentication::{
@@ -17,87 +15,70 @@ use crate::{
 use anyhow::{Ok, Result};
 use chrono::{Duration, Utc};
 
-pub struct AuthenticationUseCase<T1, T2>
+pub struct AuthenticationUseCase<T>
 where
-    T1: AdventurersRepository + Send + Sync,
-    T2: GuildCommandersRepository + Send + Sync,
+    T: UsersRepository + Send + Sync,
 {
-    adventurers_repository: Arc<T1>,
-    guild_commanders_repository: Arc<T2>,
+    users_repository: Arc<T>,
 }
 
-impl<T1, T2> AuthenticationUseCase<T1, T2>
+impl<T> AuthenticationUseCase<T>
 where
-    T1: AdventurersRepository + Send + Sync,
-    T2: GuildCommandersRepository + Send + Sync,
+    T: UsersRepository + Send + Sync,
 {
-    pub fn new(adventurers_repository: Arc<T1>, guild_commanders_repository: Arc<T2>) -> Self {
-        Self {
-            adventurers_repository,
-            guild_commanders_repository,
-        }
+    pub fn new(users_repository: Arc<T>) -> Self {
+        Self { users_repository }
     }
 
-    pub async fn adventurers_login(&self, login_model: LoginModel) -> Result<Passport> {
-        let secret_env = get_adventurer_secret_env()?;
-
-        let adventurer = self
-            .adventurers_repository
+    pub async fn login(&self, login_model: LoginModel) -> Result<Passport> {
+        let user = self
+            .users_repository
             .find_by_username(login_model.username.clone())
             .await?;
 
-        let original_password = adventurer.password;
-        let login_password = login_model.password;
-
-        if !argon2_hashing::verify(login_password, original_password)? {
-            return Err(anyhow::anyhow!("Invalid password"));
-        }
-
-        let access_token_claims = Claims {
-            sub: adventurer.id.to_string(),
-            role: Roles::Adventurer,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        self.passport_for(user, login_model.password).await
+    }
 
-        let refresh_token_claims = Claims {
-            sub: adventurer.id.to_string(),
-            role: Roles::Adventurer,
-            exp: (Utc::now() + Duration::days(7)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+    // the old per-role logins look the account up by the username kept on the profile
+    pub async fn adventurers_login(&self, login_model: LoginModel) -> Result<Passport> {
+        let user = self
+            .users_repository
+            .find_by_adventurer_username(login_model.username.clone())
+            .await?;
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.secret, &access_token_claims)?;
+        self.passport_for(user, login_model.password).await
+    }
 
-        let refresh_token =
-            jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
+    pub async fn guild_commanders_login(&self, login_model: LoginModel) -> Result<Passport> {
+        let user = self
+            .users_repository
+            .find_by_guild_commander_username(login_model.username.clone())
+            .await?;
 
-        Ok(Passport {
-            access_token,
-            refresh_token,
-        })
+        self.passport_for(user, login_model.password).await
     }
 
-    pub async fn adventurers_refresh_token(&self, refresh_token: String) -> Result<Passport> {
-        let secret_env = get_adventurer_secret_env()?;
+    pub async fn refresh_token(&self, refresh_token: String) -> Result<Passport> {
+        let secret_env = get_user_secret_env()?;
 
         let claims =
             jwt_authentication::verify_token(secret_env.refresh_secret.clone(), refresh_token)?;
 
-        let access_token_claims = Claims {
-            sub: claims.sub.clone(),
-            role: Roles::Adventurer,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        let user_id = claims.sub.parse::<i32>()?;
 
-        let refresh_token_claims = Claims {
-            sub: claims.sub,
-            role: Roles::Adventurer,
-            exp: claims.exp,
-            iat: Utc::now().timestamp() as usize,
-        };
+        let user = self.users_repository.find_by_id(user_id).await?;
+
+        check_user_active(&user)?;
+
+        // roles are read again so a role added since the last login shows up
+        let access_token_claims = self
+            .claims(
+                user_id,
+                (Utc::now() + Duration::days(1)).timestamp() as usize,
+            )
+            .await?;
+
+        let refresh_token_claims = self.claims(user_id, claims.exp).await?;
 
         let access_token =
             jwt_authentication::generate_token(secret_env.secret, &access_token_claims)?;
@@ -111,34 +92,40 @@ where
         })
     }
 
-    pub async fn guild_commanders_login(&self, login_model: LoginModel) -> Result<Passport> {
-        let secret_env = get_guild_commanders_secret_env()?;
+    async fn passport_for(&self, mut user: UserEntity, login_password: String) -> Result<Passport> {
+        // a deleted account has no password hash left to verify against
+        if user.deleted_at.is_some() {
+            return Err(anyhow::anyhow!("This account has been deleted"));
+        }
 
-        let guild_commander = self
-            .guild_commanders_repository
-            .find_by_username(login_model.username.clone())
-            .await?;
+        if !argon2_hashing::verify(login_password, user.password.clone())? {
+            return Err(anyhow::anyhow!("Invalid password"));
+        }
 
-        let original_password = guild_commander.password;
-        let login_password = login_model.password;
+        // logging in again is how a deactivated account comes back
+        if user.deactivated_at.is_some() && user.disabled_at.is_none() {
+            self.users_repository.reactivate(user.id).await?;
 
-        if !argon2_hashing::verify(login_password, original_password)? {
-            return Err(anyhow::anyhow!("Invalid password"));
+            user.deactivated_at = None;
         }
 
-        let access_token_claims = Claims {
-            sub: guild_commander.id.to_string(),
-            role: Roles::GuildCommander,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        check_user_active(&user)?;
 
-        let refresh_token_claims = Claims {
-            sub: guild_commander.id.to_string(),
-            role: Roles::GuildCommander,
-            exp: (Utc::now() + Duration::days(7)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        let secret_env = get_user_secret_env()?;
+
+        let access_token_claims = self
+            .claims(
+                user.id,
+                (Utc::now() + Duration::days(1)).timestamp() as usize,
+            )
+            .await?;
+
+        let refresh_token_claims = self
+            .claims(
+                user.id,
+                (Utc::now() + Duration::days(7)).timestamp() as usize,
+            )
+            .await?;
 
         let access_token =
             jwt_authentication::generate_token(secret_env.secret, &access_token_claims)?;
@@ -152,35 +139,38 @@ where
         })
     }
 
-    pub async fn guild_commanders_refresh_token(&self, refresh_token: String) -> Result<Passport> {
-        let secret_env = get_guild_commanders_secret_env()?;
-
-        let claims =
-            jwt_authentication::verify_token(secret_env.refresh_secret.clone(), refresh_token)?;
-
-        let access_token_claims = Claims {
-            sub: claims.sub.clone(),
-            role: Roles::GuildCommander,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
+    async fn claims(&self, user_id: i32, exp: usize) -> Result<Claims> {
+        let roles = self
+            .users_repository
+            .roles(user_id)
+            .await?
+            .iter()
+            .map(|user_role| Roles::try_from_str(&user_role.role))
+            .collect::<Result<HashSet<Roles>>>()?;
+
+        Ok(Claims {
+            sub: user_id.to_string(),
+            roles,
+            adventurer_id: self.users_repository.adventurer_id(user_id).await?,
+            guild_commander_id: self.users_repository.guild_commander_id(user_id).await?,
+            exp,
             iat: Utc::now().timestamp() as usize,
-        };
-
-        let refresh_token_claims = Claims {
-            sub: claims.sub,
-            role: Roles::GuildCommander,
-            exp: claims.exp,
-            iat: Utc::now().timestamp() as usize,
-        };
+        })
+    }
+}
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.secret, &access_token_claims)?;
+pub fn check_user_active(user: &UserEntity) -> Result<()> {
+    if user.deleted_at.is_some() {
+        return Err(anyhow::anyhow!("This account has been deleted"));
+    }
 
-        let refresh_token =
-            jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
+    if user.disabled_at.is_some() {
+        return Err(anyhow::anyhow!("This account has been disabled"));
+    }
 
-        Ok(Passport {
-            access_token,
-            refresh_token,
-        })
+    if user.deactivated_at.is_some() {
+        return Err(anyhow::anyhow!("This account has been deactivated"));
     }
+
+    Ok(())
 }
@@ -0,0 +1,142 @@
+use std::{collections::HashSet, sync::Arc};
+
+use anyhow::{Ok, Result};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::{
+    application::{board_events::BoardEventsSender, usecases::quest_viewing::joinable_by},
+    domain::{
+        repositories::quest_viewing::QuestViewingRepository,
+        value_objects::{
+            board_checking_filter::BoardCheckingFilter,
+            board_event_model::{BoardEventModel, BoardStreamMessage},
+            domain_events::DomainEvent,
+            guild_membership_model::GuildMember,
+        },
+    },
+};
+
+// one open stream, it remembers which quests are on the caller's board so it can tell
+// when a change takes a quest off the board
+pub struct BoardSubscription {
+    filter: BoardCheckingFilter,
+    member: GuildMember,
+    joinable_by: Option<i32>,
+    on_board: HashSet<i32>,
+    receiver: broadcast::Receiver<DomainEvent>,
+}
+
+pub struct BoardStreamingUseCase<T>
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    quest_viewing_repository: Arc<T>,
+    board_events: BoardEventsSender,
+}
+
+impl<T> BoardStreamingUseCase<T>
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(quest_viewing_repository: Arc<T>, board_events: BoardEventsSender) -> Self {
+        Self {
+            quest_viewing_repository,
+            board_events,
+        }
+    }
+
+    pub async fn subscribe(
+        &self,
+        filter: BoardCheckingFilter,
+        member: GuildMember,
+    ) -> Result<BoardSubscription> {
+        let joinable_by = joinable_by(&filter, member)?;
+
+        // listen before reading the board so nothing that happens in between is lost
+        let receiver = self.board_events.subscribe();
+
+        let on_board = self
+            .quest_viewing_repository
+            .board_quest_ids(&filter, member, joinable_by, None)
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(BoardSubscription {
+            filter,
+            member,
+            joinable_by,
+            on_board,
+            receiver,
+        })
+    }
+
+    // waits for the next change to the caller's board, None once no more events can come
+    pub async fn next(
+        &self,
+        subscription: &mut BoardSubscription,
+    ) -> Result<Option<BoardStreamMessage>> {
+        loop {
+            let domain_event = match subscription.receiver.recv().await {
+                std::result::Result::Ok(domain_event) => domain_event,
+                Err(RecvError::Lagged(skipped)) => {
+                    subscription.on_board = self
+                        .quest_viewing_repository
+                        .board_quest_ids(
+                            &subscription.filter,
+                            subscription.member,
+                            subscription.joinable_by,
+                            None,
+                        )
+                        .await?
+                        .into_iter()
+                        .collect();
+
+                    return Ok(Some(BoardStreamMessage::Lagged(skipped)));
+                }
+                Err(RecvError::Closed) => return Ok(None),
+            };
+
+            if let Some(board_event_model) = self.board_event(subscription, domain_event).await? {
+                return Ok(Some(BoardStreamMessage::Event(board_event_model)));
+            }
+        }
+    }
+
+    async fn board_event(
+        &self,
+        subscription: &mut BoardSubscription,
+        domain_event: DomainEvent,
+    ) -> Result<Option<BoardEventModel>> {
+        let quest_id = domain_event.quest_id();
+
+        // the quest is looked up as it is now, the same way the board would list it
+        let on_board = match domain_event {
+            DomainEvent::QuestRemoved { .. } => false,
+            _ => !self
+                .quest_viewing_repository
+                .board_quest_ids(
+                    &subscription.filter,
+                    subscription.member,
+                    subscription.joinable_by,
+                    Some(quest_id),
+                )
+                .await?
+                .is_empty(),
+        };
+
+        // changes to quests that never were on the board are none of the caller's business
+        let was_on_board = subscription.on_board.remove(&quest_id);
+
+        if on_board {
+            subscription.on_board.insert(quest_id);
+        } else if !was_on_board {
+            return Ok(None);
+        }
+
+        Ok(Some(BoardEventModel {
+            domain_event,
+            on_board,
+        }))
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use tokio::sync::broadcast;
+
+    use crate::{
+        application::usecases::board_streaming::BoardStreamingUseCase,
+        domain::{
+            repositories::quest_viewing::MockQuestViewingRepository,
+            value_objects::{
+                board_checking_filter::BoardCheckingFilter,
+                board_event_model::{BoardEventModel, BoardStreamMessage},
+                domain_events::DomainEvent,
+                guild_membership_model::GuildMember,
+            },
+        },
+    };
+
+    fn board_filter(joinable: Option<bool>) -> BoardCheckingFilter {
+        BoardCheckingFilter {
+            q: None,
+            name: None,
+            status: None,
+            statuses: None,
+            guild_commander_id: None,
+            created_from: None,
+            created_to: None,
+            updated_from: None,
+            updated_to: None,
+            joinable,
+            min_rank: None,
+            max_rank: None,
+            tags: None,
+            tags_match: None,
+        }
+    }
+
+    fn quest_edited(quest_id: i32) -> DomainEvent {
+        DomainEvent::QuestEdited {
+            quest_id,
+            guild_id: 1,
+            guild_commander_id: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_joinable_fails_for_guild_commander() {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo.expect_board_quest_ids().never();
+
+        let (board_events, _) = broadcast::channel(8);
+
+        let use_case = BoardStreamingUseCase::new(Arc::new(mock_quest_viewing_repo), board_events);
+
+        let result = use_case
+            .subscribe(board_filter(Some(true)), GuildMember::GuildCommander(7))
+            .await;
+
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Sign in as an adventurer to see the joinable quests"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_pushes_quests_entering_and_leaving_the_board() {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        // quest 1 is on the board when the stream opens, quest 3 matches the filter later on
+        mock_quest_viewing_repo
+            .expect_board_quest_ids()
+            .returning(|_, _, _, quest_id| {
+                Box::pin(async move {
+                    Ok(match quest_id {
+                        None => vec![1],
+                        Some(3) => vec![3],
+                        Some(_) => vec![],
+                    })
+                })
+            });
+
+        let (board_events, _) = broadcast::channel(8);
+
+        let use_case =
+            BoardStreamingUseCase::new(Arc::new(mock_quest_viewing_repo), board_events.clone());
+
+        let mut subscription = use_case
+            .subscribe(board_filter(None), GuildMember::Adventurer(7))
+            .await
+            .unwrap();
+
+        board_events.send(quest_edited(2)).unwrap();
+        board_events.send(quest_edited(1)).unwrap();
+        board_events.send(quest_edited(3)).unwrap();
+        board_events
+            .send(DomainEvent::QuestRemoved {
+                quest_id: 3,
+                guild_id: 1,
+                guild_commander_id: 1,
+            })
+            .unwrap();
+
+        assert_eq!(
+            use_case.next(&mut subscription).await.unwrap(),
+            Some(BoardStreamMessage::Event(BoardEventModel {
+                domain_event: quest_edited(1),
+                on_board: false,
+            }))
+        );
+        assert_eq!(
+            use_case.next(&mut subscription).await.unwrap(),
+            Some(BoardStreamMessage::Event(BoardEventModel {
+                domain_event: quest_edited(3),
+                on_board: true,
+            }))
+        );
+        assert_eq!(
+            use_case.next(&mut subscription).await.unwrap(),
+            Some(BoardStreamMessage::Event(BoardEventModel {
+                domain_event: DomainEvent::QuestRemoved {
+                    quest_id: 3,
+                    guild_id: 1,
+                    guild_commander_id: 1,
+                },
+                on_board: false,
+            }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_reports_skipped_events_when_lagging() {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_board_quest_ids()
+            .withf(|_, _, _, quest_id| quest_id.is_none())
+            .times(2)
+            .returning(|_, _, _, _| Box::pin(async { Ok(vec![1]) }));
+
+        let (board_events, _) = broadcast::channel(1);
+
+        let use_case =
+            BoardStreamingUseCase::new(Arc::new(mock_quest_viewing_repo), board_events.clone());
+
+        let mut subscription = use_case
+            .subscribe(board_filter(None), GuildMember::Adventurer(7))
+            .await
+            .unwrap();
+
+        board_events.send(quest_edited(1)).unwrap();
+        board_events.send(quest_edited(2)).unwrap();
+
+        assert_eq!(
+            use_case.next(&mut subscription).await.unwrap(),
+            Some(BoardStreamMessage::Lagged(1))
+        );
+    }
+}
@@ -3,12 +3,29 @@ use std::sync::Arc;
 use anyhow::{Ok, Result};
 
 use crate::domain::{
+    entities::{
+        quest_applications::{AddQuestApplicationEntity, QuestApplicationEntity},
+        quest_invitations::{AddQuestInvitationEntity, QuestInvitationEntity},
+        quest_waitlist_entries::AddQuestWaitlistEntryEntity,
+        quests::QuestEntity,
+    },
     repositories::{
         crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
     },
     value_objects::{
-        quest_adventurer_junction::{QuestAdventurerJunction, MAX_ADVENTURERS_PER_QUEST},
-        quest_statuses::QuestStatuses,
+        crew_member_model::AssignCrewRoleModel,
+        crew_roles::{CrewRoles, MAX_CREW_ROLE_LENGTH},
+        domain_events::DomainEvent,
+        quest_adventurer_junction::QuestAdventurerJunction,
+        quest_application_model::QuestApplicationModel,
+        quest_application_statuses::QuestApplicationStatuses,
+        quest_invitation_model::QuestInvitationModel,
+        quest_invitation_statuses::QuestInvitationStatuses,
+        quest_join_outcomes::QuestJoinOutcomes,
+        quest_join_policies::QuestJoinPolicies,
+        quest_rewards::SetRewardWeightModel,
+        quest_statuses::{prerequisites_completed, QuestStatuses, JOINABLE_QUEST_STATUSES},
+        quest_waitlist_model::QuestWaitlistPositionModel,
     },
 };
 
@@ -33,55 +50,517 @@ where
         }
     }
 
-    pub async fn join(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+    pub async fn join(&self, quest_id: i32, adventurer_id: i32) -> Result<QuestJoinOutcomes> {
         let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
+        self.check_adventurer_level(&quest, adventurer_id).await?;
+
+        // under approval the commander decides, so a full crew does not stop the application yet
+        if quest.join_policy == QuestJoinPolicies::ApprovalRequired.to_string() {
+            if !quest_status_can_join(&quest) {
+                return Err(anyhow::anyhow!("The quest not joinable"));
+            }
+
+            self.check_quest_unlocked(&quest).await?;
+
+            let now = chrono::Utc::now().naive_utc();
+
+            let application_id = self
+                .crew_switchboard_repository
+                .apply(AddQuestApplicationEntity {
+                    quest_id,
+                    adventurer_id,
+                    status: QuestApplicationStatuses::Pending.to_string(),
+                    created_at: now,
+                    updated_at: now,
+                })
+                .await?;
+
+            return Ok(QuestJoinOutcomes::Applied { application_id });
+        }
+
+        if !quest_status_can_join(&quest) {
+            return Err(anyhow::anyhow!("The quest not joinable"));
+        }
+
+        self.check_quest_unlocked(&quest).await?;
+
         let adventures_count = self
             .quest_viewing_repository
             .adventurers_counting_by_quest_id(quest_id)
             .await?;
 
-        let quest_status_can_join = quest.status == QuestStatuses::Open.to_string()
+        // a full crew puts the adventurer in line, leave and kick promote the head of the line
+        if adventures_count >= quest.capacity as i64 {
+            let position = self
+                .crew_switchboard_repository
+                .enqueue(AddQuestWaitlistEntryEntity {
+                    quest_id,
+                    adventurer_id,
+                    created_at: chrono::Utc::now().naive_utc(),
+                })
+                .await?;
+
+            return Ok(QuestJoinOutcomes::Waitlisted { position });
+        }
+
+        self.crew_switchboard_repository
+            .join(
+                QuestAdventurerJunction::new(quest_id, adventurer_id),
+                DomainEvent::AdventurerJoined {
+                    quest_id,
+                    guild_id: quest.guild_id,
+                    adventurer_id,
+                },
+            )
+            .await
+    }
+
+    pub async fn leave(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let quest_status_can_leave = quest.status == QuestStatuses::Open.to_string()
             || quest.status == QuestStatuses::Failed.to_string();
 
-        let current_total_adventurers_in_quest_not_full =
-            adventures_count < MAX_ADVENTURERS_PER_QUEST;
+        if !quest_status_can_leave {
+            return Err(anyhow::anyhow!("The quest not leavable"));
+        }
+
+        self.crew_switchboard_repository
+            .leave(
+                QuestAdventurerJunction::new(quest_id, adventurer_id),
+                DomainEvent::AdventurerLeft {
+                    quest_id,
+                    guild_id: quest.guild_id,
+                    adventurer_id,
+                },
+            )
+            .await?;
 
-        if !current_total_adventurers_in_quest_not_full {
-            return Err(anyhow::anyhow!("The quest has adventures full"));
+        Ok(())
+    }
+
+    pub async fn waitlist_position(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+    ) -> Result<QuestWaitlistPositionModel> {
+        let position = self
+            .crew_switchboard_repository
+            .waitlist_position(quest_id, adventurer_id)
+            .await?;
+
+        Ok(QuestWaitlistPositionModel {
+            quest_id,
+            adventurer_id,
+            position,
+        })
+    }
+
+    pub async fn leave_waitlist(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        self.crew_switchboard_repository
+            .dequeue(quest_id, adventurer_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn kick(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_quest_commander(&quest, guild_commander_id)?;
+
+        let quest_status_can_kick = quest.status == QuestStatuses::Open.to_string()
+            || quest.status == QuestStatuses::Failed.to_string();
+
+        if !quest_status_can_kick {
+            return Err(anyhow::anyhow!("The quest not kickable"));
         }
 
-        if !quest_status_can_join {
-            return Err(anyhow::anyhow!("The quest not joinable"));
+        self.crew_switchboard_repository
+            .leave(
+                QuestAdventurerJunction::new(quest_id, adventurer_id),
+                DomainEvent::AdventurerLeft {
+                    quest_id,
+                    guild_id: quest.guild_id,
+                    adventurer_id,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn assign_role(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+        assign_crew_role_model: AssignCrewRoleModel,
+    ) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_quest_commander(&quest, guild_commander_id)?;
+
+        let role = match assign_crew_role_model.role {
+            Some(CrewRoles::Custom(role)) if role.is_empty() => {
+                return Err(anyhow::anyhow!("The crew role cannot be empty"));
+            }
+            Some(CrewRoles::Custom(role)) if role.chars().count() > MAX_CREW_ROLE_LENGTH => {
+                return Err(anyhow::anyhow!(
+                    "The crew role cannot be longer than {} characters",
+                    MAX_CREW_ROLE_LENGTH
+                ));
+            }
+            role => role.map(|role| role.to_string()),
+        };
+
+        self.crew_switchboard_repository
+            .assign_role(quest_id, adventurer_id, role)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_reward_weight(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+        set_reward_weight_model: SetRewardWeightModel,
+    ) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_quest_commander(&quest, guild_commander_id)?;
+
+        if quest.status == QuestStatuses::Completed.to_string() {
+            return Err(anyhow::anyhow!("The quest reward is already paid out"));
+        }
+
+        if set_reward_weight_model.reward_weight <= 0 {
+            return Err(anyhow::anyhow!("The reward weight must be greater than 0"));
         }
 
         self.crew_switchboard_repository
-            .join(QuestAdventurerJunction {
+            .set_reward_weight(
                 quest_id,
                 adventurer_id,
-            })
+                set_reward_weight_model.reward_weight,
+            )
             .await?;
 
         Ok(())
     }
 
-    pub async fn leave(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+    pub async fn invite(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<i32> {
         let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
-        let quest_status_can_leave = quest.status == QuestStatuses::Open.to_string()
-            || quest.status == QuestStatuses::Failed.to_string();
+        check_quest_commander(&quest, guild_commander_id)?;
 
-        if !quest_status_can_leave {
-            return Err(anyhow::anyhow!("The quest not leavable"));
+        if !quest_status_can_join(&quest) {
+            return Err(anyhow::anyhow!("The quest not joinable"));
         }
 
-        self.crew_switchboard_repository
-            .leave(QuestAdventurerJunction {
+        // only members of the quest's guild can be invited
+        if !self
+            .crew_switchboard_repository
+            .is_guild_member(quest.guild_id, adventurer_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!(
+                "The adventurer is not a member of the quest's guild"
+            ));
+        }
+
+        let now = chrono::Utc::now().naive_utc();
+
+        let invitation_id = self
+            .crew_switchboard_repository
+            .invite(AddQuestInvitationEntity {
                 quest_id,
                 adventurer_id,
+                guild_commander_id,
+                status: QuestInvitationStatuses::Pending.to_string(),
+                created_at: now,
+                updated_at: now,
             })
             .await?;
 
+        Ok(invitation_id)
+    }
+
+    pub async fn invitations(&self, adventurer_id: i32) -> Result<Vec<QuestInvitationModel>> {
+        let results = self
+            .crew_switchboard_repository
+            .pending_invitations(adventurer_id)
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|invitation| invitation.to_model())
+            .collect())
+    }
+
+    pub async fn accept_invitation(&self, invitation_id: i32, adventurer_id: i32) -> Result<()> {
+        let invitation = self
+            .crew_switchboard_repository
+            .find_invitation(invitation_id)
+            .await?;
+
+        check_invitation_pending(&invitation, adventurer_id)?;
+
+        let quest = self
+            .quest_viewing_repository
+            .view_details(invitation.quest_id)
+            .await?;
+
+        // accepting an invitation takes a crew seat, so it follows the same rules as joining
+        self.check_quest_joinable(&quest).await?;
+        self.check_adventurer_level(&quest, adventurer_id).await?;
+
+        self.crew_switchboard_repository
+            .accept_invitation(
+                invitation_id,
+                QuestAdventurerJunction::new(invitation.quest_id, adventurer_id),
+                DomainEvent::AdventurerJoined {
+                    quest_id: invitation.quest_id,
+                    guild_id: quest.guild_id,
+                    adventurer_id,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn decline_invitation(&self, invitation_id: i32, adventurer_id: i32) -> Result<()> {
+        let invitation = self
+            .crew_switchboard_repository
+            .find_invitation(invitation_id)
+            .await?;
+
+        check_invitation_pending(&invitation, adventurer_id)?;
+
+        self.crew_switchboard_repository
+            .decline_invitation(invitation_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn applications(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestApplicationModel>> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_quest_commander(&quest, guild_commander_id)?;
+
+        let results = self
+            .crew_switchboard_repository
+            .pending_applications(quest_id)
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|application| application.to_model())
+            .collect())
+    }
+
+    pub async fn approve_application(
+        &self,
+        application_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        let application = self
+            .crew_switchboard_repository
+            .find_application(application_id)
+            .await?;
+
+        let quest = self
+            .quest_viewing_repository
+            .view_details(application.quest_id)
+            .await?;
+
+        check_quest_commander(&quest, guild_commander_id)?;
+        check_application_pending(&application)?;
+
+        // the quest may have filled up or started since the adventurer applied
+        self.check_quest_joinable(&quest).await?;
+        self.check_adventurer_level(&quest, application.adventurer_id)
+            .await?;
+
+        self.crew_switchboard_repository
+            .approve_application(
+                application_id,
+                QuestAdventurerJunction::new(application.quest_id, application.adventurer_id),
+                DomainEvent::AdventurerJoined {
+                    quest_id: application.quest_id,
+                    guild_id: quest.guild_id,
+                    adventurer_id: application.adventurer_id,
+                },
+            )
+            .await?;
+
         Ok(())
     }
+
+    pub async fn reject_application(
+        &self,
+        application_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        let application = self
+            .crew_switchboard_repository
+            .find_application(application_id)
+            .await?;
+
+        let quest = self
+            .quest_viewing_repository
+            .view_details(application.quest_id)
+            .await?;
+
+        check_quest_commander(&quest, guild_commander_id)?;
+        check_application_pending(&application)?;
+
+        self.crew_switchboard_repository
+            .reject_application(application_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn withdraw_application(
+        &self,
+        application_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        let application = self
+            .crew_switchboard_repository
+            .find_application(application_id)
+            .await?;
+
+        if application.adventurer_id != adventurer_id {
+            return Err(anyhow::anyhow!("Application not found"));
+        }
+
+        check_application_pending(&application)?;
+
+        self.crew_switchboard_repository
+            .withdraw_application(application_id)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn check_adventurer_level(&self, quest: &QuestEntity, adventurer_id: i32) -> Result<()> {
+        let Some(min_level) = quest.min_level else {
+            return Ok(());
+        };
+
+        let level = self
+            .crew_switchboard_repository
+            .adventurer_level(adventurer_id)
+            .await?;
+
+        if level < min_level {
+            return Err(anyhow::anyhow!(
+                "The quest requires level {} but the adventurer is level {}",
+                min_level,
+                level
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn check_quest_joinable(&self, quest: &QuestEntity) -> Result<()> {
+        let adventures_count = self
+            .quest_viewing_repository
+            .adventurers_counting_by_quest_id(quest.id)
+            .await?;
+
+        let current_total_adventurers_in_quest_not_full = adventures_count < quest.capacity as i64;
+
+        if !current_total_adventurers_in_quest_not_full {
+            return Err(anyhow::anyhow!("The quest has adventures full"));
+        }
+
+        if !quest_status_can_join(quest) {
+            return Err(anyhow::anyhow!("The quest not joinable"));
+        }
+
+        self.check_quest_unlocked(quest).await?;
+
+        Ok(())
+    }
+
+    async fn check_quest_unlocked(&self, quest: &QuestEntity) -> Result<()> {
+        let prerequisites = self
+            .quest_viewing_repository
+            .prerequisites_by_quest_id(quest.id)
+            .await?;
+
+        if !prerequisites_completed(&prerequisites) {
+            return Err(anyhow::anyhow!(
+                "The quest is locked until its prerequisites are completed"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn quest_status_can_join(quest: &QuestEntity) -> bool {
+    JOINABLE_QUEST_STATUSES
+        .iter()
+        .any(|status| quest.status == status.to_string())
+}
+
+fn check_quest_commander(quest: &QuestEntity, guild_commander_id: i32) -> Result<()> {
+    if quest.guild_commander_id != guild_commander_id {
+        return Err(anyhow::anyhow!(
+            "You are not the guild commander of this quest"
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_application_pending(application: &QuestApplicationEntity) -> Result<()> {
+    if application.status != QuestApplicationStatuses::Pending.to_string() {
+        return Err(anyhow::anyhow!(
+            "The application is already {}",
+            application.status
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_invitation_pending(invitation: &QuestInvitationEntity, adventurer_id: i32) -> Result<()> {
+    // another adventurer's invitation is reported as missing so ids cannot be probed
+    if invitation.adventurer_id != adventurer_id {
+        return Err(anyhow::anyhow!("Invitation not found"));
+    }
+
+    if invitation.status != QuestInvitationStatuses::Pending.to_string() {
+        return Err(anyhow::anyhow!(
+            "The invitation is already {}",
+            invitation.status
+        ));
+    }
+
+    Ok(())
 }
@@ -1,5 +1,4 @@
 #[cfg(test)]
-
 mod tests {
 
     use std::sync::Arc;
@@ -8,14 +7,26 @@ mod tests {
     use chrono::{TimeZone, Utc};
 
     use crate::{
-        application::usecases::crew_switchboard::CrewSwitchboardUseCase,
+        application::usecases::{
+            crew_switchboard::CrewSwitchboardUseCase, tests::common::quest_entity,
+        },
         domain::{
-            entities::quests::QuestEntity,
+            entities::{
+                quest_applications::QuestApplicationEntity,
+                quest_invitations::QuestInvitationEntity, quests::QuestEntity,
+            },
             repositories::{
                 crew_switchboard::MockCrewSwitchboardRepository,
                 quest_viewing::MockQuestViewingRepository,
             },
-            value_objects::quest_statuses::QuestStatuses,
+            value_objects::{
+                crew_member_model::AssignCrewRoleModel, crew_roles::CrewRoles,
+                domain_events::DomainEventTypes,
+                quest_application_statuses::QuestApplicationStatuses,
+                quest_invitation_statuses::QuestInvitationStatuses,
+                quest_join_outcomes::QuestJoinOutcomes, quest_join_policies::QuestJoinPolicies,
+                quest_ranks::QuestRanks, quest_statuses::QuestStatuses,
+            },
         },
     };
 
@@ -46,13 +57,32 @@ mod tests {
                             .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
                             .unwrap()
                             .naive_utc(),
+                        join_policy: QuestJoinPolicies::Open.to_string(),
+                        starts_at: None,
+                        due_at: None,
+                        reward: 0,
+                        experience: 0,
+                        experience_penalty: 0,
+                        min_level: None,
+                        rank: QuestRanks::E.to_string(),
+                        max_attempts: None,
+                        capacity: 4,
+                        guild_id: 1,
                     })
                 })
             });
 
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
         mock_crew_switchboard_repo
             .expect_join()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .withf(|_, domain_event| {
+                domain_event.event_type() == DomainEventTypes::AdventurerJoined
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(QuestJoinOutcomes::Joined) }));
 
         let use_case = CrewSwitchboardUseCase::new(
             Arc::new(mock_crew_switchboard_repo),
@@ -64,6 +94,47 @@ mod tests {
         assert!(result.is_ok())
     }
 
+    #[tokio::test]
+    async fn test_join_fails_when_quest_is_locked() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![QuestEntity {
+                        description: None,
+                        status: QuestStatuses::InJourney.to_string(),
+                        ..quest_entity(2)
+                    }])
+                })
+            });
+
+        mock_crew_switchboard_repo.expect_join().never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.join(1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The quest is locked until its prerequisites are completed"
+        )
+    }
+
     #[tokio::test]
     async fn test_join_fails_when_quest_is_not_open() {
         let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
@@ -91,13 +162,24 @@ mod tests {
                             .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
                             .unwrap()
                             .naive_utc(),
+                        join_policy: QuestJoinPolicies::Open.to_string(),
+                        starts_at: None,
+                        due_at: None,
+                        reward: 0,
+                        experience: 0,
+                        experience_penalty: 0,
+                        min_level: None,
+                        rank: QuestRanks::E.to_string(),
+                        max_attempts: None,
+                        capacity: 4,
+                        guild_id: 1,
                     })
                 })
             });
 
         mock_crew_switchboard_repo
             .expect_join()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .returning(|_, _| Box::pin(async { Ok(QuestJoinOutcomes::Joined) }));
 
         let use_case = CrewSwitchboardUseCase::new(
             Arc::new(mock_crew_switchboard_repo),
@@ -111,7 +193,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_join_fails_when_quest_has_adventurers_full() {
+    async fn test_join_waitlists_when_quest_has_adventurers_full() {
         let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
         let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
 
@@ -119,6 +201,42 @@ mod tests {
             .expect_adventurers_counting_by_quest_id()
             .returning(|_| Box::pin(async { Ok(4) }));
 
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_crew_switchboard_repo.expect_join().never();
+
+        mock_crew_switchboard_repo
+            .expect_enqueue()
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.join(1, 1).await;
+
+        assert_eq!(
+            result.unwrap(),
+            QuestJoinOutcomes::Waitlisted { position: 1 }
+        )
+    }
+
+    #[tokio::test]
+    async fn test_leave_success() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
         mock_quest_viewing_repo
             .expect_view_details()
             .returning(|_| {
@@ -137,30 +255,39 @@ mod tests {
                             .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
                             .unwrap()
                             .naive_utc(),
+                        join_policy: QuestJoinPolicies::Open.to_string(),
+                        starts_at: None,
+                        due_at: None,
+                        reward: 0,
+                        experience: 0,
+                        experience_penalty: 0,
+                        min_level: None,
+                        rank: QuestRanks::E.to_string(),
+                        max_attempts: None,
+                        capacity: 4,
+                        guild_id: 1,
                     })
                 })
             });
 
         mock_crew_switchboard_repo
-            .expect_join()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .expect_leave()
+            .withf(|_, domain_event| domain_event.event_type() == DomainEventTypes::AdventurerLeft)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
 
         let use_case = CrewSwitchboardUseCase::new(
             Arc::new(mock_crew_switchboard_repo),
             Arc::new(mock_quest_viewing_repo),
         );
 
-        let result = use_case.join(1, 1).await;
+        let result = use_case.leave(1, 1).await;
 
-        assert!(result.is_err());
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            "The quest has adventures full"
-        )
+        assert!(result.is_ok())
     }
 
     #[tokio::test]
-    async fn test_leave_success() {
+    async fn test_leave_fails_when_quest_is_not_open() {
         let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
         let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
 
@@ -176,7 +303,7 @@ mod tests {
                         id: 1,
                         name: "test quest 1".to_string(),
                         description: Some("test quest description".to_string()),
-                        status: QuestStatuses::Open.to_string(),
+                        status: QuestStatuses::InJourney.to_string(),
                         guild_commander_id: 1,
                         created_at: Utc
                             .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
@@ -186,13 +313,24 @@ mod tests {
                             .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
                             .unwrap()
                             .naive_utc(),
+                        join_policy: QuestJoinPolicies::Open.to_string(),
+                        starts_at: None,
+                        due_at: None,
+                        reward: 0,
+                        experience: 0,
+                        experience_penalty: 0,
+                        min_level: None,
+                        rank: QuestRanks::E.to_string(),
+                        max_attempts: None,
+                        capacity: 4,
+                        guild_id: 1,
                     })
                 })
             });
 
         mock_crew_switchboard_repo
             .expect_leave()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .returning(|_, _| Box::pin(async { Ok(()) }));
 
         let use_case = CrewSwitchboardUseCase::new(
             Arc::new(mock_crew_switchboard_repo),
@@ -201,28 +339,200 @@ mod tests {
 
         let result = use_case.leave(1, 1).await;
 
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "The quest not leavable")
+    }
+
+    #[tokio::test]
+    async fn test_kick_success() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
+        mock_crew_switchboard_repo
+            .expect_leave()
+            .withf(|_, domain_event| domain_event.event_type() == DomainEventTypes::AdventurerLeft)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.kick(1, 1, 2).await;
+
         assert!(result.is_ok())
     }
 
     #[tokio::test]
-    async fn test_leave_fails_when_quest_is_not_open() {
+    async fn test_kick_fails_when_not_guild_commander_of_quest() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        guild_commander_id: 2,
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo.expect_leave().never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.kick(1, 1, 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "You are not the guild commander of this quest"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_kick_fails_when_quest_is_not_open() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        status: QuestStatuses::InJourney.to_string(),
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo.expect_leave().never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.kick(1, 1, 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "The quest not kickable")
+    }
+
+    #[tokio::test]
+    async fn test_invite_fails_when_not_guild_commander_of_quest() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        guild_commander_id: 2,
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo.expect_invite().never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.invite(1, 1, 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "You are not the guild commander of this quest"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation_success() {
         let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
         let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
 
+        mock_crew_switchboard_repo
+            .expect_find_invitation()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestInvitationEntity {
+                        id: 1,
+                        quest_id: 1,
+                        adventurer_id: 2,
+                        guild_commander_id: 1,
+                        status: QuestInvitationStatuses::Pending.to_string(),
+                        created_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
         mock_quest_viewing_repo
             .expect_adventurers_counting_by_quest_id()
             .returning(|_| Box::pin(async { Ok(2) }));
 
         mock_quest_viewing_repo
             .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_crew_switchboard_repo
+            .expect_accept_invitation()
+            .withf(|_, _, domain_event| {
+                domain_event.event_type() == DomainEventTypes::AdventurerJoined
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.accept_invitation(1, 2).await;
+
+        assert!(result.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation_fails_when_quest_has_adventurers_full() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_crew_switchboard_repo
+            .expect_find_invitation()
             .returning(|_| {
                 Box::pin(async {
-                    Ok(QuestEntity {
+                    Ok(QuestInvitationEntity {
                         id: 1,
-                        name: "test quest 1".to_string(),
-                        description: Some("test quest description".to_string()),
-                        status: QuestStatuses::InJourney.to_string(),
+                        quest_id: 1,
+                        adventurer_id: 2,
                         guild_commander_id: 1,
+                        status: QuestInvitationStatuses::Pending.to_string(),
                         created_at: Utc
                             .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
                             .unwrap()
@@ -235,21 +545,521 @@ mod tests {
                 })
             });
 
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(4) }));
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
         mock_crew_switchboard_repo
-            .expect_leave()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .expect_accept_invitation()
+            .never();
 
         let use_case = CrewSwitchboardUseCase::new(
             Arc::new(mock_crew_switchboard_repo),
             Arc::new(mock_quest_viewing_repo),
         );
 
-        let result = use_case.leave(1, 1).await;
+        let result = use_case.accept_invitation(1, 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The quest has adventures full"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation_fails_when_invitation_belongs_to_other_adventurer() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_crew_switchboard_repo
+            .expect_find_invitation()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestInvitationEntity {
+                        id: 1,
+                        quest_id: 1,
+                        adventurer_id: 3,
+                        guild_commander_id: 1,
+                        status: QuestInvitationStatuses::Pending.to_string(),
+                        created_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo
+            .expect_accept_invitation()
+            .never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.accept_invitation(1, 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Invitation not found")
+    }
+
+    #[tokio::test]
+    async fn test_decline_invitation_fails_when_invitation_is_not_pending() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_crew_switchboard_repo
+            .expect_find_invitation()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestInvitationEntity {
+                        id: 1,
+                        quest_id: 1,
+                        adventurer_id: 2,
+                        guild_commander_id: 1,
+                        status: QuestInvitationStatuses::Accepted.to_string(),
+                        created_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo
+            .expect_decline_invitation()
+            .never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.decline_invitation(1, 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The invitation is already Accepted"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_join_applies_when_quest_requires_approval() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(4) }));
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        join_policy: QuestJoinPolicies::ApprovalRequired.to_string(),
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_crew_switchboard_repo.expect_join().never();
+
+        mock_crew_switchboard_repo
+            .expect_apply()
+            .returning(|_| Box::pin(async { Ok(7) }));
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.join(1, 1).await;
+
+        assert_eq!(
+            result.unwrap(),
+            QuestJoinOutcomes::Applied { application_id: 7 }
+        )
+    }
+
+    #[tokio::test]
+    async fn test_join_fails_when_approval_quest_is_not_open() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        status: QuestStatuses::Completed.to_string(),
+                        join_policy: QuestJoinPolicies::ApprovalRequired.to_string(),
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo.expect_apply().never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.join(1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "The quest not joinable")
+    }
+
+    #[tokio::test]
+    async fn test_approve_application_success() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_crew_switchboard_repo
+            .expect_find_application()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestApplicationEntity {
+                        id: 1,
+                        quest_id: 1,
+                        adventurer_id: 2,
+                        status: QuestApplicationStatuses::Pending.to_string(),
+                        created_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(3) }));
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        join_policy: QuestJoinPolicies::ApprovalRequired.to_string(),
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_crew_switchboard_repo
+            .expect_approve_application()
+            .withf(|_, _, domain_event| {
+                domain_event.event_type() == DomainEventTypes::AdventurerJoined
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.approve_application(1, 1).await;
+
+        assert!(result.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_approve_application_fails_when_quest_has_adventurers_full() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_crew_switchboard_repo
+            .expect_find_application()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestApplicationEntity {
+                        id: 1,
+                        quest_id: 1,
+                        adventurer_id: 2,
+                        status: QuestApplicationStatuses::Pending.to_string(),
+                        created_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(4) }));
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        join_policy: QuestJoinPolicies::ApprovalRequired.to_string(),
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo
+            .expect_approve_application()
+            .never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.approve_application(1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The quest has adventures full"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_approve_application_fails_when_not_guild_commander_of_quest() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_crew_switchboard_repo
+            .expect_find_application()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestApplicationEntity {
+                        id: 1,
+                        quest_id: 1,
+                        adventurer_id: 2,
+                        status: QuestApplicationStatuses::Pending.to_string(),
+                        created_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        guild_commander_id: 3,
+                        join_policy: QuestJoinPolicies::ApprovalRequired.to_string(),
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo
+            .expect_approve_application()
+            .never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.approve_application(1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "You are not the guild commander of this quest"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_application_fails_when_application_is_not_pending() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_crew_switchboard_repo
+            .expect_find_application()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestApplicationEntity {
+                        id: 1,
+                        quest_id: 1,
+                        adventurer_id: 2,
+                        status: QuestApplicationStatuses::Rejected.to_string(),
+                        created_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo
+            .expect_withdraw_application()
+            .never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.withdraw_application(1, 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The application is already Rejected"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assign_role_success() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        status: QuestStatuses::InJourney.to_string(),
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo
+            .expect_assign_role()
+            .withf(|_, _, role| role.as_deref() == Some("Cartographer"))
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case
+            .assign_role(
+                1,
+                1,
+                2,
+                AssignCrewRoleModel {
+                    role: Some(CrewRoles::from("Cartographer".to_string())),
+                },
+            )
+            .await;
+
+        assert!(result.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_assign_role_fails_when_custom_role_is_empty() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
+        mock_crew_switchboard_repo.expect_assign_role().never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case
+            .assign_role(
+                1,
+                1,
+                2,
+                AssignCrewRoleModel {
+                    role: Some(CrewRoles::from("  ".to_string())),
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The crew role cannot be empty"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_join_fails_when_adventurer_level_is_too_low() {
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        min_level: Some(3),
+                        ..quest_entity(1)
+                    })
+                })
+            });
+
+        mock_crew_switchboard_repo
+            .expect_adventurer_level()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_crew_switchboard_repo.expect_join().never();
+
+        let use_case = CrewSwitchboardUseCase::new(
+            Arc::new(mock_crew_switchboard_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.join(1, 1).await;
 
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err().to_string(),
-            "The quest not leavable"
+            "The quest requires level 3 but the adventurer is level 2"
         )
     }
 }
@@ -0,0 +1,63 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    repositories::guilds::GuildsRepository,
+    value_objects::guild_membership_model::{GuildAccess, GuildMember, GuildResource},
+};
+
+pub struct GuildAccessUseCase<T>
+where
+    T: GuildsRepository + Send + Sync,
+{
+    guilds_repository: Arc<T>,
+}
+
+impl<T> GuildAccessUseCase<T>
+where
+    T: GuildsRepository + Send + Sync,
+{
+    pub fn new(guilds_repository: Arc<T>) -> Self {
+        Self { guilds_repository }
+    }
+
+    // every row named in the path must exist and sit in a guild the member belongs to,
+    // so a quest of one guild cannot be used to reach a comment or invitation of another
+    pub async fn check(
+        &self,
+        member: GuildMember,
+        path_params: &HashMap<String, String>,
+    ) -> Result<GuildAccess> {
+        let mut guild_ids: Vec<i32> = Vec::new();
+
+        for (name, value) in path_params.iter() {
+            let std::result::Result::Ok(id) = value.parse::<i32>() else {
+                return Ok(GuildAccess::NotFound);
+            };
+
+            let Some(resource) = GuildResource::from_path_param(name, id)? else {
+                continue;
+            };
+
+            match self.guilds_repository.resource_guild_id(resource).await? {
+                Some(guild_id) if !guild_ids.contains(&guild_id) => guild_ids.push(guild_id),
+                Some(_) => {}
+                None => return Ok(GuildAccess::NotFound),
+            }
+        }
+
+        for guild_id in guild_ids {
+            if self
+                .guilds_repository
+                .membership(guild_id, member)
+                .await?
+                .is_none()
+            {
+                return Ok(GuildAccess::NotMember);
+            }
+        }
+
+        Ok(GuildAccess::Member)
+    }
+}
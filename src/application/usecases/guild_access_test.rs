@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests {
+
+    use std::{collections::HashMap, sync::Arc};
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::usecases::guild_access::GuildAccessUseCase,
+        domain::{
+            entities::guild_memberships::GuildMembershipEntity,
+            repositories::guilds::MockGuildsRepository,
+            value_objects::{
+                guild_membership_model::{GuildAccess, GuildMember, GuildResource},
+                guild_membership_roles::GuildMembershipRoles,
+            },
+        },
+    };
+
+    fn membership(guild_id: i32) -> GuildMembershipEntity {
+        GuildMembershipEntity {
+            id: 1,
+            guild_id,
+            adventurer_id: Some(3),
+            guild_commander_id: None,
+            role: GuildMembershipRoles::Member.to_string(),
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    fn path_params(params: &[(&str, &str)]) -> HashMap<String, String> {
+        params
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    // the adventurer only belongs to guild 1
+    fn mock_guilds_repo_for_member_of_guild_1() -> MockGuildsRepository {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo
+            .expect_membership()
+            .returning(|guild_id, _| {
+                Box::pin(async move { Ok((guild_id == 1).then(|| membership(guild_id))) })
+            });
+
+        mock_guilds_repo
+    }
+
+    #[tokio::test]
+    async fn test_check_rejects_non_member_on_every_guild_route() {
+        let mut mock_guilds_repo = mock_guilds_repo_for_member_of_guild_1();
+
+        // every row lives in guild 2
+        mock_guilds_repo
+            .expect_resource_guild_id()
+            .returning(|_| Box::pin(async { Ok(Some(2)) }));
+
+        let use_case = GuildAccessUseCase::new(Arc::new(mock_guilds_repo));
+
+        let routes = [
+            vec![("guild_id", "2")],
+            vec![("guild_id", "2"), ("membership_id", "4")],
+            vec![("guild_id", "2"), ("invite_id", "4")],
+            vec![("quest_id", "5")],
+            vec![("quest_id", "5"), ("comment_id", "6")],
+            vec![("quest_id", "5"), ("milestone_id", "6")],
+            vec![("quest_id", "5"), ("prerequisite_id", "6")],
+            vec![("quest_id", "5"), ("adventurer_id", "3")],
+            vec![("invitation_id", "7")],
+            vec![("application_id", "7")],
+        ];
+
+        for route in routes {
+            let result = use_case
+                .check(GuildMember::Adventurer(3), &path_params(&route))
+                .await
+                .unwrap();
+
+            assert_eq!(result, GuildAccess::NotMember, "{:?}", route);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_rejects_comment_of_another_guild_under_own_quest() {
+        let mut mock_guilds_repo = mock_guilds_repo_for_member_of_guild_1();
+
+        mock_guilds_repo
+            .expect_resource_guild_id()
+            .returning(|resource| {
+                Box::pin(async move {
+                    Ok(match resource {
+                        GuildResource::Quest(_) => Some(1),
+                        _ => Some(2),
+                    })
+                })
+            });
+
+        let use_case = GuildAccessUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case
+            .check(
+                GuildMember::Adventurer(3),
+                &path_params(&[("quest_id", "5"), ("comment_id", "6")]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, GuildAccess::NotMember);
+    }
+
+    #[tokio::test]
+    async fn test_check_reports_missing_row_as_not_found() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo
+            .expect_resource_guild_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        mock_guilds_repo.expect_membership().never();
+
+        let use_case = GuildAccessUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case
+            .check(
+                GuildMember::Adventurer(3),
+                &path_params(&[("application_id", "7")]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, GuildAccess::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_check_fails_on_unlisted_route_parameter() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo.expect_resource_guild_id().never();
+
+        let use_case = GuildAccessUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case
+            .check(
+                GuildMember::Adventurer(3),
+                &path_params(&[("badge_id", "1")]),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The route parameter badge_id has no guild to check"
+        );
+    }
+}
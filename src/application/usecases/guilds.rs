@@ -0,0 +1,382 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    entities::{guild_invites::GuildInviteEntity, guild_memberships::GuildMembershipEntity},
+    repositories::guilds::GuildsRepository,
+    value_objects::{
+        guild_invite_model::{AddGuildInviteModel, GuildInviteModel},
+        guild_membership_model::{
+            AddGuildMemberModel, EditGuildMemberModel, GuildMember, GuildMemberModel,
+        },
+        guild_membership_roles::GuildMembershipRoles,
+        guild_model::{AddGuildModel, GuildModel, MAX_GUILD_NAME_LENGTH},
+    },
+};
+
+pub struct GuildsUseCase<T>
+where
+    T: GuildsRepository + Send + Sync,
+{
+    guilds_repository: Arc<T>,
+}
+
+impl<T> GuildsUseCase<T>
+where
+    T: GuildsRepository + Send + Sync,
+{
+    pub fn new(guilds_repository: Arc<T>) -> Self {
+        Self { guilds_repository }
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_guild_model: AddGuildModel,
+    ) -> Result<i32> {
+        let name = add_guild_model.name.trim();
+
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("The guild name cannot be empty"));
+        }
+
+        if name.chars().count() > MAX_GUILD_NAME_LENGTH {
+            return Err(anyhow::anyhow!(
+                "The guild name cannot be longer than {} characters",
+                MAX_GUILD_NAME_LENGTH
+            ));
+        }
+
+        let result = self
+            .guilds_repository
+            .add(add_guild_model.to_entity(), guild_commander_id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn guilds(&self, member: GuildMember) -> Result<Vec<GuildModel>> {
+        let results = self.guilds_repository.guilds_by_member(member).await?;
+
+        Ok(results
+            .iter()
+            .map(|(guild, role)| guild.to_model(Some(role.clone())))
+            .collect())
+    }
+
+    pub async fn view(&self, guild_id: i32, member: GuildMember) -> Result<GuildModel> {
+        let membership = self.check_guild_member(guild_id, member).await?;
+
+        let guild = self.guilds_repository.find(guild_id).await?;
+
+        Ok(guild.to_model(Some(membership.role)))
+    }
+
+    pub async fn add_member(
+        &self,
+        guild_id: i32,
+        guild_commander_id: i32,
+        add_guild_member_model: AddGuildMemberModel,
+    ) -> Result<i32> {
+        let membership = self
+            .check_guild_member(guild_id, GuildMember::GuildCommander(guild_commander_id))
+            .await?;
+
+        check_guild_manager(&membership)?;
+
+        let Some(member) = add_guild_member_model.member() else {
+            return Err(anyhow::anyhow!(
+                "A member is either an adventurer or a guild commander"
+            ));
+        };
+
+        let role = add_guild_member_model.role.unwrap_or_default();
+
+        // ownership is never handed out, the founder stays the only Owner
+        if role == GuildMembershipRoles::Owner {
+            return Err(anyhow::anyhow!("A guild has only one Owner"));
+        }
+
+        if matches!(member, GuildMember::Adventurer(_)) && role != GuildMembershipRoles::Member {
+            return Err(anyhow::anyhow!(
+                "Adventurers can only be Members of a guild"
+            ));
+        }
+
+        if self
+            .guilds_repository
+            .membership(guild_id, member)
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!("Already a member of this guild"));
+        }
+
+        let result = self
+            .guilds_repository
+            .add_member(member.to_membership_entity(guild_id, &role))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn members(
+        &self,
+        guild_id: i32,
+        member: GuildMember,
+    ) -> Result<Vec<GuildMemberModel>> {
+        self.check_guild_member(guild_id, member).await?;
+
+        let results = self.guilds_repository.members_by_guild_id(guild_id).await?;
+
+        Ok(results
+            .iter()
+            .map(|(membership, username)| membership.to_member_model(username.clone()))
+            .collect())
+    }
+
+    pub async fn change_member_role(
+        &self,
+        guild_id: i32,
+        guild_commander_id: i32,
+        membership_id: i32,
+        edit_guild_member_model: EditGuildMemberModel,
+    ) -> Result<()> {
+        let membership = self
+            .check_guild_member(guild_id, GuildMember::GuildCommander(guild_commander_id))
+            .await?;
+
+        check_guild_manager(&membership)?;
+
+        let target = self.find_guild_membership(guild_id, membership_id).await?;
+
+        let role = edit_guild_member_model.role;
+
+        if target.role == GuildMembershipRoles::Owner.to_string() {
+            return Err(anyhow::anyhow!("The Owner's role cannot be changed"));
+        }
+
+        if role == GuildMembershipRoles::Owner {
+            return Err(anyhow::anyhow!("A guild has only one Owner"));
+        }
+
+        if matches!(target.member(), GuildMember::Adventurer(_))
+            && role != GuildMembershipRoles::Member
+        {
+            return Err(anyhow::anyhow!(
+                "Adventurers can only be Members of a guild"
+            ));
+        }
+
+        let touches_officer = target.role == GuildMembershipRoles::Officer.to_string()
+            || role == GuildMembershipRoles::Officer;
+
+        if touches_officer && membership.role != GuildMembershipRoles::Owner.to_string() {
+            return Err(anyhow::anyhow!(
+                "Only the Owner can appoint or demote Officers"
+            ));
+        }
+
+        self.guilds_repository
+            .change_member_role(target.id, role.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_member(
+        &self,
+        guild_id: i32,
+        guild_commander_id: i32,
+        membership_id: i32,
+    ) -> Result<()> {
+        let membership = self
+            .check_guild_member(guild_id, GuildMember::GuildCommander(guild_commander_id))
+            .await?;
+
+        check_guild_manager(&membership)?;
+
+        let target = self.find_guild_membership(guild_id, membership_id).await?;
+
+        if target.role == GuildMembershipRoles::Owner.to_string() {
+            return Err(anyhow::anyhow!(
+                "The Owner cannot be removed from the guild"
+            ));
+        }
+
+        if target.role == GuildMembershipRoles::Officer.to_string()
+            && membership.role != GuildMembershipRoles::Owner.to_string()
+        {
+            return Err(anyhow::anyhow!("Only the Owner can remove an Officer"));
+        }
+
+        self.guilds_repository
+            .remove_member(guild_id, target.id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn add_invite(
+        &self,
+        guild_id: i32,
+        guild_commander_id: i32,
+        add_guild_invite_model: AddGuildInviteModel,
+    ) -> Result<GuildInviteModel> {
+        let membership = self
+            .check_guild_member(guild_id, GuildMember::GuildCommander(guild_commander_id))
+            .await?;
+
+        check_guild_manager(&membership)?;
+
+        if add_guild_invite_model
+            .max_uses
+            .is_some_and(|max_uses| max_uses < 1)
+        {
+            return Err(anyhow::anyhow!("An invite must allow at least one use"));
+        }
+
+        if add_guild_invite_model
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= chrono::Utc::now().naive_utc())
+        {
+            return Err(anyhow::anyhow!("The invite expiry must be in the future"));
+        }
+
+        let invite = self
+            .guilds_repository
+            .add_invite(add_guild_invite_model.to_entity(guild_id, guild_commander_id))
+            .await?;
+
+        Ok(invite.to_model())
+    }
+
+    pub async fn invites(
+        &self,
+        guild_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<Vec<GuildInviteModel>> {
+        let membership = self
+            .check_guild_member(guild_id, GuildMember::GuildCommander(guild_commander_id))
+            .await?;
+
+        check_guild_manager(&membership)?;
+
+        let results = self.guilds_repository.invites_by_guild_id(guild_id).await?;
+
+        Ok(results.iter().map(|invite| invite.to_model()).collect())
+    }
+
+    pub async fn revoke_invite(
+        &self,
+        guild_id: i32,
+        guild_commander_id: i32,
+        invite_id: i32,
+    ) -> Result<()> {
+        let membership = self
+            .check_guild_member(guild_id, GuildMember::GuildCommander(guild_commander_id))
+            .await?;
+
+        check_guild_manager(&membership)?;
+
+        let invite = self.guilds_repository.find_invite(invite_id).await?;
+
+        if invite.guild_id != guild_id {
+            return Err(anyhow::anyhow!("Invite not found"));
+        }
+
+        self.guilds_repository.revoke_invite(invite.id).await?;
+
+        Ok(())
+    }
+
+    pub async fn accept_invite(&self, code: String, adventurer_id: i32) -> Result<i32> {
+        let invite = self.guilds_repository.find_invite_by_code(code).await?;
+
+        check_invite_usable(&invite)?;
+
+        let member = GuildMember::Adventurer(adventurer_id);
+
+        if self
+            .guilds_repository
+            .membership(invite.guild_id, member)
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!("Already a member of this guild"));
+        }
+
+        let result = self
+            .guilds_repository
+            .accept_invite(
+                invite.id,
+                member.to_membership_entity(invite.guild_id, &GuildMembershipRoles::Member),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn find_guild_membership(
+        &self,
+        guild_id: i32,
+        membership_id: i32,
+    ) -> Result<GuildMembershipEntity> {
+        let membership = self
+            .guilds_repository
+            .find_membership(membership_id)
+            .await?;
+
+        if membership.guild_id != guild_id {
+            return Err(anyhow::anyhow!("Guild member not found"));
+        }
+
+        Ok(membership)
+    }
+
+    async fn check_guild_member(
+        &self,
+        guild_id: i32,
+        member: GuildMember,
+    ) -> Result<GuildMembershipEntity> {
+        self.guilds_repository
+            .membership(guild_id, member)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("You are not a member of this guild"))
+    }
+}
+
+fn check_guild_manager(membership: &GuildMembershipEntity) -> Result<()> {
+    if membership.role != GuildMembershipRoles::Owner.to_string()
+        && membership.role != GuildMembershipRoles::Officer.to_string()
+    {
+        return Err(anyhow::anyhow!(
+            "Only the Owner or an Officer can manage this guild"
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_invite_usable(invite: &GuildInviteEntity) -> Result<()> {
+    if invite.revoked_at.is_some() {
+        return Err(anyhow::anyhow!("The invite has been revoked"));
+    }
+
+    if invite
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= chrono::Utc::now().naive_utc())
+    {
+        return Err(anyhow::anyhow!("The invite has expired"));
+    }
+
+    if invite
+        .max_uses
+        .is_some_and(|max_uses| invite.uses >= max_uses)
+    {
+        return Err(anyhow::anyhow!("The invite has been used up"));
+    }
+
+    Ok(())
+}
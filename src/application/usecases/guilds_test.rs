@@ -0,0 +1,304 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::usecases::guilds::GuildsUseCase,
+        domain::{
+            entities::{
+                guild_invites::GuildInviteEntity, guild_memberships::GuildMembershipEntity,
+            },
+            repositories::guilds::MockGuildsRepository,
+            value_objects::{
+                guild_membership_model::{AddGuildMemberModel, EditGuildMemberModel, GuildMember},
+                guild_membership_roles::GuildMembershipRoles,
+            },
+        },
+    };
+
+    fn guild_commander_membership(role: GuildMembershipRoles) -> GuildMembershipEntity {
+        GuildMembershipEntity {
+            id: 1,
+            guild_id: 1,
+            adventurer_id: None,
+            guild_commander_id: Some(1),
+            role: role.to_string(),
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    fn adventurer_membership(id: i32, adventurer_id: i32) -> GuildMembershipEntity {
+        GuildMembershipEntity {
+            id,
+            adventurer_id: Some(adventurer_id),
+            guild_commander_id: None,
+            ..guild_commander_membership(GuildMembershipRoles::Member)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_member_success() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo
+            .expect_membership()
+            .withf(|_, member| *member == GuildMember::GuildCommander(1))
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(Some(guild_commander_membership(
+                        GuildMembershipRoles::Owner,
+                    )))
+                })
+            });
+
+        mock_guilds_repo
+            .expect_membership()
+            .withf(|_, member| *member == GuildMember::Adventurer(3))
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
+        mock_guilds_repo
+            .expect_add_member()
+            .withf(|add_guild_membership_entity| {
+                add_guild_membership_entity.guild_id == 1
+                    && add_guild_membership_entity.adventurer_id == Some(3)
+                    && add_guild_membership_entity.guild_commander_id.is_none()
+                    && add_guild_membership_entity.role == GuildMembershipRoles::Member.to_string()
+            })
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        let use_case = GuildsUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case
+            .add_member(
+                1,
+                1,
+                AddGuildMemberModel {
+                    adventurer_id: Some(3),
+                    guild_commander_id: None,
+                    role: None,
+                },
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_member_fails_when_guild_commander_is_a_member() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo.expect_membership().returning(|_, _| {
+            Box::pin(async {
+                Ok(Some(guild_commander_membership(
+                    GuildMembershipRoles::Member,
+                )))
+            })
+        });
+
+        mock_guilds_repo.expect_add_member().never();
+
+        let use_case = GuildsUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case
+            .add_member(
+                1,
+                1,
+                AddGuildMemberModel {
+                    adventurer_id: Some(3),
+                    guild_commander_id: None,
+                    role: None,
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Only the Owner or an Officer can manage this guild"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_add_member_fails_when_adventurer_is_made_officer() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo.expect_membership().returning(|_, _| {
+            Box::pin(async {
+                Ok(Some(guild_commander_membership(
+                    GuildMembershipRoles::Officer,
+                )))
+            })
+        });
+
+        mock_guilds_repo.expect_add_member().never();
+
+        let use_case = GuildsUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case
+            .add_member(
+                1,
+                1,
+                AddGuildMemberModel {
+                    adventurer_id: Some(3),
+                    guild_commander_id: None,
+                    role: Some(GuildMembershipRoles::Officer),
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Adventurers can only be Members of a guild"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_view_fails_when_not_a_member() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo
+            .expect_membership()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
+        mock_guilds_repo.expect_find().never();
+
+        let use_case = GuildsUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case.view(2, GuildMember::Adventurer(3)).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "You are not a member of this guild"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_remove_member_removes_adventurer() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo.expect_membership().returning(|_, _| {
+            Box::pin(async {
+                Ok(Some(guild_commander_membership(
+                    GuildMembershipRoles::Owner,
+                )))
+            })
+        });
+
+        mock_guilds_repo
+            .expect_find_membership()
+            .returning(|_| Box::pin(async { Ok(adventurer_membership(5, 3)) }));
+
+        // the open quests, waitlist entries, applications and invitations go with the membership
+        mock_guilds_repo
+            .expect_remove_member()
+            .withf(|guild_id, membership_id| *guild_id == 1 && *membership_id == 5)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let use_case = GuildsUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case.remove_member(1, 1, 5).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_change_member_role_fails_when_officer_demotes_officer() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo.expect_membership().returning(|_, _| {
+            Box::pin(async {
+                Ok(Some(guild_commander_membership(
+                    GuildMembershipRoles::Officer,
+                )))
+            })
+        });
+
+        mock_guilds_repo.expect_find_membership().returning(|_| {
+            Box::pin(async {
+                Ok(GuildMembershipEntity {
+                    id: 6,
+                    guild_commander_id: Some(2),
+                    ..guild_commander_membership(GuildMembershipRoles::Officer)
+                })
+            })
+        });
+
+        mock_guilds_repo.expect_change_member_role().never();
+
+        let use_case = GuildsUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case
+            .change_member_role(
+                1,
+                1,
+                6,
+                EditGuildMemberModel {
+                    role: GuildMembershipRoles::Member,
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Only the Owner can appoint or demote Officers"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_accept_invite_fails_when_expired() {
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo
+            .expect_find_invite_by_code()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(GuildInviteEntity {
+                        id: 1,
+                        guild_id: 1,
+                        guild_commander_id: 1,
+                        code: "abcdef123456".to_string(),
+                        max_uses: None,
+                        uses: 0,
+                        expires_at: Some(
+                            Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0)
+                                .unwrap()
+                                .naive_utc(),
+                        ),
+                        revoked_at: None,
+                        created_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_guilds_repo.expect_accept_invite().never();
+
+        let use_case = GuildsUseCase::new(Arc::new(mock_guilds_repo));
+
+        let result = use_case.accept_invite("abcdef123456".to_string(), 3).await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "The invite has expired")
+    }
+}
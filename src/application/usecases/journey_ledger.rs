@@ -3,11 +3,16 @@ use std::sync::Arc;
 use anyhow::{Ok, Result};
 
 use crate::domain::{
+    entities::wallet_transactions::AddWalletTransactionEntity,
     repositories::{
         journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
     },
     value_objects::{
-        quest_adventurer_junction::MAX_ADVENTURERS_PER_QUEST, quest_statuses::QuestStatuses,
+        domain_events::DomainEvent,
+        quest_milestone_model::all_required_milestones_done,
+        quest_rewards::split_reward,
+        quest_statuses::{prerequisites_completed, QuestStatuses},
+        wallet_transaction_kinds::WalletTransactionKinds,
     },
 };
 
@@ -42,15 +47,49 @@ where
         let can_update = (quest.status == QuestStatuses::Open.to_string()
             || quest.status == QuestStatuses::Failed.to_string())
             && adventurers_count > 0
-            && adventurers_count <= MAX_ADVENTURERS_PER_QUEST;
+            && adventurers_count <= quest.capacity as i64;
 
         if !can_update {
             return Err(anyhow::anyhow!("Cannot changed status of this quest"));
         }
 
+        if let Some(max_attempts) = quest.max_attempts {
+            let attempts = self
+                .quest_viewing_repository
+                .attempts_by_quest_id(quest_id)
+                .await?;
+
+            if attempts.len() >= max_attempts as usize {
+                return Err(anyhow::anyhow!(
+                    "The quest has used all of its {} attempts",
+                    max_attempts
+                ));
+            }
+        }
+
+        // a prerequisite can be reopened after the crew joined, so departing checks it again
+        let prerequisites = self
+            .quest_viewing_repository
+            .prerequisites_by_quest_id(quest_id)
+            .await?;
+
+        if !prerequisites_completed(&prerequisites) {
+            return Err(anyhow::anyhow!(
+                "The quest is locked until its prerequisites are completed"
+            ));
+        }
+
         let result = self
             .journey_ledger_repository
-            .in_journey(quest_id, guild_commander_id)
+            .in_journey(
+                quest_id,
+                guild_commander_id,
+                DomainEvent::QuestStarted {
+                    quest_id,
+                    guild_id: quest.guild_id,
+                    guild_commander_id,
+                },
+            )
             .await?;
 
         Ok(result)
@@ -65,9 +104,52 @@ where
             return Err(anyhow::anyhow!("Cannot changed status of this quest"));
         }
 
+        let milestones = self
+            .quest_viewing_repository
+            .milestones_by_quest_id(quest_id)
+            .await?;
+
+        if !all_required_milestones_done(&milestones) {
+            return Err(anyhow::anyhow!(
+                "All required milestones must be done before the quest is completed"
+            ));
+        }
+
+        // the crew cannot change while the quest is InJourney, so the split stays valid
+        let crew = self
+            .quest_viewing_repository
+            .crew_by_quest_id(quest_id)
+            .await?;
+
+        let weights: Vec<(i32, i32)> = crew
+            .iter()
+            .map(|(junction, _)| (junction.adventurer_id, junction.reward_weight))
+            .collect();
+
+        let rewards = split_reward(quest.reward, &weights)
+            .into_iter()
+            .map(|(adventurer_id, amount)| AddWalletTransactionEntity {
+                adventurer_id,
+                quest_id: Some(quest_id),
+                amount,
+                kind: WalletTransactionKinds::QuestReward.to_string(),
+                created_at: chrono::Utc::now().naive_utc(),
+            })
+            .collect();
+
         let result = self
             .journey_ledger_repository
-            .to_completed(quest_id, guild_commander_id)
+            .to_completed(
+                quest_id,
+                guild_commander_id,
+                rewards,
+                quest.experience,
+                DomainEvent::QuestCompleted {
+                    quest_id,
+                    guild_id: quest.guild_id,
+                    guild_commander_id,
+                },
+            )
             .await?;
 
         Ok(result)
@@ -84,7 +166,16 @@ where
 
         let result = self
             .journey_ledger_repository
-            .to_failed(quest_id, guild_commander_id)
+            .to_failed(
+                quest_id,
+                guild_commander_id,
+                -quest.experience_penalty,
+                DomainEvent::QuestFailed {
+                    quest_id,
+                    guild_id: quest.guild_id,
+                    guild_commander_id,
+                },
+            )
             .await?;
 
         Ok(result)
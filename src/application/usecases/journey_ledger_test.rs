@@ -0,0 +1,578 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::{Arc, Mutex};
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::usecases::{
+            journey_ledger::JourneyLedgerUseCase, tests::common::quest_entity,
+        },
+        domain::{
+            entities::{
+                adventurers::AdventurerEntity, quest_attempts::QuestAttemptEntity,
+                quest_milestones::QuestMilestoneEntity, quests::QuestEntity,
+            },
+            repositories::{
+                journey_ledger::MockJourneyLedgerRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                domain_events::DomainEventTypes,
+                quest_adventurer_junction::QuestAdventurerJunction,
+                quest_attempt_outcomes::QuestAttemptOutcomes, quest_statuses::QuestStatuses,
+                wallet_transaction_kinds::WalletTransactionKinds,
+            },
+        },
+    };
+
+    fn quest_in_journey(reward: i64) -> QuestEntity {
+        QuestEntity {
+            status: QuestStatuses::InJourney.to_string(),
+            reward,
+            experience: 50,
+            experience_penalty: 20,
+            ..quest_entity(1)
+        }
+    }
+
+    fn crew_member(
+        adventurer_id: i32,
+        reward_weight: i32,
+    ) -> (QuestAdventurerJunction, AdventurerEntity) {
+        (
+            QuestAdventurerJunction {
+                quest_id: 1,
+                adventurer_id,
+                joined_at: Utc
+                    .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                role: None,
+                reward_weight,
+            },
+            AdventurerEntity {
+                id: adventurer_id,
+                username: format!("adventurer {}", adventurer_id),
+                created_at: Utc
+                    .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                updated_at: Utc
+                    .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                experience: 0,
+                level: 1,
+                user_id: adventurer_id,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_splits_reward_evenly() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_in_journey(100)) }));
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_viewing_repo
+            .expect_crew_by_quest_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        crew_member(1, 1),
+                        crew_member(2, 1),
+                        crew_member(3, 1),
+                    ])
+                })
+            });
+
+        mock_journey_ledger_repo
+            .expect_to_completed()
+            .withf(|_, _, rewards, _, _| {
+                let amounts: Vec<(i32, i64)> = rewards
+                    .iter()
+                    .map(|reward| (reward.adventurer_id, reward.amount))
+                    .collect();
+
+                amounts == vec![(1, 34), (2, 33), (3, 33)]
+                    && rewards.iter().all(|reward| {
+                        reward.quest_id == Some(1)
+                            && reward.kind == WalletTransactionKinds::QuestReward.to_string()
+                    })
+            })
+            .returning(|quest_id, _, _, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.to_completed(1, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_splits_reward_by_weights() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_in_journey(100)) }));
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_viewing_repo
+            .expect_crew_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![crew_member(1, 3), crew_member(2, 1)]) }));
+
+        mock_journey_ledger_repo
+            .expect_to_completed()
+            .withf(|_, _, rewards, _, _| {
+                let amounts: Vec<(i32, i64)> = rewards
+                    .iter()
+                    .map(|reward| (reward.adventurer_id, reward.amount))
+                    .collect();
+
+                amounts == vec![(1, 75), (2, 25)]
+            })
+            .returning(|quest_id, _, _, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.to_completed(1, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_without_reward_credits_nobody() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_in_journey(0)) }));
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_viewing_repo
+            .expect_crew_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![crew_member(1, 1)]) }));
+
+        mock_journey_ledger_repo
+            .expect_to_completed()
+            .withf(|_, _, rewards, _, _| rewards.is_empty())
+            .returning(|quest_id, _, _, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.to_completed(1, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_awards_quest_experience() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_in_journey(0)) }));
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_viewing_repo
+            .expect_crew_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![crew_member(1, 1)]) }));
+
+        mock_journey_ledger_repo
+            .expect_to_completed()
+            .withf(|_, _, _, experience_change, _| *experience_change == 50)
+            .returning(|quest_id, _, _, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.to_completed(1, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    fn milestone(id: i32, required: bool, done: bool) -> QuestMilestoneEntity {
+        QuestMilestoneEntity {
+            id,
+            quest_id: 1,
+            position: id,
+            title: format!("milestone {}", id),
+            required,
+            completed_by: done.then_some(1),
+            completed_at: done.then(|| {
+                Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc()
+            }),
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_fails_with_required_milestone_left() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_in_journey(100)) }));
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_id()
+            .returning(|_| {
+                Box::pin(async { Ok(vec![milestone(1, true, true), milestone(2, true, false)]) })
+            });
+
+        mock_journey_ledger_repo.expect_to_completed().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.to_completed(1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "All required milestones must be done before the quest is completed"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_ignores_optional_milestones() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_in_journey(0)) }));
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_id()
+            .returning(|_| {
+                Box::pin(async { Ok(vec![milestone(1, true, true), milestone(2, false, false)]) })
+            });
+
+        mock_quest_viewing_repo
+            .expect_crew_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![crew_member(1, 1)]) }));
+
+        mock_journey_ledger_repo
+            .expect_to_completed()
+            .withf(|_, _, _, _, domain_event| {
+                domain_event.event_type() == DomainEventTypes::QuestCompleted
+            })
+            .returning(|quest_id, _, _, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.to_completed(1, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_twice_pays_the_crew_once() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        // both requests read the quest before either of them has completed it
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_in_journey(100)) }));
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_viewing_repo
+            .expect_crew_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![crew_member(1, 1)]) }));
+
+        // the update only matches while the quest is InJourney, so the second one rolls back
+        let payouts = Arc::new(Mutex::new(vec![]));
+        let in_journey = Arc::new(Mutex::new(true));
+
+        let paid = Arc::clone(&payouts);
+        mock_journey_ledger_repo
+            .expect_to_completed()
+            .times(2)
+            .returning(move |quest_id, _, rewards, _, _| {
+                let mut in_journey = in_journey.lock().unwrap();
+
+                if !*in_journey {
+                    return Box::pin(async {
+                        Err(anyhow::anyhow!("Cannot changed status of this quest"))
+                    });
+                }
+
+                *in_journey = false;
+                paid.lock()
+                    .unwrap()
+                    .extend(rewards.iter().map(|reward| reward.amount));
+
+                Box::pin(async move { Ok(quest_id) })
+            });
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let first = use_case.to_completed(1, 1).await;
+        let second = use_case.to_completed(1, 1).await;
+
+        assert!(first.is_ok());
+        assert_eq!(
+            second.unwrap_err().to_string(),
+            "Cannot changed status of this quest"
+        );
+        assert_eq!(*payouts.lock().unwrap(), vec![100]);
+    }
+
+    fn failed_quest(max_attempts: Option<i32>) -> QuestEntity {
+        QuestEntity {
+            status: QuestStatuses::Failed.to_string(),
+            max_attempts,
+            ..quest_in_journey(0)
+        }
+    }
+
+    fn failed_attempt(attempt_number: i32) -> (QuestAttemptEntity, Vec<i32>) {
+        (
+            QuestAttemptEntity {
+                id: attempt_number,
+                quest_id: 1,
+                attempt_number,
+                outcome: Some(QuestAttemptOutcomes::Failed.to_string()),
+                started_at: Utc
+                    .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                ended_at: Some(
+                    Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                ),
+            },
+            vec![1],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_in_journey_retries_failed_quest_within_max_attempts() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(failed_quest(Some(2))) }));
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        mock_quest_viewing_repo
+            .expect_attempts_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![failed_attempt(1)]) }));
+
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_journey_ledger_repo
+            .expect_in_journey()
+            .withf(|_, _, domain_event| domain_event.event_type() == DomainEventTypes::QuestStarted)
+            .times(1)
+            .returning(|quest_id, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.in_journey(1, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_in_journey_fails_when_attempts_used_up() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(failed_quest(Some(2))) }));
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        mock_quest_viewing_repo
+            .expect_attempts_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![failed_attempt(1), failed_attempt(2)]) }));
+
+        mock_journey_ledger_repo.expect_in_journey().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.in_journey(1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The quest has used all of its 2 attempts"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_in_journey_fails_when_prerequisite_is_not_completed() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(failed_quest(None)) }));
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![QuestEntity {
+                        id: 2,
+                        status: QuestStatuses::Open.to_string(),
+                        ..quest_in_journey(0)
+                    }])
+                })
+            });
+
+        mock_journey_ledger_repo.expect_in_journey().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.in_journey(1, 1).await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The quest is locked until its prerequisites are completed"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_in_journey_fails_when_crew_exceeds_capacity() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        capacity: 2,
+                        ..failed_quest(None)
+                    })
+                })
+            });
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(3) }));
+
+        mock_journey_ledger_repo.expect_in_journey().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.in_journey(1, 1).await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Cannot changed status of this quest"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_to_failed_reduces_experience_by_penalty() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_in_journey(100)) }));
+
+        mock_journey_ledger_repo
+            .expect_to_failed()
+            .withf(|_, _, experience_change, domain_event| {
+                *experience_change == -20
+                    && domain_event.event_type() == DomainEventTypes::QuestFailed
+            })
+            .returning(|quest_id, _, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.to_failed(1, 1).await;
+
+        assert!(result.is_ok());
+    }
+}
@@ -1,8 +1,39 @@
+pub mod admin;
+pub mod admin_test;
 pub mod adventurers;
 pub mod authentication;
+pub mod board_streaming;
+pub mod board_streaming_test;
 pub mod crew_switchboard;
-pub mod guild_commanders;
+pub mod crew_switchboard_test;
+pub mod guild_access;
+pub mod guild_access_test;
+pub mod guilds;
+pub mod guilds_test;
 pub mod journey_ledger;
+pub mod journey_ledger_test;
+pub mod notifications;
+pub mod notifications_test;
+pub mod outbox_dispatcher;
+pub mod outbox_dispatcher_test;
+pub mod quest_comments;
+pub mod quest_comments_test;
+pub mod quest_deadlines;
+pub mod quest_deadlines_test;
+pub mod quest_milestones;
+pub mod quest_milestones_test;
 pub mod quest_ops;
+pub mod quest_ops_test;
+pub mod quest_templates;
+pub mod quest_templates_test;
 pub mod quest_viewing;
-pub mod crew_switchboard_test;
\ No newline at end of file
+pub mod quest_viewing_test;
+#[cfg(test)]
+pub mod tests;
+pub mod users;
+pub mod users_test;
+pub mod wallet;
+pub mod webhook_dispatcher;
+pub mod webhook_dispatcher_test;
+pub mod webhooks;
+pub mod webhooks_test;
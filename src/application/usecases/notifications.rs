@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+
+use crate::{
+    application::domain_events::DomainEventSubscriber,
+    domain::{
+        entities::{
+            notification_preferences::AddNotificationPreferenceEntity,
+            notifications::AddNotificationEntity,
+        },
+        repositories::notifications::NotificationsRepository,
+        value_objects::{
+            domain_events::DomainEvent,
+            notification_model::{
+                notification_message, NotificationPreferenceModel, NotificationsFilter,
+                NotificationsPageModel, NOTIFIABLE_EVENT_TYPES,
+            },
+        },
+    },
+};
+
+pub struct NotificationsUseCase<T>
+where
+    T: NotificationsRepository + Send + Sync,
+{
+    notifications_repository: Arc<T>,
+}
+
+impl<T> NotificationsUseCase<T>
+where
+    T: NotificationsRepository + Send + Sync,
+{
+    pub fn new(notifications_repository: Arc<T>) -> Self {
+        Self {
+            notifications_repository,
+        }
+    }
+
+    pub async fn notifications(
+        &self,
+        user_id: i32,
+        filter: &NotificationsFilter,
+    ) -> Result<NotificationsPageModel> {
+        let page = filter.page();
+        let page_size = filter.page_size();
+
+        let notifications = self
+            .notifications_repository
+            .by_user(
+                user_id,
+                filter.unread_only(),
+                page_size,
+                (page - 1) * page_size,
+            )
+            .await?;
+
+        let total = self
+            .notifications_repository
+            .counting_by_user(user_id, filter.unread_only())
+            .await?;
+
+        let unread_count = self
+            .notifications_repository
+            .counting_by_user(user_id, true)
+            .await?;
+
+        Ok(NotificationsPageModel {
+            notifications: notifications
+                .iter()
+                .map(|notification| notification.to_model())
+                .collect(),
+            unread_count,
+            page,
+            page_size,
+            total,
+        })
+    }
+
+    pub async fn mark_read(&self, user_id: i32, notification_id: i32) -> Result<()> {
+        self.notifications_repository
+            .mark_read(user_id, notification_id)
+            .await
+    }
+
+    pub async fn mark_all_read(&self, user_id: i32) -> Result<usize> {
+        self.notifications_repository.mark_all_read(user_id).await
+    }
+
+    // one entry per notifiable event type, the ones never changed are on
+    pub async fn preferences(&self, user_id: i32) -> Result<Vec<NotificationPreferenceModel>> {
+        let preferences = self.notifications_repository.preferences(user_id).await?;
+
+        Ok(NOTIFIABLE_EVENT_TYPES
+            .iter()
+            .map(|event_type| NotificationPreferenceModel {
+                event_type: *event_type,
+                enabled: preferences
+                    .iter()
+                    .find(|preference| preference.event_type == event_type.to_string())
+                    .map(|preference| preference.enabled)
+                    .unwrap_or(true),
+            })
+            .collect())
+    }
+
+    // only the event types given are changed, the rest keep their setting
+    pub async fn set_preferences(
+        &self,
+        user_id: i32,
+        notification_preference_models: Vec<NotificationPreferenceModel>,
+    ) -> Result<Vec<NotificationPreferenceModel>> {
+        if let Some(notification_preference_model) =
+            notification_preference_models
+                .iter()
+                .find(|notification_preference_model| {
+                    !NOTIFIABLE_EVENT_TYPES.contains(&notification_preference_model.event_type)
+                })
+        {
+            return Err(anyhow::anyhow!(
+                "{} events do not send notifications",
+                notification_preference_model.event_type
+            ));
+        }
+
+        let now = chrono::Utc::now().naive_utc();
+
+        let add_notification_preference_entities = notification_preference_models
+            .iter()
+            .map(
+                |notification_preference_model| AddNotificationPreferenceEntity {
+                    user_id,
+                    event_type: notification_preference_model.event_type.to_string(),
+                    enabled: notification_preference_model.enabled,
+                    updated_at: now,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        if !add_notification_preference_entities.is_empty() {
+            self.notifications_repository
+                .set_preferences(add_notification_preference_entities)
+                .await?;
+        }
+
+        self.preferences(user_id).await
+    }
+
+    // the crew hears about their quest starting and ending, the guild commander about joins;
+    // event_id is the outbox row id, a retried event adds nothing for users it already reached
+    pub async fn notify(&self, event_id: i32, domain_event: &DomainEvent) -> Result<Vec<i32>> {
+        let quest_id = domain_event.quest_id();
+
+        let (user_ids, adventurer_name) = match domain_event {
+            DomainEvent::QuestStarted { .. }
+            | DomainEvent::QuestCompleted { .. }
+            | DomainEvent::QuestFailed { .. } => (
+                self.notifications_repository
+                    .crew_user_ids(quest_id)
+                    .await?,
+                None,
+            ),
+            DomainEvent::AdventurerJoined { adventurer_id, .. } => (
+                vec![
+                    self.notifications_repository
+                        .guild_commander_user_id(quest_id)
+                        .await?,
+                ],
+                Some(
+                    self.notifications_repository
+                        .adventurer_username(*adventurer_id)
+                        .await?,
+                ),
+            ),
+            _ => return Ok(vec![]),
+        };
+
+        let event_type = domain_event.event_type().to_string();
+
+        let muted_user_ids = self
+            .notifications_repository
+            .muted_user_ids(event_type.clone(), user_ids.clone())
+            .await?;
+
+        let user_ids: Vec<i32> = user_ids
+            .into_iter()
+            .filter(|user_id| !muted_user_ids.contains(user_id))
+            .collect();
+
+        if user_ids.is_empty() {
+            return Ok(user_ids);
+        }
+
+        let quest_name = self.notifications_repository.quest_name(quest_id).await?;
+
+        let message = notification_message(domain_event, &quest_name, adventurer_name.as_deref());
+
+        self.notifications_repository
+            .add(
+                user_ids
+                    .iter()
+                    .map(|user_id| AddNotificationEntity {
+                        user_id: *user_id,
+                        event_type: event_type.clone(),
+                        quest_id,
+                        guild_id: domain_event.guild_id(),
+                        message: message.clone(),
+                        outbox_event_id: event_id,
+                    })
+                    .collect(),
+            )
+            .await?;
+
+        Ok(user_ids)
+    }
+}
+
+#[async_trait]
+impl<T> DomainEventSubscriber for NotificationsUseCase<T>
+where
+    T: NotificationsRepository + Send + Sync,
+{
+    async fn handle(&self, event_id: i32, domain_event: &DomainEvent) -> Result<()> {
+        self.notify(event_id, domain_event).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::usecases::notifications::NotificationsUseCase,
+        domain::{
+            entities::notification_preferences::NotificationPreferenceEntity,
+            repositories::notifications::MockNotificationsRepository,
+            value_objects::{
+                domain_events::{DomainEvent, DomainEventTypes},
+                notification_model::NotificationPreferenceModel,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn test_notify_quest_completed_skips_muted_crew() {
+        let mut mock_notifications_repo = MockNotificationsRepository::new();
+
+        mock_notifications_repo
+            .expect_crew_user_ids()
+            .withf(|quest_id| *quest_id == 7)
+            .returning(|_| Box::pin(async { Ok(vec![2, 3, 4]) }));
+        mock_notifications_repo
+            .expect_muted_user_ids()
+            .withf(|event_type, user_ids| {
+                event_type == "QuestCompleted" && *user_ids == vec![2, 3, 4]
+            })
+            .returning(|_, _| Box::pin(async { Ok(vec![3]) }));
+        mock_notifications_repo
+            .expect_quest_name()
+            .returning(|_| Box::pin(async { Ok("slay the dragon".to_string()) }));
+        mock_notifications_repo
+            .expect_add()
+            .withf(|add_notification_entities| {
+                add_notification_entities
+                    .iter()
+                    .map(|add_notification_entity| add_notification_entity.user_id)
+                    .collect::<Vec<_>>()
+                    == vec![2, 4]
+                    && add_notification_entities
+                        .iter()
+                        .all(|add_notification_entity| {
+                            add_notification_entity.guild_id == 1
+                                && add_notification_entity.outbox_event_id == 11
+                                && add_notification_entity.message
+                                    == "The quest \"slay the dragon\" has been completed"
+                        })
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = NotificationsUseCase::new(Arc::new(mock_notifications_repo));
+
+        let result = use_case
+            .notify(
+                11,
+                &DomainEvent::QuestCompleted {
+                    quest_id: 7,
+                    guild_id: 1,
+                    guild_commander_id: 1,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, vec![2, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_notify_adventurer_joined_tells_guild_commander() {
+        let mut mock_notifications_repo = MockNotificationsRepository::new();
+
+        mock_notifications_repo.expect_crew_user_ids().times(0);
+        mock_notifications_repo
+            .expect_guild_commander_user_id()
+            .returning(|_| Box::pin(async { Ok(9) }));
+        mock_notifications_repo
+            .expect_adventurer_username()
+            .withf(|adventurer_id| *adventurer_id == 5)
+            .returning(|_| Box::pin(async { Ok("ann".to_string()) }));
+        mock_notifications_repo
+            .expect_muted_user_ids()
+            .returning(|_, _| Box::pin(async { Ok(vec![]) }));
+        mock_notifications_repo
+            .expect_quest_name()
+            .returning(|_| Box::pin(async { Ok("slay the dragon".to_string()) }));
+        mock_notifications_repo
+            .expect_add()
+            .withf(|add_notification_entities| {
+                add_notification_entities.len() == 1
+                    && add_notification_entities[0].user_id == 9
+                    && add_notification_entities[0].event_type == "AdventurerJoined"
+                    && add_notification_entities[0].message
+                        == "ann joined the quest \"slay the dragon\""
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = NotificationsUseCase::new(Arc::new(mock_notifications_repo));
+
+        let result = use_case
+            .notify(
+                12,
+                &DomainEvent::AdventurerJoined {
+                    quest_id: 7,
+                    guild_id: 1,
+                    adventurer_id: 5,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, vec![9]);
+    }
+
+    #[tokio::test]
+    async fn test_set_preferences_rejects_events_without_notifications() {
+        let mut mock_notifications_repo = MockNotificationsRepository::new();
+
+        mock_notifications_repo.expect_set_preferences().times(0);
+
+        let use_case = NotificationsUseCase::new(Arc::new(mock_notifications_repo));
+
+        let result = use_case
+            .set_preferences(
+                1,
+                vec![NotificationPreferenceModel {
+                    event_type: DomainEventTypes::QuestEdited,
+                    enabled: false,
+                }],
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "QuestEdited events do not send notifications"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preferences_default_to_enabled() {
+        let mut mock_notifications_repo = MockNotificationsRepository::new();
+
+        mock_notifications_repo
+            .expect_preferences()
+            .returning(|user_id| {
+                Box::pin(async move {
+                    Ok(vec![NotificationPreferenceEntity {
+                        id: 1,
+                        user_id,
+                        event_type: "QuestFailed".to_string(),
+                        enabled: false,
+                        updated_at: Utc
+                            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    }])
+                })
+            });
+
+        let use_case = NotificationsUseCase::new(Arc::new(mock_notifications_repo));
+
+        let result = use_case.preferences(1).await.unwrap();
+
+        assert_eq!(
+            result
+                .iter()
+                .filter(|preference| !preference.enabled)
+                .map(|preference| preference.event_type)
+                .collect::<Vec<_>>(),
+            vec![DomainEventTypes::QuestFailed]
+        );
+        assert_eq!(result.len(), 4);
+    }
+}
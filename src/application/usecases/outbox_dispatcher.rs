@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Duration;
+use tracing::{error, warn};
+
+use crate::{
+    application::{clock::Clock, domain_events::DomainEventPublisher},
+    domain::{
+        entities::outbox::OutboxEventEntity,
+        repositories::outbox::OutboxRepository,
+        value_objects::outbox_model::{
+            outbox_retry_delay, MAX_OUTBOX_ATTEMPTS, OUTBOX_BATCH_SIZE, OUTBOX_LEASE_SECONDS,
+        },
+    },
+};
+
+pub struct OutboxDispatcherUseCase<T, P, C>
+where
+    T: OutboxRepository + Send + Sync,
+    P: DomainEventPublisher + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    outbox_repository: Arc<T>,
+    domain_event_publisher: Arc<P>,
+    clock: Arc<C>,
+}
+
+impl<T, P, C> OutboxDispatcherUseCase<T, P, C>
+where
+    T: OutboxRepository + Send + Sync,
+    P: DomainEventPublisher + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    pub fn new(outbox_repository: Arc<T>, domain_event_publisher: Arc<P>, clock: Arc<C>) -> Self {
+        Self {
+            outbox_repository,
+            domain_event_publisher,
+            clock,
+        }
+    }
+
+    // delivers every due event once and returns the ids that were delivered, failed deliveries
+    // are pushed back with a growing delay until they run out of attempts; a retry only reaches
+    // the subscribers that have not handled the event yet
+    pub async fn dispatch_due(&self) -> Result<Vec<i32>> {
+        let now = self.clock.now();
+
+        let outbox_events = self
+            .outbox_repository
+            .claim_due(
+                now,
+                now + Duration::seconds(OUTBOX_LEASE_SECONDS),
+                OUTBOX_BATCH_SIZE,
+            )
+            .await?;
+
+        let mut delivered_ids: Vec<i32> = Vec::new();
+
+        for outbox_event in outbox_events.into_iter() {
+            match self.deliver(&outbox_event).await {
+                Ok(()) => {
+                    self.outbox_repository
+                        .mark_delivered(outbox_event.id, self.clock.now())
+                        .await?;
+                    delivered_ids.push(outbox_event.id);
+                }
+                Err(e) => self.record_failure(&outbox_event, e.to_string()).await?,
+            }
+        }
+
+        Ok(delivered_ids)
+    }
+
+    async fn deliver(&self, outbox_event: &OutboxEventEntity) -> Result<()> {
+        let domain_event = outbox_event.to_domain_event()?;
+
+        let handled_by = self
+            .outbox_repository
+            .handled_subscribers(outbox_event.id)
+            .await?;
+
+        let publish_outcome = self
+            .domain_event_publisher
+            .publish(outbox_event.id, domain_event, handled_by)
+            .await;
+
+        if !publish_outcome.handled_by.is_empty() {
+            self.outbox_repository
+                .mark_handled(
+                    outbox_event.id,
+                    publish_outcome.handled_by,
+                    self.clock.now(),
+                )
+                .await?;
+        }
+
+        publish_outcome.result
+    }
+
+    async fn record_failure(
+        &self,
+        outbox_event: &OutboxEventEntity,
+        last_error: String,
+    ) -> Result<()> {
+        let attempts = outbox_event.attempts + 1;
+        let now = self.clock.now();
+
+        if attempts >= MAX_OUTBOX_ATTEMPTS {
+            error!(
+                "🔴 Giving up on outbox event id: {} after {} attempts: {}",
+                outbox_event.id, attempts, last_error
+            );
+
+            return self
+                .outbox_repository
+                .mark_failed(outbox_event.id, now, last_error)
+                .await;
+        }
+
+        warn!(
+            "Outbox event id: {} failed on attempt {}: {}",
+            outbox_event.id, attempts, last_error
+        );
+
+        self.outbox_repository
+            .schedule_retry(
+                outbox_event.id,
+                now + outbox_retry_delay(attempts),
+                last_error,
+            )
+            .await
+    }
+}
@@ -0,0 +1,266 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+
+    use crate::{
+        application::{
+            clock::MockClock,
+            domain_events::{MockDomainEventPublisher, PublishOutcome},
+            usecases::outbox_dispatcher::OutboxDispatcherUseCase,
+        },
+        domain::{
+            entities::outbox::{AddOutboxEventEntity, OutboxEventEntity},
+            repositories::outbox::MockOutboxRepository,
+            value_objects::{
+                domain_events::DomainEvent,
+                outbox_model::{MAX_OUTBOX_ATTEMPTS, OUTBOX_LEASE_SECONDS},
+            },
+        },
+    };
+
+    fn now() -> NaiveDateTime {
+        Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn quest_started(quest_id: i32) -> DomainEvent {
+        DomainEvent::QuestStarted {
+            quest_id,
+            guild_id: 1,
+            guild_commander_id: 1,
+        }
+    }
+
+    fn outbox_event(id: i32, attempts: i32) -> OutboxEventEntity {
+        let add_outbox_event_entity = AddOutboxEventEntity::new(&quest_started(id)).unwrap();
+
+        OutboxEventEntity {
+            id,
+            event_type: add_outbox_event_entity.event_type,
+            payload: add_outbox_event_entity.payload,
+            attempts,
+            next_attempt_at: now(),
+            last_error: None,
+            delivered_at: None,
+            failed_at: None,
+            created_at: now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_due_marks_published_events_delivered() {
+        let mut mock_outbox_repo = MockOutboxRepository::new();
+        let mut mock_domain_event_publisher = MockDomainEventPublisher::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_outbox_repo
+            .expect_claim_due()
+            .withf(|current, lease_until, _| {
+                *current == now() && *lease_until == now() + Duration::seconds(OUTBOX_LEASE_SECONDS)
+            })
+            .returning(|_, _, _| {
+                Box::pin(async { Ok(vec![outbox_event(1, 0), outbox_event(2, 0)]) })
+            });
+
+        mock_outbox_repo
+            .expect_handled_subscribers()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_domain_event_publisher
+            .expect_publish()
+            .withf(|event_id, domain_event, handled_by| {
+                *domain_event == quest_started(*event_id) && handled_by.is_empty()
+            })
+            .times(2)
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    PublishOutcome {
+                        handled_by: vec!["logger".to_string()],
+                        result: Ok(()),
+                    }
+                })
+            });
+
+        mock_outbox_repo
+            .expect_mark_handled()
+            .times(2)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        mock_outbox_repo
+            .expect_mark_delivered()
+            .times(2)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_outbox_repo.expect_schedule_retry().times(0);
+
+        let use_case = OutboxDispatcherUseCase::new(
+            Arc::new(mock_outbox_repo),
+            Arc::new(mock_domain_event_publisher),
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.dispatch_due().await.unwrap();
+
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_due_backs_off_failed_delivery() {
+        let mut mock_outbox_repo = MockOutboxRepository::new();
+        let mut mock_domain_event_publisher = MockDomainEventPublisher::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_outbox_repo
+            .expect_claim_due()
+            .returning(|_, _, _| Box::pin(async { Ok(vec![outbox_event(1, 2)]) }));
+
+        mock_outbox_repo
+            .expect_handled_subscribers()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_domain_event_publisher
+            .expect_publish()
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    PublishOutcome {
+                        handled_by: vec![],
+                        result: Err(anyhow::anyhow!("sink is down")),
+                    }
+                })
+            });
+        mock_outbox_repo.expect_mark_handled().times(0);
+
+        // third attempt failed, the next one waits 2s * 2^2
+        mock_outbox_repo
+            .expect_schedule_retry()
+            .withf(|outbox_event_id, next_attempt_at, last_error| {
+                *outbox_event_id == 1
+                    && *next_attempt_at == now() + Duration::seconds(8)
+                    && last_error == "sink is down"
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        mock_outbox_repo.expect_mark_delivered().times(0);
+        mock_outbox_repo.expect_mark_failed().times(0);
+
+        let use_case = OutboxDispatcherUseCase::new(
+            Arc::new(mock_outbox_repo),
+            Arc::new(mock_domain_event_publisher),
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.dispatch_due().await.unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_due_gives_up_after_max_attempts() {
+        let mut mock_outbox_repo = MockOutboxRepository::new();
+        let mut mock_domain_event_publisher = MockDomainEventPublisher::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_outbox_repo.expect_claim_due().returning(|_, _, _| {
+            Box::pin(async { Ok(vec![outbox_event(1, MAX_OUTBOX_ATTEMPTS - 1)]) })
+        });
+
+        mock_outbox_repo
+            .expect_handled_subscribers()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_domain_event_publisher
+            .expect_publish()
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    PublishOutcome {
+                        handled_by: vec![],
+                        result: Err(anyhow::anyhow!("sink is down")),
+                    }
+                })
+            });
+        mock_outbox_repo.expect_mark_handled().times(0);
+
+        mock_outbox_repo
+            .expect_mark_failed()
+            .withf(|outbox_event_id, failed_at, _| *outbox_event_id == 1 && *failed_at == now())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        mock_outbox_repo.expect_schedule_retry().times(0);
+
+        let use_case = OutboxDispatcherUseCase::new(
+            Arc::new(mock_outbox_repo),
+            Arc::new(mock_domain_event_publisher),
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.dispatch_due().await.unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_due_retries_only_subscribers_that_have_not_handled_the_event() {
+        let mut mock_outbox_repo = MockOutboxRepository::new();
+        let mut mock_domain_event_publisher = MockDomainEventPublisher::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_outbox_repo
+            .expect_claim_due()
+            .returning(|_, _, _| Box::pin(async { Ok(vec![outbox_event(1, 1)]) }));
+
+        mock_outbox_repo
+            .expect_handled_subscribers()
+            .withf(|outbox_event_id| *outbox_event_id == 1)
+            .returning(|_| Box::pin(async { Ok(vec!["board_events".to_string()]) }));
+
+        mock_domain_event_publisher
+            .expect_publish()
+            .withf(|_, _, handled_by| *handled_by == vec!["board_events".to_string()])
+            .times(1)
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    PublishOutcome {
+                        handled_by: vec!["notifications".to_string()],
+                        result: Err(anyhow::anyhow!("sink is down")),
+                    }
+                })
+            });
+
+        // recorded even though the webhooks failed again
+        mock_outbox_repo
+            .expect_mark_handled()
+            .withf(|outbox_event_id, subscribers, _| {
+                *outbox_event_id == 1 && *subscribers == vec!["notifications".to_string()]
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        mock_outbox_repo
+            .expect_schedule_retry()
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        mock_outbox_repo.expect_mark_delivered().times(0);
+
+        let use_case = OutboxDispatcherUseCase::new(
+            Arc::new(mock_outbox_repo),
+            Arc::new(mock_domain_event_publisher),
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.dispatch_due().await.unwrap();
+
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    entities::{
+        quest_comments::{AddQuestCommentEntity, QuestCommentEntity},
+        quests::QuestEntity,
+    },
+    repositories::{
+        quest_comments::QuestCommentsRepository, quest_viewing::QuestViewingRepository,
+    },
+    value_objects::{
+        quest_comment_author_roles::QuestCommentAuthorRoles,
+        quest_comment_model::{
+            AddQuestCommentModel, EditQuestCommentModel, QuestCommentsPageModel,
+            QuestCommentsPagination, MAX_QUEST_COMMENT_LENGTH,
+        },
+    },
+};
+
+pub struct QuestCommentsUseCase<T1, T2>
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    quest_comments_repository: Arc<T1>,
+    quest_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> QuestCommentsUseCase<T1, T2>
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(quest_comments_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            quest_comments_repository,
+            quest_viewing_repository,
+        }
+    }
+
+    pub async fn comments(
+        &self,
+        quest_id: i32,
+        pagination: &QuestCommentsPagination,
+    ) -> Result<QuestCommentsPageModel> {
+        // make sure the quest exists and is not removed
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let page = pagination.page();
+        let page_size = pagination.page_size();
+
+        let comments = self
+            .quest_comments_repository
+            .comments_by_quest_id(quest_id, page_size, (page - 1) * page_size)
+            .await?;
+
+        let total = self
+            .quest_comments_repository
+            .counting_by_quest_id(quest_id)
+            .await?;
+
+        Ok(QuestCommentsPageModel {
+            comments: comments.iter().map(|comment| comment.to_model()).collect(),
+            page,
+            page_size,
+            total,
+        })
+    }
+
+    pub async fn post(
+        &self,
+        quest_id: i32,
+        author_id: i32,
+        author_role: QuestCommentAuthorRoles,
+        add_quest_comment_model: AddQuestCommentModel,
+    ) -> Result<i32> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        self.check_quest_member(&quest, author_id, &author_role)
+            .await?;
+
+        let body = check_comment_body(&add_quest_comment_model.body)?;
+
+        let now = chrono::Utc::now().naive_utc();
+
+        let comment_id = self
+            .quest_comments_repository
+            .add(AddQuestCommentEntity {
+                quest_id,
+                author_id,
+                author_role: author_role.to_string(),
+                body,
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+
+        Ok(comment_id)
+    }
+
+    pub async fn edit(
+        &self,
+        quest_id: i32,
+        comment_id: i32,
+        author_id: i32,
+        author_role: QuestCommentAuthorRoles,
+        edit_quest_comment_model: EditQuestCommentModel,
+    ) -> Result<()> {
+        let comment = self.quest_comments_repository.find(comment_id).await?;
+
+        check_comment_author(&comment, quest_id, author_id, &author_role)?;
+
+        let body = check_comment_body(&edit_quest_comment_model.body)?;
+
+        self.quest_comments_repository
+            .edit(comment_id, body)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove(
+        &self,
+        quest_id: i32,
+        comment_id: i32,
+        author_id: i32,
+        author_role: QuestCommentAuthorRoles,
+    ) -> Result<()> {
+        let comment = self.quest_comments_repository.find(comment_id).await?;
+
+        check_comment_author(&comment, quest_id, author_id, &author_role)?;
+
+        self.quest_comments_repository.remove(comment_id).await?;
+
+        Ok(())
+    }
+
+    // only the commander of the quest and its crew take part in the thread
+    async fn check_quest_member(
+        &self,
+        quest: &QuestEntity,
+        author_id: i32,
+        author_role: &QuestCommentAuthorRoles,
+    ) -> Result<()> {
+        let is_member = match author_role {
+            QuestCommentAuthorRoles::GuildCommander => quest.guild_commander_id == author_id,
+            QuestCommentAuthorRoles::Adventurer => {
+                self.quest_comments_repository
+                    .is_crew_member(quest.id, author_id)
+                    .await?
+            }
+        };
+
+        if !is_member {
+            return Err(anyhow::anyhow!(
+                "Only the guild commander and crew of the quest can comment"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn check_comment_author(
+    comment: &QuestCommentEntity,
+    quest_id: i32,
+    author_id: i32,
+    author_role: &QuestCommentAuthorRoles,
+) -> Result<()> {
+    if comment.quest_id != quest_id {
+        return Err(anyhow::anyhow!("Comment not found"));
+    }
+
+    if comment.author_id != author_id || comment.author_role != author_role.to_string() {
+        return Err(anyhow::anyhow!("You are not the author of this comment"));
+    }
+
+    Ok(())
+}
+
+fn check_comment_body(body: &str) -> Result<String> {
+    let body = body.trim();
+
+    if body.is_empty() {
+        return Err(anyhow::anyhow!("The comment cannot be empty"));
+    }
+
+    if body.chars().count() > MAX_QUEST_COMMENT_LENGTH {
+        return Err(anyhow::anyhow!(
+            "The comment cannot be longer than {} characters",
+            MAX_QUEST_COMMENT_LENGTH
+        ));
+    }
+
+    Ok(body.to_string())
+}
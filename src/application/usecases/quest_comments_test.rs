@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::usecases::{
+            quest_comments::QuestCommentsUseCase, tests::common::quest_entity,
+        },
+        domain::{
+            entities::quest_comments::QuestCommentEntity,
+            repositories::{
+                quest_comments::MockQuestCommentsRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                quest_comment_author_roles::QuestCommentAuthorRoles,
+                quest_comment_model::{
+                    AddQuestCommentModel, EditQuestCommentModel, QuestCommentsPagination,
+                },
+            },
+        },
+    };
+
+    fn comment() -> QuestCommentEntity {
+        QuestCommentEntity {
+            id: 1,
+            quest_id: 1,
+            author_id: 2,
+            author_role: QuestCommentAuthorRoles::Adventurer.to_string(),
+            body: "meet at the gate".to_string(),
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_by_guild_commander_success() {
+        let mut mock_quest_comments_repo = MockQuestCommentsRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
+        mock_quest_comments_repo.expect_is_crew_member().never();
+
+        mock_quest_comments_repo
+            .expect_add()
+            .withf(|add_quest_comment_entity| {
+                add_quest_comment_entity.author_role
+                    == QuestCommentAuthorRoles::GuildCommander.to_string()
+                    && add_quest_comment_entity.body == "meet at the gate"
+            })
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        let use_case = QuestCommentsUseCase::new(
+            Arc::new(mock_quest_comments_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case
+            .post(
+                1,
+                1,
+                QuestCommentAuthorRoles::GuildCommander,
+                AddQuestCommentModel {
+                    body: "  meet at the gate ".to_string(),
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_post_fails_when_adventurer_is_not_in_crew() {
+        let mut mock_quest_comments_repo = MockQuestCommentsRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
+        mock_quest_comments_repo
+            .expect_is_crew_member()
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+
+        mock_quest_comments_repo.expect_add().never();
+
+        let use_case = QuestCommentsUseCase::new(
+            Arc::new(mock_quest_comments_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case
+            .post(
+                1,
+                2,
+                QuestCommentAuthorRoles::Adventurer,
+                AddQuestCommentModel {
+                    body: "can I come?".to_string(),
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Only the guild commander and crew of the quest can comment"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_edit_fails_when_not_author() {
+        let mut mock_quest_comments_repo = MockQuestCommentsRepository::new();
+        let mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_comments_repo
+            .expect_find()
+            .returning(|_| Box::pin(async { Ok(comment()) }));
+
+        mock_quest_comments_repo.expect_edit().never();
+
+        let use_case = QuestCommentsUseCase::new(
+            Arc::new(mock_quest_comments_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        // same id as the author but signed in as the other role
+        let result = use_case
+            .edit(
+                1,
+                1,
+                2,
+                QuestCommentAuthorRoles::GuildCommander,
+                EditQuestCommentModel {
+                    body: "changed".to_string(),
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "You are not the author of this comment"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_comments_paginates() {
+        let mut mock_quest_comments_repo = MockQuestCommentsRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_entity(1)) }));
+
+        mock_quest_comments_repo
+            .expect_comments_by_quest_id()
+            .withf(|_, limit, offset| *limit == 10 && *offset == 20)
+            .returning(|_, _, _| Box::pin(async { Ok(vec![comment()]) }));
+
+        mock_quest_comments_repo
+            .expect_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(21) }));
+
+        let use_case = QuestCommentsUseCase::new(
+            Arc::new(mock_quest_comments_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case
+            .comments(
+                1,
+                &QuestCommentsPagination {
+                    page: Some(3),
+                    page_size: Some(10),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.page, 3);
+        assert_eq!(result.total, 21);
+        assert_eq!(result.comments.len(), 1);
+    }
+}
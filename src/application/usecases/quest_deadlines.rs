@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tracing::error;
+
+use crate::{
+    application::{clock::Clock, usecases::journey_ledger::JourneyLedgerUseCase},
+    domain::repositories::{
+        journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
+    },
+};
+
+pub struct QuestDeadlinesUseCase<T1, T2, C>
+where
+    T1: JourneyLedgerRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    journey_ledger_use_case: Arc<JourneyLedgerUseCase<T1, T2>>,
+    quest_viewing_repository: Arc<T2>,
+    clock: Arc<C>,
+}
+
+impl<T1, T2, C> QuestDeadlinesUseCase<T1, T2, C>
+where
+    T1: JourneyLedgerRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    pub fn new(
+        journey_ledger_use_case: Arc<JourneyLedgerUseCase<T1, T2>>,
+        quest_viewing_repository: Arc<T2>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            journey_ledger_use_case,
+            quest_viewing_repository,
+            clock,
+        }
+    }
+
+    // moves every overdue journey to Failed and returns the ids that were moved
+    pub async fn fail_overdue_quests(&self) -> Result<Vec<i32>> {
+        let now = self.clock.now();
+
+        let overdue_quests = self
+            .quest_viewing_repository
+            .overdue_quests_in_journey(now)
+            .await?;
+
+        let mut failed_quest_ids: Vec<i32> = Vec::new();
+
+        for quest in overdue_quests.into_iter() {
+            // fail through the journey ledger so the same status guards apply as for commanders
+            match self
+                .journey_ledger_use_case
+                .to_failed(quest.id, quest.guild_commander_id)
+                .await
+            {
+                Ok(quest_id) => failed_quest_ids.push(quest_id),
+                Err(e) => error!("🔴 Failed to fail overdue quest id: {}: {}", quest.id, e),
+            }
+        }
+
+        Ok(failed_quest_ids)
+    }
+}
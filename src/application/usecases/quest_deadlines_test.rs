@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{NaiveDateTime, TimeZone, Utc};
+
+    use crate::{
+        application::{
+            clock::MockClock,
+            usecases::{
+                journey_ledger::JourneyLedgerUseCase, quest_deadlines::QuestDeadlinesUseCase,
+                tests::common::quest_entity,
+            },
+        },
+        domain::{
+            entities::quests::QuestEntity,
+            repositories::{
+                journey_ledger::MockJourneyLedgerRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::quest_statuses::QuestStatuses,
+        },
+    };
+
+    fn now() -> NaiveDateTime {
+        Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn overdue_quest(id: i32) -> QuestEntity {
+        QuestEntity {
+            status: QuestStatuses::InJourney.to_string(),
+            due_at: Some(
+                Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+            ),
+            ..quest_entity(id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fail_overdue_quests_success() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_quest_viewing_repo
+            .expect_overdue_quests_in_journey()
+            .withf(|current| *current == now())
+            .returning(|_| Box::pin(async { Ok(vec![overdue_quest(1), overdue_quest(2)]) }));
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(overdue_quest(quest_id)) }));
+
+        mock_journey_ledger_repo
+            .expect_to_failed()
+            .withf(|_, guild_commander_id, _, _| *guild_commander_id == 1)
+            .times(2)
+            .returning(|quest_id, _, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let quest_viewing_repo = Arc::new(mock_quest_viewing_repo);
+
+        let journey_ledger_use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::clone(&quest_viewing_repo),
+        );
+
+        let use_case = QuestDeadlinesUseCase::new(
+            Arc::new(journey_ledger_use_case),
+            quest_viewing_repo,
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.fail_overdue_quests().await.unwrap();
+
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_fail_overdue_quests_continues_after_failure() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_quest_viewing_repo
+            .expect_overdue_quests_in_journey()
+            .returning(|_| Box::pin(async { Ok(vec![overdue_quest(1), overdue_quest(2)]) }));
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(overdue_quest(quest_id)) }));
+
+        mock_journey_ledger_repo
+            .expect_to_failed()
+            .returning(|quest_id, _, _, _| {
+                Box::pin(async move {
+                    if quest_id == 1 {
+                        return Err(anyhow::anyhow!("database is unavailable"));
+                    }
+                    Ok(quest_id)
+                })
+            });
+
+        let quest_viewing_repo = Arc::new(mock_quest_viewing_repo);
+
+        let journey_ledger_use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::clone(&quest_viewing_repo),
+        );
+
+        let use_case = QuestDeadlinesUseCase::new(
+            Arc::new(journey_ledger_use_case),
+            quest_viewing_repo,
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.fail_overdue_quests().await.unwrap();
+
+        assert_eq!(result, vec![2]);
+    }
+}
@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    entities::{quest_milestones::QuestMilestoneEntity, quests::QuestEntity},
+    repositories::{
+        quest_milestones::QuestMilestonesRepository, quest_viewing::QuestViewingRepository,
+    },
+    value_objects::{
+        quest_milestone_model::{
+            AddQuestMilestoneModel, EditQuestMilestoneModel, QuestMilestoneModel,
+            MAX_MILESTONES_PER_QUEST, MAX_QUEST_MILESTONE_TITLE_LENGTH,
+        },
+        quest_statuses::QuestStatuses,
+    },
+};
+
+pub struct QuestMilestonesUseCase<T1, T2>
+where
+    T1: QuestMilestonesRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    quest_milestones_repository: Arc<T1>,
+    quest_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> QuestMilestonesUseCase<T1, T2>
+where
+    T1: QuestMilestonesRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(quest_milestones_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            quest_milestones_repository,
+            quest_viewing_repository,
+        }
+    }
+
+    pub async fn milestones(&self, quest_id: i32) -> Result<Vec<QuestMilestoneModel>> {
+        // make sure the quest exists and is not removed
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let results = self
+            .quest_viewing_repository
+            .milestones_by_quest_id(quest_id)
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|milestone| milestone.to_model())
+            .collect())
+    }
+
+    pub async fn add(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        add_quest_milestone_model: AddQuestMilestoneModel,
+    ) -> Result<i32> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_checklist_editable(&quest, guild_commander_id)?;
+        check_milestone_title(&add_quest_milestone_model.title)?;
+
+        let milestones = self
+            .quest_viewing_repository
+            .milestones_by_quest_id(quest_id)
+            .await?;
+
+        if milestones.len() >= MAX_MILESTONES_PER_QUEST {
+            return Err(anyhow::anyhow!(
+                "A quest cannot have more than {} milestones",
+                MAX_MILESTONES_PER_QUEST
+            ));
+        }
+
+        // new milestones go to the end of the checklist
+        let position = milestones
+            .iter()
+            .map(|milestone| milestone.position)
+            .max()
+            .unwrap_or_default()
+            + 1;
+
+        let result = self
+            .quest_milestones_repository
+            .add(add_quest_milestone_model.to_entity(quest_id, position))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        quest_id: i32,
+        milestone_id: i32,
+        guild_commander_id: i32,
+        edit_quest_milestone_model: EditQuestMilestoneModel,
+    ) -> Result<i32> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_checklist_editable(&quest, guild_commander_id)?;
+
+        if let Some(title) = &edit_quest_milestone_model.title {
+            check_milestone_title(title)?;
+        }
+
+        let milestone = self.quest_milestones_repository.find(milestone_id).await?;
+
+        check_milestone_of_quest(&milestone, quest_id)?;
+
+        let result = self
+            .quest_milestones_repository
+            .edit(
+                milestone_id,
+                edit_quest_milestone_model.to_entity(),
+                edit_quest_milestone_model.position,
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(
+        &self,
+        quest_id: i32,
+        milestone_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_checklist_editable(&quest, guild_commander_id)?;
+
+        let milestone = self.quest_milestones_repository.find(milestone_id).await?;
+
+        check_milestone_of_quest(&milestone, quest_id)?;
+
+        self.quest_milestones_repository
+            .remove(milestone_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn tick(&self, quest_id: i32, milestone_id: i32, adventurer_id: i32) -> Result<()> {
+        self.check_crew_in_journey(quest_id, adventurer_id).await?;
+
+        let milestone = self.quest_milestones_repository.find(milestone_id).await?;
+
+        check_milestone_of_quest(&milestone, quest_id)?;
+
+        self.quest_milestones_repository
+            .tick(milestone_id, adventurer_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn untick(&self, quest_id: i32, milestone_id: i32, adventurer_id: i32) -> Result<()> {
+        self.check_crew_in_journey(quest_id, adventurer_id).await?;
+
+        let milestone = self.quest_milestones_repository.find(milestone_id).await?;
+
+        check_milestone_of_quest(&milestone, quest_id)?;
+
+        self.quest_milestones_repository
+            .untick(milestone_id)
+            .await?;
+
+        Ok(())
+    }
+
+    // milestones are only ticked by the crew while they are out on the journey
+    async fn check_crew_in_journey(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        if quest.status != QuestStatuses::InJourney.to_string() {
+            return Err(anyhow::anyhow!(
+                "Milestones can only be ticked while the quest is InJourney"
+            ));
+        }
+
+        let crew = self
+            .quest_viewing_repository
+            .crew_by_quest_id(quest_id)
+            .await?;
+
+        if !crew
+            .iter()
+            .any(|(junction, _)| junction.adventurer_id == adventurer_id)
+        {
+            return Err(anyhow::anyhow!("You are not in the crew of this quest"));
+        }
+
+        Ok(())
+    }
+}
+
+fn check_checklist_editable(quest: &QuestEntity, guild_commander_id: i32) -> Result<()> {
+    if quest.guild_commander_id != guild_commander_id {
+        return Err(anyhow::anyhow!(
+            "You are not the guild commander of this quest"
+        ));
+    }
+
+    if quest.status == QuestStatuses::Completed.to_string() {
+        return Err(anyhow::anyhow!(
+            "The checklist of a completed quest cannot be changed"
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_milestone_of_quest(milestone: &QuestMilestoneEntity, quest_id: i32) -> Result<()> {
+    if milestone.quest_id != quest_id {
+        return Err(anyhow::anyhow!("Milestone not found"));
+    }
+
+    Ok(())
+}
+
+fn check_milestone_title(title: &str) -> Result<()> {
+    let title = title.trim();
+
+    if title.is_empty() {
+        return Err(anyhow::anyhow!("The milestone title cannot be empty"));
+    }
+
+    if title.chars().count() > MAX_QUEST_MILESTONE_TITLE_LENGTH {
+        return Err(anyhow::anyhow!(
+            "The milestone title cannot be longer than {} characters",
+            MAX_QUEST_MILESTONE_TITLE_LENGTH
+        ));
+    }
+
+    Ok(())
+}
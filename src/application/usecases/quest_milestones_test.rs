@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::usecases::{
+            quest_milestones::QuestMilestonesUseCase, tests::common::quest_entity,
+        },
+        domain::{
+            entities::{
+                adventurers::AdventurerEntity, quest_milestones::QuestMilestoneEntity,
+                quests::QuestEntity,
+            },
+            repositories::{
+                quest_milestones::MockQuestMilestonesRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                quest_adventurer_junction::QuestAdventurerJunction,
+                quest_milestone_model::AddQuestMilestoneModel, quest_statuses::QuestStatuses,
+            },
+        },
+    };
+
+    fn quest(status: QuestStatuses) -> QuestEntity {
+        QuestEntity {
+            status: status.to_string(),
+            ..quest_entity(1)
+        }
+    }
+
+    fn milestone(id: i32, position: i32) -> QuestMilestoneEntity {
+        QuestMilestoneEntity {
+            id,
+            quest_id: 1,
+            position,
+            title: format!("milestone {}", id),
+            required: true,
+            completed_by: None,
+            completed_at: None,
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    fn crew_member(adventurer_id: i32) -> (QuestAdventurerJunction, AdventurerEntity) {
+        (
+            QuestAdventurerJunction {
+                quest_id: 1,
+                adventurer_id,
+                joined_at: Utc
+                    .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                role: None,
+                reward_weight: 1,
+            },
+            AdventurerEntity {
+                id: adventurer_id,
+                username: format!("adventurer {}", adventurer_id),
+                created_at: Utc
+                    .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                updated_at: Utc
+                    .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                experience: 0,
+                level: 1,
+                user_id: adventurer_id,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_add_appends_to_checklist() {
+        let mut mock_quest_milestones_repo = MockQuestMilestonesRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest(QuestStatuses::Open)) }));
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![milestone(1, 1), milestone(2, 2)]) }));
+
+        mock_quest_milestones_repo
+            .expect_add()
+            .withf(|add_quest_milestone_entity| {
+                add_quest_milestone_entity.position == 3
+                    && add_quest_milestone_entity.title == "reach the cave"
+                    && add_quest_milestone_entity.required
+            })
+            .returning(|_| Box::pin(async { Ok(3) }));
+
+        let use_case = QuestMilestonesUseCase::new(
+            Arc::new(mock_quest_milestones_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case
+            .add(
+                1,
+                1,
+                AddQuestMilestoneModel {
+                    title: " reach the cave ".to_string(),
+                    required: None,
+                },
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_tick_fails_when_quest_not_in_journey() {
+        let mut mock_quest_milestones_repo = MockQuestMilestonesRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest(QuestStatuses::Open)) }));
+
+        mock_quest_milestones_repo.expect_tick().never();
+
+        let use_case = QuestMilestonesUseCase::new(
+            Arc::new(mock_quest_milestones_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.tick(1, 1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Milestones can only be ticked while the quest is InJourney"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_tick_fails_when_not_in_crew() {
+        let mut mock_quest_milestones_repo = MockQuestMilestonesRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest(QuestStatuses::InJourney)) }));
+
+        mock_quest_viewing_repo
+            .expect_crew_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![crew_member(1)]) }));
+
+        mock_quest_milestones_repo.expect_tick().never();
+
+        let use_case = QuestMilestonesUseCase::new(
+            Arc::new(mock_quest_milestones_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.tick(1, 1, 2).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "You are not in the crew of this quest"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_tick_by_crew_member_success() {
+        let mut mock_quest_milestones_repo = MockQuestMilestonesRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest(QuestStatuses::InJourney)) }));
+
+        mock_quest_viewing_repo
+            .expect_crew_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![crew_member(1)]) }));
+
+        mock_quest_milestones_repo
+            .expect_find()
+            .returning(|_| Box::pin(async { Ok(milestone(1, 1)) }));
+
+        mock_quest_milestones_repo
+            .expect_tick()
+            .withf(|milestone_id, adventurer_id| *milestone_id == 1 && *adventurer_id == 1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let use_case = QuestMilestonesUseCase::new(
+            Arc::new(mock_quest_milestones_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case.tick(1, 1, 1).await;
+
+        assert!(result.is_ok());
+    }
+}
@@ -1,30 +1,50 @@
 use std::sync::Arc;
 
 use anyhow::{Ok, Result};
+use chrono::NaiveDateTime;
 
 use crate::domain::{
-    repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
-    value_objects::quest_model::{AddQuestModel, EditQuestModel},
+    entities::quest_prerequisites::QuestPrerequisiteEntity,
+    repositories::{
+        guilds::GuildsRepository, quest_ops::QuestOpsRepository,
+        quest_viewing::QuestViewingRepository,
+    },
+    value_objects::{
+        domain_events::DomainEvent,
+        guild_membership_model::GuildMember,
+        quest_adventurer_junction::MAX_ADVENTURERS_PER_QUEST,
+        quest_model::{AddQuestModel, EditQuestModel},
+        quest_statuses::QuestStatuses,
+        quest_tags::{normalize_quest_tag, MAX_QUEST_TAG_LENGTH, MAX_TAGS_PER_QUEST},
+    },
 };
 
-pub struct QuestOpsUseCase<T1, T2>
+pub struct QuestOpsUseCase<T1, T2, T3>
 where
     T1: QuestOpsRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: GuildsRepository + Send + Sync,
 {
     quest_ops_repository: Arc<T1>,
     quest_viewing_repository: Arc<T2>,
+    guilds_repository: Arc<T3>,
 }
 
-impl<T1, T2> QuestOpsUseCase<T1, T2>
+impl<T1, T2, T3> QuestOpsUseCase<T1, T2, T3>
 where
     T1: QuestOpsRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: GuildsRepository + Send + Sync,
 {
-    pub fn new(quest_ops_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        quest_ops_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        guilds_repository: Arc<T3>,
+    ) -> Self {
         Self {
             quest_ops_repository,
             quest_viewing_repository,
+            guilds_repository,
         }
     }
 
@@ -33,8 +53,16 @@ where
         guild_commander_id: i32,
         add_quest_model: AddQuestModel,
     ) -> Result<i32> {
+        let tags = check_add_quest_model(&add_quest_model)?;
+
+        self.check_guild_commander_membership(add_quest_model.guild_id, guild_commander_id)
+            .await?;
+
         let add_quest_entity = add_quest_model.to_entity(guild_commander_id);
-        let result = self.quest_ops_repository.add(add_quest_entity).await?;
+        let result = self
+            .quest_ops_repository
+            .add(add_quest_entity, tags)
+            .await?;
         Ok(result)
     }
 
@@ -55,10 +83,37 @@ where
             ));
         }
 
+        // only the changed side of the schedule is sent, the other side comes from the quest
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_quest_schedule(
+            edit_quest_model.starts_at.or(quest.starts_at),
+            edit_quest_model.due_at.or(quest.due_at),
+        )?;
+        check_quest_reward(edit_quest_model.reward)?;
+        check_quest_experience(
+            edit_quest_model.experience,
+            edit_quest_model.experience_penalty,
+            edit_quest_model.min_level,
+        )?;
+        check_quest_max_attempts(edit_quest_model.max_attempts)?;
+        check_quest_capacity(edit_quest_model.capacity)?;
+
+        let tags = check_quest_tags(edit_quest_model.tags.clone())?;
+
         let edit_quest_entity = edit_quest_model.to_entity(guild_commander_id);
         let result = self
             .quest_ops_repository
-            .edit(quest_id, edit_quest_entity)
+            .edit(
+                quest_id,
+                edit_quest_entity,
+                tags,
+                DomainEvent::QuestEdited {
+                    quest_id,
+                    guild_id: quest.guild_id,
+                    guild_commander_id,
+                },
+            )
             .await?;
 
         Ok(result)
@@ -76,10 +131,231 @@ where
             ));
         }
 
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        self.quest_ops_repository
+            .remove(
+                quest_id,
+                guild_commander_id,
+                DomainEvent::QuestRemoved {
+                    quest_id,
+                    guild_id: quest.guild_id,
+                    guild_commander_id,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn add_prerequisite(
+        &self,
+        quest_id: i32,
+        prerequisite_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        if quest_id == prerequisite_id {
+            return Err(anyhow::anyhow!("A quest cannot be its own prerequisite"));
+        }
+
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_quest_commander(quest.guild_commander_id, guild_commander_id)?;
+
+        // a quest already underway cannot be locked behind another one
+        if quest.status != QuestStatuses::Open.to_string()
+            && quest.status != QuestStatuses::Failed.to_string()
+        {
+            return Err(anyhow::anyhow!(
+                "Prerequisites can only be added while the quest is Open or Failed"
+            ));
+        }
+
+        let prerequisite = self
+            .quest_viewing_repository
+            .view_details(prerequisite_id)
+            .await?;
+
+        if prerequisite.guild_id != quest.guild_id {
+            return Err(anyhow::anyhow!(
+                "A prerequisite must belong to the same guild as the quest"
+            ));
+        }
+
+        // the new edge closes a cycle when the prerequisite already depends on the quest
+        let chain_ids = self
+            .quest_viewing_repository
+            .prerequisite_chain_ids(prerequisite_id)
+            .await?;
+
+        if chain_ids.contains(&quest_id) {
+            return Err(anyhow::anyhow!("The prerequisite would create a cycle"));
+        }
+
         self.quest_ops_repository
-            .remove(quest_id, guild_commander_id)
+            .add_prerequisite(QuestPrerequisiteEntity {
+                quest_id,
+                prerequisite_id,
+                created_at: chrono::Utc::now().naive_utc(),
+            })
             .await?;
 
         Ok(())
     }
+
+    pub async fn remove_prerequisite(
+        &self,
+        quest_id: i32,
+        prerequisite_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        check_quest_commander(quest.guild_commander_id, guild_commander_id)?;
+
+        self.quest_ops_repository
+            .remove_prerequisite(quest_id, prerequisite_id)
+            .await?;
+
+        Ok(())
+    }
+
+    // quests are posted to a guild, so the commander has to belong to it
+    pub async fn check_guild_commander_membership(
+        &self,
+        guild_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        self.guilds_repository
+            .membership(guild_id, GuildMember::GuildCommander(guild_commander_id))
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("You are not a member of this guild"))?;
+
+        Ok(())
+    }
+}
+
+// shared with the quest templates, returns the normalized tags of the new quest
+pub fn check_add_quest_model(add_quest_model: &AddQuestModel) -> Result<Vec<String>> {
+    check_quest_schedule(add_quest_model.starts_at, add_quest_model.due_at)?;
+    check_quest_reward(add_quest_model.reward)?;
+    check_quest_experience(
+        add_quest_model.experience,
+        add_quest_model.experience_penalty,
+        add_quest_model.min_level,
+    )?;
+    check_quest_max_attempts(add_quest_model.max_attempts)?;
+    check_quest_capacity(add_quest_model.capacity)?;
+
+    let tags = check_quest_tags(add_quest_model.tags.clone())?.unwrap_or_default();
+
+    Ok(tags)
+}
+
+fn check_quest_commander(quest_guild_commander_id: i32, guild_commander_id: i32) -> Result<()> {
+    if quest_guild_commander_id != guild_commander_id {
+        return Err(anyhow::anyhow!(
+            "You are not the guild commander of this quest"
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_quest_schedule(
+    starts_at: Option<NaiveDateTime>,
+    due_at: Option<NaiveDateTime>,
+) -> Result<()> {
+    if let (Some(starts_at), Some(due_at)) = (starts_at, due_at) {
+        if due_at <= starts_at {
+            return Err(anyhow::anyhow!(
+                "The quest due date must be after its start date"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_quest_reward(reward: Option<i64>) -> Result<()> {
+    if reward.is_some_and(|reward| reward < 0) {
+        return Err(anyhow::anyhow!("The quest reward cannot be negative"));
+    }
+
+    Ok(())
+}
+
+fn check_quest_experience(
+    experience: Option<i64>,
+    experience_penalty: Option<i64>,
+    min_level: Option<i32>,
+) -> Result<()> {
+    if experience.is_some_and(|experience| experience < 0)
+        || experience_penalty.is_some_and(|experience_penalty| experience_penalty < 0)
+    {
+        return Err(anyhow::anyhow!("The quest experience cannot be negative"));
+    }
+
+    if min_level.is_some_and(|min_level| min_level < 1) {
+        return Err(anyhow::anyhow!(
+            "The quest minimum level must be at least 1"
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_quest_max_attempts(max_attempts: Option<i32>) -> Result<()> {
+    if max_attempts.is_some_and(|max_attempts| max_attempts < 1) {
+        return Err(anyhow::anyhow!("The quest must allow at least 1 attempt"));
+    }
+
+    Ok(())
+}
+
+fn check_quest_capacity(capacity: Option<i32>) -> Result<()> {
+    if capacity.is_some_and(|capacity| capacity < 1 || capacity as i64 > MAX_ADVENTURERS_PER_QUEST)
+    {
+        return Err(anyhow::anyhow!(
+            "The quest capacity must be between 1 and {}",
+            MAX_ADVENTURERS_PER_QUEST
+        ));
+    }
+
+    Ok(())
+}
+
+// returns the tags normalized and without duplicates
+fn check_quest_tags(tags: Option<Vec<String>>) -> Result<Option<Vec<String>>> {
+    let Some(tags) = tags else {
+        return Ok(None);
+    };
+
+    let mut quest_tags: Vec<String> = Vec::new();
+
+    for tag in tags.iter().map(|tag| normalize_quest_tag(tag)) {
+        if tag.is_empty() {
+            return Err(anyhow::anyhow!("The quest tag cannot be empty"));
+        }
+
+        if tag.chars().count() > MAX_QUEST_TAG_LENGTH {
+            return Err(anyhow::anyhow!(
+                "The quest tag cannot be longer than {} characters",
+                MAX_QUEST_TAG_LENGTH
+            ));
+        }
+
+        if !quest_tags.contains(&tag) {
+            quest_tags.push(tag);
+        }
+    }
+
+    if quest_tags.len() > MAX_TAGS_PER_QUEST {
+        return Err(anyhow::anyhow!(
+            "The quest cannot have more than {} tags",
+            MAX_TAGS_PER_QUEST
+        ));
+    }
+
+    Ok(Some(quest_tags))
 }
@@ -0,0 +1,262 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::usecases::{quest_ops::QuestOpsUseCase, tests::common::quest_entity},
+        domain::{
+            entities::guild_memberships::GuildMembershipEntity,
+            repositories::{
+                guilds::MockGuildsRepository, quest_ops::MockQuestOpsRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                domain_events::DomainEvent, guild_membership_model::GuildMember,
+                guild_membership_roles::GuildMembershipRoles, quest_model::AddQuestModel,
+                quest_ranks::QuestRanks, quest_tags::MAX_TAGS_PER_QUEST,
+            },
+        },
+    };
+
+    fn add_quest_model(tags: Vec<String>) -> AddQuestModel {
+        AddQuestModel {
+            guild_id: 1,
+            name: "test quest 1".to_string(),
+            description: Some("test quest description".to_string()),
+            join_policy: None,
+            starts_at: None,
+            due_at: None,
+            reward: None,
+            experience: None,
+            experience_penalty: None,
+            min_level: None,
+            rank: Some(QuestRanks::B),
+            max_attempts: None,
+            capacity: None,
+            tags: Some(tags),
+        }
+    }
+
+    fn guild_membership() -> GuildMembershipEntity {
+        GuildMembershipEntity {
+            id: 1,
+            guild_id: 1,
+            adventurer_id: None,
+            guild_commander_id: Some(1),
+            role: GuildMembershipRoles::Officer.to_string(),
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_normalizes_tags() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_viewing_repo = MockQuestViewingRepository::new();
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_quest_ops_repo
+            .expect_add()
+            .withf(|add_quest_entity, tags| {
+                add_quest_entity.rank == QuestRanks::B.to_string()
+                    && *tags == vec!["escort".to_string(), "night watch".to_string()]
+            })
+            .returning(|_, _| Box::pin(async { Ok(1) }));
+
+        mock_guilds_repo
+            .expect_membership()
+            .withf(|guild_id, member| *guild_id == 1 && *member == GuildMember::GuildCommander(1))
+            .returning(|_, _| Box::pin(async { Ok(Some(guild_membership())) }));
+
+        let use_case = QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(mock_quest_viewing_repo),
+            Arc::new(mock_guilds_repo),
+        );
+
+        let result = use_case
+            .add(
+                1,
+                add_quest_model(vec![
+                    " Escort ".to_string(),
+                    "Night Watch".to_string(),
+                    "escort".to_string(),
+                ]),
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_when_quest_has_too_many_tags() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_viewing_repo = MockQuestViewingRepository::new();
+        let mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_quest_ops_repo.expect_add().never();
+
+        let use_case = QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(mock_quest_viewing_repo),
+            Arc::new(mock_guilds_repo),
+        );
+
+        let tags = (0..=MAX_TAGS_PER_QUEST)
+            .map(|index| format!("tag {}", index))
+            .collect();
+
+        let result = use_case.add(1, add_quest_model(tags)).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "The quest cannot have more than {} tags",
+                MAX_TAGS_PER_QUEST
+            )
+        )
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_when_guild_commander_is_not_a_guild_member() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_viewing_repo = MockQuestViewingRepository::new();
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_guilds_repo
+            .expect_membership()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
+        mock_quest_ops_repo.expect_add().never();
+
+        let use_case = QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(mock_quest_viewing_repo),
+            Arc::new(mock_guilds_repo),
+        );
+
+        let result = use_case.add(2, add_quest_model(vec![])).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "You are not a member of this guild"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_add_prerequisite_success() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+        let mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(quest_entity(quest_id)) }));
+
+        mock_quest_viewing_repo
+            .expect_prerequisite_chain_ids()
+            .returning(|_| Box::pin(async { Ok(vec![3]) }));
+
+        mock_quest_ops_repo
+            .expect_add_prerequisite()
+            .withf(|quest_prerequisite_entity| {
+                quest_prerequisite_entity.quest_id == 1
+                    && quest_prerequisite_entity.prerequisite_id == 2
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(mock_quest_viewing_repo),
+            Arc::new(mock_guilds_repo),
+        );
+
+        let result = use_case.add_prerequisite(1, 2, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_add_prerequisite_fails_when_it_would_create_a_cycle() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+        let mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(quest_entity(quest_id)) }));
+
+        // quest 2 already waits on quest 3, which waits on quest 1
+        mock_quest_viewing_repo
+            .expect_prerequisite_chain_ids()
+            .returning(|_| Box::pin(async { Ok(vec![1, 3]) }));
+
+        mock_quest_ops_repo.expect_add_prerequisite().never();
+
+        let use_case = QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(mock_quest_viewing_repo),
+            Arc::new(mock_guilds_repo),
+        );
+
+        let result = use_case.add_prerequisite(1, 2, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The prerequisite would create a cycle"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_remove_records_quest_removed() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+        let mock_guilds_repo = MockGuildsRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(quest_entity(quest_id)) }));
+
+        mock_quest_ops_repo
+            .expect_remove()
+            .withf(|quest_id, guild_commander_id, domain_event| {
+                *quest_id == 3
+                    && *guild_commander_id == 1
+                    && *domain_event
+                        == DomainEvent::QuestRemoved {
+                            quest_id: 3,
+                            guild_id: 1,
+                            guild_commander_id: 1,
+                        }
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(mock_quest_viewing_repo),
+            Arc::new(mock_guilds_repo),
+        );
+
+        let result = use_case.remove(3, 1).await;
+
+        assert!(result.is_ok())
+    }
+}
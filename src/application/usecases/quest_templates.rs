@@ -0,0 +1,234 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Duration;
+use tracing::error;
+
+use crate::{
+    application::{
+        clock::Clock,
+        usecases::quest_ops::{check_add_quest_model, QuestOpsUseCase},
+    },
+    domain::{
+        entities::quest_templates::QuestTemplateEntity,
+        repositories::{
+            guilds::GuildsRepository, quest_ops::QuestOpsRepository,
+            quest_templates::QuestTemplatesRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_model::AddQuestModel,
+            quest_template_model::{AddQuestTemplateModel, QuestTemplateModel},
+            quest_template_recurrences::{
+                next_template_run_at, parse_cron_expression, QuestTemplateRecurrences,
+                QUEST_TEMPLATE_LEASE_SECONDS,
+            },
+        },
+    },
+};
+
+pub struct QuestTemplatesUseCase<T1, T2, T3, T4, C>
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: GuildsRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    quest_templates_repository: Arc<T1>,
+    quest_ops_use_case: Arc<QuestOpsUseCase<T2, T3, T4>>,
+    clock: Arc<C>,
+}
+
+impl<T1, T2, T3, T4, C> QuestTemplatesUseCase<T1, T2, T3, T4, C>
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: GuildsRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    pub fn new(
+        quest_templates_repository: Arc<T1>,
+        quest_ops_use_case: Arc<QuestOpsUseCase<T2, T3, T4>>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            quest_templates_repository,
+            quest_ops_use_case,
+            clock,
+        }
+    }
+
+    pub async fn templates(&self, guild_commander_id: i32) -> Result<Vec<QuestTemplateModel>> {
+        let results = self
+            .quest_templates_repository
+            .templates_by_guild_commander_id(guild_commander_id)
+            .await?;
+
+        Ok(results.iter().map(|template| template.to_model()).collect())
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_quest_template_model: AddQuestTemplateModel,
+    ) -> Result<i32> {
+        // the same checks as a quest posted by hand, so every run can be posted
+        let tags = check_add_quest_model(&AddQuestModel {
+            guild_id: add_quest_template_model.guild_id,
+            name: add_quest_template_model.name.clone(),
+            description: add_quest_template_model.description.clone(),
+            join_policy: None,
+            starts_at: None,
+            due_at: None,
+            reward: add_quest_template_model.reward,
+            experience: None,
+            experience_penalty: None,
+            min_level: None,
+            rank: None,
+            max_attempts: None,
+            capacity: add_quest_template_model.capacity,
+            tags: add_quest_template_model.tags.clone(),
+        })?;
+
+        self.quest_ops_use_case
+            .check_guild_commander_membership(add_quest_template_model.guild_id, guild_commander_id)
+            .await?;
+
+        let now = self.clock.now();
+
+        let next_run_at = match (
+            &add_quest_template_model.recurrence,
+            &add_quest_template_model.cron_expression,
+        ) {
+            (QuestTemplateRecurrences::Cron, Some(cron_expression)) => {
+                parse_cron_expression(cron_expression)?;
+
+                next_template_run_at(
+                    &QuestTemplateRecurrences::Cron.to_string(),
+                    Some(cron_expression),
+                    now,
+                    now,
+                )?
+            }
+            (QuestTemplateRecurrences::Cron, None) => {
+                return Err(anyhow::anyhow!("A Cron template needs a cron expression"));
+            }
+            (_, Some(_)) => {
+                return Err(anyhow::anyhow!(
+                    "Only Cron templates take a cron expression"
+                ));
+            }
+            (_, None) => add_quest_template_model.first_run_at.unwrap_or(now),
+        };
+
+        let result = self
+            .quest_templates_repository
+            .add(add_quest_template_model.to_entity(guild_commander_id, tags, next_run_at))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn pause(&self, template_id: i32, guild_commander_id: i32) -> Result<()> {
+        let template = self.quest_templates_repository.find(template_id).await?;
+
+        check_template_commander(&template, guild_commander_id)?;
+
+        self.quest_templates_repository.pause(template_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn resume(&self, template_id: i32, guild_commander_id: i32) -> Result<()> {
+        let template = self.quest_templates_repository.find(template_id).await?;
+
+        check_template_commander(&template, guild_commander_id)?;
+
+        // runs missed while the template was paused are not posted
+        let next_run_at = next_template_run_at(
+            &template.recurrence,
+            template.cron_expression.as_deref(),
+            template.next_run_at,
+            self.clock.now(),
+        )?;
+
+        self.quest_templates_repository
+            .resume(template_id, next_run_at)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove(&self, template_id: i32, guild_commander_id: i32) -> Result<()> {
+        let template = self.quest_templates_repository.find(template_id).await?;
+
+        check_template_commander(&template, guild_commander_id)?;
+
+        self.quest_templates_repository.remove(template_id).await?;
+
+        Ok(())
+    }
+
+    // posts an Open quest for every due template and returns the new quest ids
+    pub async fn instantiate_due_templates(&self) -> Result<Vec<i32>> {
+        let now = self.clock.now();
+
+        let due_templates = self
+            .quest_templates_repository
+            .claim_due(now, now + Duration::seconds(QUEST_TEMPLATE_LEASE_SECONDS))
+            .await?;
+
+        let mut quest_ids: Vec<i32> = Vec::new();
+
+        for template in due_templates.into_iter() {
+            // a template whose schedule cannot be worked out is paused, otherwise the lease
+            // would run out and it would be picked up again on every tick
+            let next_run_at = match next_template_run_at(
+                &template.recurrence,
+                template.cron_expression.as_deref(),
+                template.next_run_at,
+                now,
+            ) {
+                Ok(next_run_at) => next_run_at,
+                Err(e) => {
+                    error!(
+                        "🔴 Failed to schedule template id: {}, pausing it: {}",
+                        template.id, e
+                    );
+                    self.quest_templates_repository.pause(template.id).await?;
+                    continue;
+                }
+            };
+
+            match self
+                .quest_ops_use_case
+                .add(template.guild_commander_id, template.to_add_quest_model())
+                .await
+            {
+                Ok(quest_id) => quest_ids.push(quest_id),
+                Err(e) => error!(
+                    "🔴 Failed to post a quest from template id: {}: {}",
+                    template.id, e
+                ),
+            }
+
+            // the schedule moves on even when posting failed, so a broken template is not retried every tick
+            self.quest_templates_repository
+                .record_run(template.id, now, next_run_at)
+                .await?;
+        }
+
+        Ok(quest_ids)
+    }
+}
+
+fn check_template_commander(template: &QuestTemplateEntity, guild_commander_id: i32) -> Result<()> {
+    if template.guild_commander_id != guild_commander_id {
+        return Err(anyhow::anyhow!(
+            "You are not the guild commander of this template"
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,257 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+
+    use crate::{
+        application::{
+            clock::MockClock,
+            usecases::{quest_ops::QuestOpsUseCase, quest_templates::QuestTemplatesUseCase},
+        },
+        domain::{
+            entities::{
+                guild_memberships::GuildMembershipEntity, quest_templates::QuestTemplateEntity,
+            },
+            repositories::{
+                guilds::MockGuildsRepository, quest_ops::MockQuestOpsRepository,
+                quest_templates::MockQuestTemplatesRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                guild_membership_roles::GuildMembershipRoles,
+                quest_template_model::AddQuestTemplateModel,
+                quest_template_recurrences::QuestTemplateRecurrences,
+            },
+        },
+    };
+
+    fn now() -> NaiveDateTime {
+        Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn weekly_template(next_run_at: NaiveDateTime) -> QuestTemplateEntity {
+        QuestTemplateEntity {
+            id: 1,
+            guild_commander_id: 1,
+            guild_id: 1,
+            name: "weekly patrol".to_string(),
+            description: Some("walk the walls".to_string()),
+            capacity: 2,
+            reward: 30,
+            tags: vec!["patrol".to_string()],
+            recurrence: QuestTemplateRecurrences::Weekly.to_string(),
+            cron_expression: None,
+            paused: false,
+            next_run_at,
+            last_run_at: None,
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    fn guild_membership() -> GuildMembershipEntity {
+        GuildMembershipEntity {
+            id: 1,
+            guild_id: 1,
+            adventurer_id: None,
+            guild_commander_id: Some(1),
+            role: GuildMembershipRoles::Officer.to_string(),
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    fn use_case(
+        mock_quest_templates_repo: MockQuestTemplatesRepository,
+        mock_quest_ops_repo: MockQuestOpsRepository,
+    ) -> QuestTemplatesUseCase<
+        MockQuestTemplatesRepository,
+        MockQuestOpsRepository,
+        MockQuestViewingRepository,
+        MockGuildsRepository,
+        MockClock,
+    > {
+        let mut mock_clock = MockClock::new();
+        mock_clock.expect_now().returning(now);
+
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+        mock_guilds_repo
+            .expect_membership()
+            .returning(|_, _| Box::pin(async { Ok(Some(guild_membership())) }));
+
+        let quest_ops_use_case = QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(MockQuestViewingRepository::new()),
+            Arc::new(mock_guilds_repo),
+        );
+
+        QuestTemplatesUseCase::new(
+            Arc::new(mock_quest_templates_repo),
+            Arc::new(quest_ops_use_case),
+            Arc::new(mock_clock),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_instantiate_due_templates_posts_quest_and_skips_missed_runs() {
+        let mut mock_quest_templates_repo = MockQuestTemplatesRepository::new();
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+
+        // due since two and a half weeks ago, the next run lands after now on the weekly grid
+        let next_run_at = now() - Duration::days(17) - Duration::hours(12);
+
+        mock_quest_templates_repo
+            .expect_claim_due()
+            .withf(|current, lease_until| {
+                *current == now() && *lease_until == now() + Duration::seconds(60)
+            })
+            .returning(move |_, _| Box::pin(async move { Ok(vec![weekly_template(next_run_at)]) }));
+
+        mock_quest_ops_repo
+            .expect_add()
+            .withf(|add_quest_entity, tags| {
+                add_quest_entity.name == "weekly patrol"
+                    && add_quest_entity.capacity == 2
+                    && add_quest_entity.reward == 30
+                    && *tags == vec!["patrol".to_string()]
+            })
+            .returning(|_, _| Box::pin(async { Ok(7) }));
+
+        mock_quest_templates_repo
+            .expect_record_run()
+            .withf(move |template_id, last_run_at, next| {
+                *template_id == 1
+                    && *last_run_at == now()
+                    && *next == next_run_at + Duration::weeks(3)
+            })
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = use_case(mock_quest_templates_repo, mock_quest_ops_repo);
+
+        let result = use_case.instantiate_due_templates().await.unwrap();
+
+        assert_eq!(result, vec![7]);
+    }
+
+    #[tokio::test]
+    async fn test_instantiate_due_templates_pauses_template_with_broken_schedule() {
+        let mut mock_quest_templates_repo = MockQuestTemplatesRepository::new();
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+
+        mock_quest_templates_repo
+            .expect_claim_due()
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![QuestTemplateEntity {
+                        recurrence: QuestTemplateRecurrences::Cron.to_string(),
+                        cron_expression: Some("every other full moon".to_string()),
+                        ..weekly_template(now())
+                    }])
+                })
+            });
+
+        mock_quest_templates_repo
+            .expect_pause()
+            .withf(|template_id| *template_id == 1)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        mock_quest_templates_repo.expect_record_run().never();
+        mock_quest_ops_repo.expect_add().never();
+
+        let use_case = use_case(mock_quest_templates_repo, mock_quest_ops_repo);
+
+        let result = use_case.instantiate_due_templates().await.unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_cron_template_schedules_next_match() {
+        let mut mock_quest_templates_repo = MockQuestTemplatesRepository::new();
+
+        mock_quest_templates_repo
+            .expect_add()
+            .withf(|add_quest_template_entity| {
+                // 2025-01-15 is a Wednesday, the next Monday 09:00 is the 20th
+                add_quest_template_entity.next_run_at
+                    == Utc
+                        .with_ymd_and_hms(2025, 1, 20, 9, 0, 0)
+                        .unwrap()
+                        .naive_utc()
+                    && add_quest_template_entity.recurrence
+                        == QuestTemplateRecurrences::Cron.to_string()
+            })
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        let use_case = use_case(mock_quest_templates_repo, MockQuestOpsRepository::new());
+
+        let result = use_case
+            .add(
+                1,
+                AddQuestTemplateModel {
+                    guild_id: 1,
+                    name: "escort".to_string(),
+                    description: None,
+                    capacity: None,
+                    reward: None,
+                    tags: None,
+                    recurrence: QuestTemplateRecurrences::Cron,
+                    cron_expression: Some("0 0 9 * * Mon".to_string()),
+                    first_run_at: None,
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_when_cron_template_has_no_expression() {
+        let mut mock_quest_templates_repo = MockQuestTemplatesRepository::new();
+
+        mock_quest_templates_repo.expect_add().never();
+
+        let use_case = use_case(mock_quest_templates_repo, MockQuestOpsRepository::new());
+
+        let result = use_case
+            .add(
+                1,
+                AddQuestTemplateModel {
+                    guild_id: 1,
+                    name: "escort".to_string(),
+                    description: None,
+                    capacity: None,
+                    reward: None,
+                    tags: None,
+                    recurrence: QuestTemplateRecurrences::Cron,
+                    cron_expression: None,
+                    first_run_at: None,
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "A Cron template needs a cron expression"
+        )
+    }
+}
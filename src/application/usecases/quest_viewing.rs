@@ -4,7 +4,11 @@ use anyhow::{Ok, Result};
 
 use crate::domain::{
     repositories::quest_viewing::QuestViewingRepository,
-    value_objects::{board_checking_filter::BoardCheckingFilter, quest_model::QuestModel},
+    value_objects::{
+        board_checking_filter::BoardCheckingFilter, crew_member_model::CrewMemberModel,
+        guild_membership_model::GuildMember, quest_milestone_model::completion_percentage,
+        quest_model::QuestModel,
+    },
 };
 
 pub struct QuestViewingUseCase<T>
@@ -32,26 +36,133 @@ where
             .adventurers_counting_by_quest_id(quest_id)
             .await?;
 
-        let quest_model = result.to_model(adventures_count);
+        let tags = self
+            .quest_viewing_repository
+            .tags_by_quest_id(quest_id)
+            .await?;
+
+        let milestones = self
+            .quest_viewing_repository
+            .milestones_by_quest_id(quest_id)
+            .await?;
+
+        let attempts = self
+            .quest_viewing_repository
+            .attempts_by_quest_id(quest_id)
+            .await?;
+
+        let prerequisites = self
+            .quest_viewing_repository
+            .prerequisites_by_quest_id(quest_id)
+            .await?;
+
+        let mut quest_model = result.to_model(
+            adventures_count,
+            tags,
+            completion_percentage(&milestones),
+            &prerequisites,
+        );
+        quest_model.attempts = Some(
+            attempts
+                .into_iter()
+                .map(|(attempt, crew)| attempt.to_model(crew))
+                .collect(),
+        );
 
         Ok(quest_model)
     }
 
-    pub async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestModel>> {
-        let results = self.quest_viewing_repository.board_checking(filter).await?;
+    pub async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+        member: GuildMember,
+    ) -> Result<Vec<QuestModel>> {
+        let joinable_by = joinable_by(filter, member)?;
+
+        let results = self
+            .quest_viewing_repository
+            .board_checking(filter, member, joinable_by)
+            .await?;
+
+        if results.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // one query per lookup for the whole board instead of one per quest
+        let quest_ids: Vec<i32> = results.iter().map(|(quest, _)| quest.id).collect();
+
+        let adventurers_counts = self
+            .quest_viewing_repository
+            .adventurers_counting_by_quest_ids(&quest_ids)
+            .await?;
+
+        let mut tags = self
+            .quest_viewing_repository
+            .tags_by_quest_ids(&quest_ids)
+            .await?;
+
+        let milestones = self
+            .quest_viewing_repository
+            .milestones_by_quest_ids(&quest_ids)
+            .await?;
+
+        let prerequisites = self
+            .quest_viewing_repository
+            .prerequisites_by_quest_ids(&quest_ids)
+            .await?;
 
         let mut quests_model: Vec<QuestModel> = Vec::new();
 
         //  we not use into_iter anymore we will move the ownership of the results
-        for quest in results.into_iter() {
-            let adventurers_count = self
-                .quest_viewing_repository
-                .adventurers_counting_by_quest_id(quest.id)
-                .await?;
+        for (quest, headline) in results.into_iter() {
+            let mut quest_model = quest.to_model(
+                adventurers_counts
+                    .get(&quest.id)
+                    .copied()
+                    .unwrap_or_default(),
+                tags.remove(&quest.id).unwrap_or_default(),
+                completion_percentage(
+                    milestones
+                        .get(&quest.id)
+                        .map(Vec::as_slice)
+                        .unwrap_or_default(),
+                ),
+                prerequisites
+                    .get(&quest.id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            );
+            quest_model.headline = headline;
 
-            quests_model.push(quest.to_model(adventurers_count));
+            quests_model.push(quest_model);
         }
 
         Ok(quests_model)
     }
+
+    pub async fn crew(&self, quest_id: i32) -> Result<Vec<CrewMemberModel>> {
+        // make sure the quest exists and is not removed
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let results = self
+            .quest_viewing_repository
+            .crew_by_quest_id(quest_id)
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|(junction, adventurer)| junction.to_crew_member_model(adventurer))
+            .collect())
+    }
+}
+
+// the adventurer a joinable filter narrows the board down to, guild commanders cannot join
+pub fn joinable_by(filter: &BoardCheckingFilter, member: GuildMember) -> Result<Option<i32>> {
+    match (filter.joinable_only(), member) {
+        (true, GuildMember::GuildCommander(_)) => Err(anyhow::anyhow!(
+            "Sign in as an adventurer to see the joinable quests"
+        )),
+        (true, GuildMember::Adventurer(adventurer_id)) => Ok(Some(adventurer_id)),
+        (false, _) => Ok(None),
+    }
 }
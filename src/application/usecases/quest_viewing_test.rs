@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+
+    use std::{collections::HashMap, sync::Arc};
+
+    use anyhow::Ok;
+
+    use crate::{
+        application::usecases::{quest_viewing::QuestViewingUseCase, tests::common::quest_entity},
+        domain::{
+            entities::quests::QuestEntity,
+            repositories::quest_viewing::MockQuestViewingRepository,
+            value_objects::{
+                board_checking_filter::BoardCheckingFilter, guild_membership_model::GuildMember,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn test_board_checking_returns_search_headlines() {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_board_checking()
+            .withf(|filter, member, joinable_by| {
+                filter.search_query() == Some("dragon".to_string())
+                    && *member == GuildMember::Adventurer(1)
+                    && joinable_by.is_none()
+            })
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    Ok(vec![(
+                        QuestEntity {
+                            name: "slay the dragon".to_string(),
+                            ..quest_entity(1)
+                        },
+                        Some("slay the <mark>dragon</mark>".to_string()),
+                    )])
+                })
+            });
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_ids()
+            .withf(|quest_ids| quest_ids == [1])
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(HashMap::new()) }));
+
+        mock_quest_viewing_repo
+            .expect_tags_by_quest_ids()
+            .times(1)
+            .returning(|_| {
+                Box::pin(async { Ok(HashMap::from([(1, vec!["monster".to_string()])])) })
+            });
+
+        mock_quest_viewing_repo
+            .expect_milestones_by_quest_ids()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(HashMap::new()) }));
+
+        mock_quest_viewing_repo
+            .expect_prerequisites_by_quest_ids()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(HashMap::new()) }));
+
+        mock_quest_viewing_repo.expect_tags_by_quest_id().never();
+
+        let use_case = QuestViewingUseCase::new(Arc::new(mock_quest_viewing_repo));
+
+        let filter = BoardCheckingFilter {
+            q: Some("  dragon ".to_string()),
+            name: None,
+            status: None,
+            statuses: None,
+            guild_commander_id: None,
+            created_from: None,
+            created_to: None,
+            updated_from: None,
+            updated_to: None,
+            joinable: None,
+            min_rank: None,
+            max_rank: None,
+            tags: None,
+            tags_match: None,
+        };
+
+        let result = use_case
+            .board_checking(&filter, GuildMember::Adventurer(1))
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].headline,
+            Some("slay the <mark>dragon</mark>".to_string())
+        );
+        assert_eq!(result[0].tags, vec!["monster".to_string()]);
+    }
+
+    fn joinable_filter() -> BoardCheckingFilter {
+        BoardCheckingFilter {
+            q: None,
+            name: None,
+            status: None,
+            statuses: None,
+            guild_commander_id: None,
+            created_from: None,
+            created_to: None,
+            updated_from: None,
+            updated_to: None,
+            joinable: Some(true),
+            min_rank: None,
+            max_rank: None,
+            tags: None,
+            tags_match: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_board_checking_joinable_filters_for_adventurer() {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_board_checking()
+            .withf(|_, _, joinable_by| *joinable_by == Some(7))
+            .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+
+        let use_case = QuestViewingUseCase::new(Arc::new(mock_quest_viewing_repo));
+
+        let result = use_case
+            .board_checking(&joinable_filter(), GuildMember::Adventurer(7))
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_board_checking_joinable_fails_for_guild_commander() {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo.expect_board_checking().never();
+
+        let use_case = QuestViewingUseCase::new(Arc::new(mock_quest_viewing_repo));
+
+        let result = use_case
+            .board_checking(&joinable_filter(), GuildMember::GuildCommander(7))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Sign in as an adventurer to see the joinable quests"
+        )
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{TimeZone, Utc};
+
+use crate::domain::{
+    entities::quests::QuestEntity,
+    value_objects::{
+        quest_join_policies::QuestJoinPolicies, quest_ranks::QuestRanks,
+        quest_statuses::QuestStatuses,
+    },
+};
+
+// an Open quest of guild 1 with no reward, tests override what they need with
+// QuestEntity { status: ..., ..quest_entity(id) }
+pub fn quest_entity(id: i32) -> QuestEntity {
+    QuestEntity {
+        id,
+        name: format!("test quest {}", id),
+        description: Some("test quest description".to_string()),
+        status: QuestStatuses::Open.to_string(),
+        guild_commander_id: 1,
+        created_at: Utc
+            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+            .unwrap()
+            .naive_utc(),
+        updated_at: Utc
+            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+            .unwrap()
+            .naive_utc(),
+        join_policy: QuestJoinPolicies::Open.to_string(),
+        starts_at: None,
+        due_at: None,
+        reward: 0,
+        experience: 0,
+        experience_penalty: 0,
+        min_level: None,
+        rank: QuestRanks::E.to_string(),
+        max_attempts: None,
+        capacity: 4,
+        guild_id: 1,
+    }
+}
@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::{
+    domain::{
+        repositories::users::UsersRepository,
+        value_objects::user_model::{
+            anonymized_username, AddUserRoleModel, RegisterUserModel, UserDataExportModel,
+            UserModel, DELETED_USERNAME_PREFIX,
+        },
+    },
+    infrastructure::{argon2_hashing, jwt_authentication::jwt_model::Roles},
+};
+
+pub struct UsersUseCase<T>
+where
+    T: UsersRepository + Send + Sync,
+{
+    users_repository: Arc<T>,
+}
+
+impl<T> UsersUseCase<T>
+where
+    T: UsersRepository + Send + Sync,
+{
+    pub fn new(users_repository: Arc<T>) -> Self {
+        Self { users_repository }
+    }
+
+    pub async fn register(&self, mut register_user_model: RegisterUserModel) -> Result<i32> {
+        if register_user_model.username.trim().is_empty() {
+            return Err(anyhow::anyhow!("The username cannot be empty"));
+        }
+
+        if register_user_model
+            .username
+            .starts_with(DELETED_USERNAME_PREFIX)
+        {
+            return Err(anyhow::anyhow!("The username is reserved"));
+        }
+
+        if register_user_model.roles.contains(&Roles::Admin) {
+            return Err(anyhow::anyhow!("The Admin role cannot be self-assigned"));
+        }
+
+        let mut roles: Vec<String> = Vec::new();
+
+        for role in register_user_model.roles.iter() {
+            if !roles.contains(&role.to_string()) {
+                roles.push(role.to_string());
+            }
+        }
+
+        if roles.is_empty() {
+            roles.push(Roles::Adventurer.to_string());
+        }
+
+        let hashed_password = argon2_hashing::hash(register_user_model.password.clone())?;
+
+        register_user_model.password = hashed_password;
+
+        let user_id = self
+            .users_repository
+            .register(register_user_model.to_entity(), roles)
+            .await?;
+
+        Ok(user_id)
+    }
+
+    // backs the old per-role registration routes, which answer with the profile id
+    pub async fn register_as(
+        &self,
+        mut register_user_model: RegisterUserModel,
+        role: Roles,
+    ) -> Result<i32> {
+        register_user_model.roles = vec![role.clone()];
+
+        let user_id = self.register(register_user_model).await?;
+
+        let profile_id = match role {
+            Roles::Adventurer => self.users_repository.adventurer_id(user_id).await?,
+            Roles::GuildCommander => self.users_repository.guild_commander_id(user_id).await?,
+            Roles::Admin => None,
+        };
+
+        profile_id.ok_or_else(|| anyhow::anyhow!("Failed to register the {} profile", role))
+    }
+
+    pub async fn me(&self, user_id: i32) -> Result<UserModel> {
+        let user = self.users_repository.find_by_id(user_id).await?;
+
+        let roles = self
+            .users_repository
+            .roles(user_id)
+            .await?
+            .iter()
+            .map(|user_role| Roles::try_from_str(&user_role.role))
+            .collect::<Result<Vec<Roles>>>()?;
+
+        let adventurer_id = self.users_repository.adventurer_id(user_id).await?;
+        let guild_commander_id = self.users_repository.guild_commander_id(user_id).await?;
+
+        Ok(UserModel {
+            id: user.id,
+            username: user.username,
+            roles,
+            adventurer_id,
+            guild_commander_id,
+            created_at: user.created_at,
+        })
+    }
+
+    pub async fn add_role(
+        &self,
+        user_id: i32,
+        add_user_role_model: AddUserRoleModel,
+    ) -> Result<()> {
+        if add_user_role_model.role == Roles::Admin {
+            return Err(anyhow::anyhow!("The Admin role cannot be self-assigned"));
+        }
+
+        let role = add_user_role_model.role.to_string();
+
+        let roles = self.users_repository.roles(user_id).await?;
+
+        if roles.iter().any(|user_role| user_role.role == role) {
+            return Err(anyhow::anyhow!("The user already has the {} role", role));
+        }
+
+        self.users_repository.add_role(user_id, role).await?;
+
+        Ok(())
+    }
+
+    // the only way to the Admin role, run at startup for the account named in the config;
+    // returns false when the account already is an admin
+    pub async fn grant_initial_admin(&self, username: String) -> Result<bool> {
+        let user = self.users_repository.find_by_username(username).await?;
+
+        let role = Roles::Admin.to_string();
+
+        let roles = self.users_repository.roles(user.id).await?;
+
+        if roles.iter().any(|user_role| user_role.role == role) {
+            return Ok(false);
+        }
+
+        self.users_repository.add_role(user.id, role).await?;
+
+        Ok(true)
+    }
+
+    // a deactivated account is turned away until its owner logs in again
+    pub async fn deactivate(&self, user_id: i32) -> Result<()> {
+        let user = self.users_repository.find_by_id(user_id).await?;
+
+        if user.deactivated_at.is_some() {
+            return Err(anyhow::anyhow!("The account is already deactivated"));
+        }
+
+        self.users_repository.deactivate(user_id).await?;
+
+        Ok(())
+    }
+
+    // the rows stay so quest history, ledger and audit entries keep pointing somewhere
+    pub async fn delete(&self, user_id: i32) -> Result<()> {
+        let user = self.users_repository.find_by_id(user_id).await?;
+
+        if user.deleted_at.is_some() {
+            return Err(anyhow::anyhow!("The account is already deleted"));
+        }
+
+        self.users_repository
+            .anonymize(user_id, anonymized_username(user_id))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn export(&self, user_id: i32) -> Result<UserDataExportModel> {
+        let user = self.me(user_id).await?;
+
+        let user_data = self.users_repository.export_data(user_id).await?;
+
+        Ok(UserDataExportModel {
+            adventurer: user_data
+                .adventurer
+                .as_ref()
+                .map(|adventurer| adventurer.to_profile_model()),
+            guild_memberships: user_data
+                .guild_memberships
+                .iter()
+                .map(|membership| membership.to_member_model(user.username.clone()))
+                .collect(),
+            crew: user_data.crew,
+            quest_applications: user_data
+                .quest_applications
+                .iter()
+                .map(|quest_application| quest_application.to_model())
+                .collect(),
+            quest_invitations: user_data
+                .quest_invitations
+                .iter()
+                .map(|quest_invitation| quest_invitation.to_model())
+                .collect(),
+            quest_comments: user_data
+                .quest_comments
+                .iter()
+                .map(|quest_comment| quest_comment.to_model())
+                .collect(),
+            wallet_transactions: user_data
+                .wallet_transactions
+                .iter()
+                .map(|wallet_transaction| wallet_transaction.to_model())
+                .collect(),
+            commanded_quest_ids: user_data.commanded_quest_ids,
+            exported_at: chrono::Utc::now().naive_utc(),
+            user,
+        })
+    }
+}
@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::usecases::users::UsersUseCase,
+        domain::{
+            entities::{user_roles::UserRoleEntity, users::UserEntity},
+            repositories::users::MockUsersRepository,
+            value_objects::user_model::{AddUserRoleModel, RegisterUserModel},
+        },
+        infrastructure::jwt_authentication::jwt_model::Roles,
+    };
+
+    #[tokio::test]
+    async fn test_register_defaults_to_adventurer() {
+        let mut mock_users_repo = MockUsersRepository::new();
+
+        mock_users_repo
+            .expect_register()
+            .withf(|register_user_entity, roles| {
+                register_user_entity.username == "ranger"
+                    && register_user_entity.password != "secret"
+                    && *roles == vec![Roles::Adventurer.to_string()]
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(1) }));
+
+        let use_case = UsersUseCase::new(Arc::new(mock_users_repo));
+
+        let result = use_case
+            .register(RegisterUserModel {
+                username: "ranger".to_string(),
+                password: "secret".to_string(),
+                roles: vec![],
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_register_keeps_each_role_once() {
+        let mut mock_users_repo = MockUsersRepository::new();
+
+        mock_users_repo
+            .expect_register()
+            .withf(|_, roles| {
+                *roles
+                    == vec![
+                        Roles::GuildCommander.to_string(),
+                        Roles::Adventurer.to_string(),
+                    ]
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(2) }));
+
+        let use_case = UsersUseCase::new(Arc::new(mock_users_repo));
+
+        let result = use_case
+            .register(RegisterUserModel {
+                username: "warden".to_string(),
+                password: "secret".to_string(),
+                roles: vec![
+                    Roles::GuildCommander,
+                    Roles::Adventurer,
+                    Roles::GuildCommander,
+                ],
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_role_fails_when_already_held() {
+        let mut mock_users_repo = MockUsersRepository::new();
+
+        mock_users_repo.expect_roles().returning(|_| {
+            Box::pin(async {
+                Ok(vec![UserRoleEntity {
+                    id: 1,
+                    user_id: 1,
+                    role: Roles::Adventurer.to_string(),
+                    created_at: Utc
+                        .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                }])
+            })
+        });
+
+        mock_users_repo.expect_add_role().never();
+
+        let use_case = UsersUseCase::new(Arc::new(mock_users_repo));
+
+        let result = use_case
+            .add_role(
+                1,
+                AddUserRoleModel {
+                    role: Roles::Adventurer,
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The user already has the Adventurer role"
+        )
+    }
+
+    fn user_entity(id: i32) -> UserEntity {
+        UserEntity {
+            id,
+            username: "ranger".to_string(),
+            password: "hashed".to_string(),
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            disabled_at: None,
+            deactivated_at: None,
+            deleted_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_anonymizes_in_one_call() {
+        let mut mock_users_repo = MockUsersRepository::new();
+
+        mock_users_repo
+            .expect_find_by_id()
+            .returning(|user_id| Box::pin(async move { Ok(user_entity(user_id)) }));
+
+        mock_users_repo
+            .expect_anonymize()
+            .withf(|user_id, username| *user_id == 1 && username == "deleted-user-1")
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let use_case = UsersUseCase::new(Arc::new(mock_users_repo));
+
+        let result = use_case.delete(1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_grant_initial_admin_skips_existing_admin() {
+        let mut mock_users_repo = MockUsersRepository::new();
+
+        mock_users_repo
+            .expect_find_by_username()
+            .withf(|username| username == "ranger")
+            .returning(|_| Box::pin(async { Ok(user_entity(1)) }));
+
+        mock_users_repo.expect_roles().returning(|user_id| {
+            Box::pin(async move {
+                Ok(vec![UserRoleEntity {
+                    id: 1,
+                    user_id,
+                    role: Roles::Admin.to_string(),
+                    created_at: Utc
+                        .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                }])
+            })
+        });
+
+        mock_users_repo.expect_add_role().never();
+
+        let use_case = UsersUseCase::new(Arc::new(mock_users_repo));
+
+        let result = use_case
+            .grant_initial_admin("ranger".to_string())
+            .await
+            .unwrap();
+
+        assert!(!result);
+    }
+
+    #[tokio::test]
+    async fn test_register_fails_for_deleted_username_prefix() {
+        let mut mock_users_repo = MockUsersRepository::new();
+
+        mock_users_repo.expect_register().never();
+
+        let use_case = UsersUseCase::new(Arc::new(mock_users_repo));
+
+        let result = use_case
+            .register(RegisterUserModel {
+                username: "deleted-user-7".to_string(),
+                password: "secret".to_string(),
+                roles: vec![],
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().to_string(), "The username is reserved");
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    repositories::wallet::WalletRepository, value_objects::wallet_model::WalletModel,
+};
+
+pub struct WalletUseCase<T>
+where
+    T: WalletRepository + Send + Sync,
+{
+    wallet_repository: Arc<T>,
+}
+
+impl<T> WalletUseCase<T>
+where
+    T: WalletRepository + Send + Sync,
+{
+    pub fn new(wallet_repository: Arc<T>) -> Self {
+        Self { wallet_repository }
+    }
+
+    pub async fn wallet(&self, adventurer_id: i32) -> Result<WalletModel> {
+        let balance = self.wallet_repository.balance(adventurer_id).await?;
+
+        let transactions = self
+            .wallet_repository
+            .transactions(adventurer_id)
+            .await?
+            .into_iter()
+            .map(|transaction| transaction.to_model())
+            .collect();
+
+        Ok(WalletModel {
+            adventurer_id,
+            balance,
+            transactions,
+        })
+    }
+}
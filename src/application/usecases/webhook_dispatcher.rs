@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Duration;
+use tracing::{error, warn};
+
+use crate::{
+    application::{clock::Clock, webhook_sender::WebhookSender},
+    domain::{
+        entities::{webhook_deliveries::WebhookDeliveryEntity, webhooks::WebhookEntity},
+        repositories::webhooks::WebhooksRepository,
+        value_objects::webhook_model::{
+            sign_webhook_payload, webhook_retry_delay, WebhookRequestModel, MAX_WEBHOOK_ATTEMPTS,
+            WEBHOOK_BATCH_SIZE, WEBHOOK_LEASE_SECONDS,
+        },
+    },
+};
+
+pub struct WebhookDispatcherUseCase<T, S, C>
+where
+    T: WebhooksRepository + Send + Sync,
+    S: WebhookSender + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    webhooks_repository: Arc<T>,
+    webhook_sender: Arc<S>,
+    clock: Arc<C>,
+}
+
+impl<T, S, C> WebhookDispatcherUseCase<T, S, C>
+where
+    T: WebhooksRepository + Send + Sync,
+    S: WebhookSender + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    pub fn new(webhooks_repository: Arc<T>, webhook_sender: Arc<S>, clock: Arc<C>) -> Self {
+        Self {
+            webhooks_repository,
+            webhook_sender,
+            clock,
+        }
+    }
+
+    // sends every due delivery once and returns the ids that were delivered, anything but a
+    // 2xx answer is retried with a growing delay until the attempts run out
+    pub async fn dispatch_due(&self) -> Result<Vec<i32>> {
+        let now = self.clock.now();
+
+        let deliveries = self
+            .webhooks_repository
+            .claim_due_deliveries(
+                now,
+                now + Duration::seconds(WEBHOOK_LEASE_SECONDS),
+                WEBHOOK_BATCH_SIZE,
+            )
+            .await?;
+
+        let mut delivered_ids: Vec<i32> = Vec::new();
+
+        for (delivery, webhook) in deliveries.into_iter() {
+            let webhook_request = self.signed_request(&delivery, &webhook);
+
+            match self.webhook_sender.send(webhook_request).await {
+                Ok(response_status) if (200..300).contains(&response_status) => {
+                    self.webhooks_repository
+                        .record_delivered(delivery.id, response_status as i32, self.clock.now())
+                        .await?;
+                    delivered_ids.push(delivery.id);
+                }
+                Ok(response_status) => {
+                    self.record_failure(
+                        &delivery,
+                        Some(response_status as i32),
+                        format!("Receiver responded with status {}", response_status),
+                    )
+                    .await?
+                }
+                Err(e) => self.record_failure(&delivery, None, e.to_string()).await?,
+            }
+        }
+
+        Ok(delivered_ids)
+    }
+
+    fn signed_request(
+        &self,
+        delivery: &WebhookDeliveryEntity,
+        webhook: &WebhookEntity,
+    ) -> WebhookRequestModel {
+        let timestamp = self.clock.now().and_utc().timestamp();
+        let body = delivery.payload.to_string();
+
+        WebhookRequestModel {
+            url: webhook.url.clone(),
+            delivery_id: delivery.delivery_id.clone(),
+            event_type: delivery.event_type.clone(),
+            timestamp,
+            signature: sign_webhook_payload(&webhook.secret, timestamp, &body),
+            body,
+        }
+    }
+
+    async fn record_failure(
+        &self,
+        delivery: &WebhookDeliveryEntity,
+        response_status: Option<i32>,
+        last_error: String,
+    ) -> Result<()> {
+        let attempts = delivery.attempts + 1;
+
+        if attempts >= MAX_WEBHOOK_ATTEMPTS {
+            error!(
+                "🔴 Giving up on webhook delivery id: {} after {} attempts: {}",
+                delivery.delivery_id, attempts, last_error
+            );
+
+            return self
+                .webhooks_repository
+                .record_failed(delivery.id, response_status, last_error)
+                .await;
+        }
+
+        warn!(
+            "Webhook delivery id: {} failed on attempt {}: {}",
+            delivery.delivery_id, attempts, last_error
+        );
+
+        self.webhooks_repository
+            .record_retry(
+                delivery.id,
+                response_status,
+                self.clock.now() + webhook_retry_delay(attempts),
+                last_error,
+            )
+            .await
+    }
+}
@@ -0,0 +1,252 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::{Arc, Mutex};
+
+    use anyhow::Ok;
+    use axum::{extract::State, http::HeaderMap, http::StatusCode, routing::post, Router};
+    use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+    use tokio::net::TcpListener;
+
+    use crate::{
+        application::{clock::MockClock, usecases::webhook_dispatcher::WebhookDispatcherUseCase},
+        domain::{
+            entities::{webhook_deliveries::WebhookDeliveryEntity, webhooks::WebhookEntity},
+            repositories::webhooks::MockWebhooksRepository,
+            value_objects::{
+                webhook_delivery_statuses::WebhookDeliveryStatuses,
+                webhook_model::{
+                    sign_webhook_payload, WEBHOOK_DELIVERY_HEADER, WEBHOOK_SIGNATURE_HEADER,
+                    WEBHOOK_TIMESTAMP_HEADER,
+                },
+            },
+        },
+        infrastructure::webhook_http::HttpWebhookSender,
+    };
+
+    const SECRET: &str = "0123456789abcdef";
+
+    #[derive(Debug, Clone)]
+    struct ReceivedRequest {
+        delivery_id: String,
+        timestamp: String,
+        signature: String,
+        body: String,
+    }
+
+    type Received = Arc<Mutex<Vec<ReceivedRequest>>>;
+
+    // stands in for a commander's endpoint, records what arrived and answers with a fixed status
+    async fn start_receiver(response_status: StatusCode) -> (String, Received) {
+        let received: Received = Arc::new(Mutex::new(Vec::new()));
+
+        let app =
+            Router::new()
+                .route(
+                    "/hooks",
+                    post(
+                        move |State(received): State<Received>,
+                              headers: HeaderMap,
+                              body: String| async move {
+                            let header = |name: &str| {
+                                headers
+                                    .get(name)
+                                    .and_then(|value| value.to_str().ok())
+                                    .unwrap_or_default()
+                                    .to_string()
+                            };
+
+                            received.lock().unwrap().push(ReceivedRequest {
+                                delivery_id: header(WEBHOOK_DELIVERY_HEADER),
+                                timestamp: header(WEBHOOK_TIMESTAMP_HEADER),
+                                signature: header(WEBHOOK_SIGNATURE_HEADER),
+                                body,
+                            });
+
+                            response_status
+                        },
+                    ),
+                )
+                .with_state(Arc::clone(&received));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        (format!("http://{}/hooks", addr), received)
+    }
+
+    fn now() -> NaiveDateTime {
+        Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn due_delivery(url: String, attempts: i32) -> (WebhookDeliveryEntity, WebhookEntity) {
+        (
+            WebhookDeliveryEntity {
+                id: 1,
+                delivery_id: "6f1d3c1e-2a4b-4c8d-9e0f-1a2b3c4d5e6f".to_string(),
+                webhook_id: 1,
+                event_type: "QuestCompleted".to_string(),
+                payload: serde_json::json!({
+                    "type": "QuestCompleted",
+                    "quest_id": 1,
+                    "guild_id": 1,
+                    "guild_commander_id": 1,
+                }),
+                status: WebhookDeliveryStatuses::Pending.to_string(),
+                attempts,
+                next_attempt_at: now(),
+                response_status: None,
+                last_error: None,
+                delivered_at: None,
+                created_at: now(),
+                updated_at: now(),
+            },
+            WebhookEntity {
+                id: 1,
+                guild_id: 1,
+                guild_commander_id: 1,
+                url,
+                secret: SECRET.to_string(),
+                event_types: vec![],
+                created_at: now(),
+                updated_at: now(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_due_sends_signed_delivery() {
+        let (url, received) = start_receiver(StatusCode::NO_CONTENT).await;
+
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_webhooks_repo
+            .expect_claim_due_deliveries()
+            .returning(move |_, _, _| {
+                let delivery = due_delivery(url.clone(), 0);
+                Box::pin(async move { Ok(vec![delivery]) })
+            });
+        mock_webhooks_repo
+            .expect_record_delivered()
+            .withf(|id, response_status, delivered_at| {
+                *id == 1 && *response_status == 204 && *delivered_at == now()
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        mock_webhooks_repo.expect_record_retry().times(0);
+
+        let use_case = WebhookDispatcherUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(HttpWebhookSender::for_local_receiver().unwrap()),
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.dispatch_due().await.unwrap();
+
+        assert_eq!(result, vec![1]);
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(received.len(), 1);
+
+        let request = &received[0];
+        let timestamp = now().and_utc().timestamp();
+
+        assert_eq!(request.delivery_id, "6f1d3c1e-2a4b-4c8d-9e0f-1a2b3c4d5e6f");
+        assert_eq!(request.timestamp, timestamp.to_string());
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&request.body).unwrap()["type"],
+            "QuestCompleted"
+        );
+        assert_eq!(
+            request.signature,
+            sign_webhook_payload(SECRET, timestamp, &request.body)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_due_retries_on_error_status() {
+        let (url, received) = start_receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_webhooks_repo
+            .expect_claim_due_deliveries()
+            .returning(move |_, _, _| {
+                let delivery = due_delivery(url.clone(), 1);
+                Box::pin(async move { Ok(vec![delivery]) })
+            });
+        // second attempt failed, the next one waits 10s * 2
+        mock_webhooks_repo
+            .expect_record_retry()
+            .withf(|id, response_status, next_attempt_at, last_error| {
+                *id == 1
+                    && *response_status == Some(500)
+                    && *next_attempt_at == now() + Duration::seconds(20)
+                    && last_error == "Receiver responded with status 500"
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        mock_webhooks_repo.expect_record_delivered().times(0);
+
+        let use_case = WebhookDispatcherUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(HttpWebhookSender::for_local_receiver().unwrap()),
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.dispatch_due().await.unwrap();
+
+        assert!(result.is_empty());
+        assert_eq!(received.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_due_refuses_local_address() {
+        let (url, received) = start_receiver(StatusCode::NO_CONTENT).await;
+
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_clock = MockClock::new();
+
+        mock_clock.expect_now().returning(now);
+
+        mock_webhooks_repo
+            .expect_claim_due_deliveries()
+            .returning(move |_, _, _| {
+                let delivery = due_delivery(url.clone(), 0);
+                Box::pin(async move { Ok(vec![delivery]) })
+            });
+        mock_webhooks_repo
+            .expect_record_retry()
+            .withf(|id, response_status, _, last_error| {
+                *id == 1
+                    && response_status.is_none()
+                    && last_error == "The webhook url does not point at a public address"
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        mock_webhooks_repo.expect_record_delivered().times(0);
+
+        let use_case = WebhookDispatcherUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(HttpWebhookSender::new().unwrap()),
+            Arc::new(mock_clock),
+        );
+
+        let result = use_case.dispatch_due().await.unwrap();
+
+        assert!(result.is_empty());
+        assert!(received.lock().unwrap().is_empty());
+    }
+}
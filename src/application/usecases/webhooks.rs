@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use url::{Host, Url};
+
+use crate::{
+    application::{
+        domain_events::DomainEventSubscriber, webhook_host_resolver::WebhookHostResolver,
+    },
+    domain::{
+        entities::{webhook_deliveries::AddWebhookDeliveryEntity, webhooks::WebhookEntity},
+        repositories::{guilds::GuildsRepository, webhooks::WebhooksRepository},
+        value_objects::{
+            domain_events::DomainEvent,
+            guild_membership_model::GuildMember,
+            webhook_delivery_statuses::WebhookDeliveryStatuses,
+            webhook_model::{
+                is_public_webhook_address, webhook_delivery_id, webhook_matches, AddWebhookModel,
+                WebhookDeliveryModel, WebhookModel, MAX_WEBHOOKS_PER_GUILD,
+                MAX_WEBHOOK_SECRET_LENGTH, MAX_WEBHOOK_URL_LENGTH, MIN_WEBHOOK_SECRET_LENGTH,
+            },
+        },
+    },
+};
+
+pub struct WebhooksUseCase<T1, T2, T3>
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: GuildsRepository + Send + Sync,
+    T3: WebhookHostResolver + Send + Sync,
+{
+    webhooks_repository: Arc<T1>,
+    guilds_repository: Arc<T2>,
+    webhook_host_resolver: Arc<T3>,
+}
+
+impl<T1, T2, T3> WebhooksUseCase<T1, T2, T3>
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: GuildsRepository + Send + Sync,
+    T3: WebhookHostResolver + Send + Sync,
+{
+    pub fn new(
+        webhooks_repository: Arc<T1>,
+        guilds_repository: Arc<T2>,
+        webhook_host_resolver: Arc<T3>,
+    ) -> Self {
+        Self {
+            webhooks_repository,
+            guilds_repository,
+            webhook_host_resolver,
+        }
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_webhook_model: AddWebhookModel,
+    ) -> Result<i32> {
+        let url = check_add_webhook_model(&add_webhook_model)?;
+        self.check_webhook_host(&url).await?;
+
+        self.guilds_repository
+            .membership(
+                add_webhook_model.guild_id,
+                GuildMember::GuildCommander(guild_commander_id),
+            )
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("You are not a member of this guild"))?;
+
+        let guild_webhooks = self
+            .webhooks_repository
+            .by_guild(add_webhook_model.guild_id)
+            .await?;
+
+        if guild_webhooks.len() >= MAX_WEBHOOKS_PER_GUILD {
+            return Err(anyhow::anyhow!(
+                "A guild can have at most {} webhooks",
+                MAX_WEBHOOKS_PER_GUILD
+            ));
+        }
+
+        self.webhooks_repository
+            .add(add_webhook_model.to_entity(guild_commander_id))
+            .await
+    }
+
+    pub async fn webhooks(&self, guild_commander_id: i32) -> Result<Vec<WebhookModel>> {
+        let results = self
+            .webhooks_repository
+            .by_guild_commander(guild_commander_id)
+            .await?;
+
+        Ok(results.iter().map(|webhook| webhook.to_model()).collect())
+    }
+
+    pub async fn remove(&self, webhook_id: i32, guild_commander_id: i32) -> Result<()> {
+        self.owned_webhook(webhook_id, guild_commander_id).await?;
+
+        self.webhooks_repository.remove(webhook_id).await
+    }
+
+    pub async fn deliveries(
+        &self,
+        webhook_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<Vec<WebhookDeliveryModel>> {
+        self.owned_webhook(webhook_id, guild_commander_id).await?;
+
+        let results = self.webhooks_repository.deliveries(webhook_id).await?;
+
+        Ok(results.iter().map(|delivery| delivery.to_model()).collect())
+    }
+
+    pub async fn redeliver(&self, webhook_id: i32, id: i32, guild_commander_id: i32) -> Result<()> {
+        self.owned_webhook(webhook_id, guild_commander_id).await?;
+
+        let delivery = self
+            .webhooks_repository
+            .find_delivery(webhook_id, id)
+            .await?;
+
+        if delivery.status == WebhookDeliveryStatuses::Pending.to_string() {
+            return Err(anyhow::anyhow!("The delivery is still pending"));
+        }
+
+        self.webhooks_repository
+            .redeliver(id, chrono::Utc::now().naive_utc())
+            .await
+    }
+
+    // queues one delivery for every webhook of the event's guild that listens to its type,
+    // event_id is the outbox row id and keeps the delivery ids stable across retries
+    pub async fn enqueue(&self, event_id: i32, domain_event: &DomainEvent) -> Result<()> {
+        let webhooks = self
+            .webhooks_repository
+            .by_guild(domain_event.guild_id())
+            .await?;
+
+        let payload = serde_json::to_value(domain_event)?;
+
+        let add_webhook_delivery_entities: Vec<AddWebhookDeliveryEntity> = webhooks
+            .iter()
+            .filter(|webhook| webhook_matches(&webhook.event_types, domain_event))
+            .map(|webhook| AddWebhookDeliveryEntity {
+                delivery_id: webhook_delivery_id(event_id, webhook.id),
+                webhook_id: webhook.id,
+                event_type: domain_event.event_type().to_string(),
+                payload: payload.clone(),
+                status: WebhookDeliveryStatuses::Pending.to_string(),
+            })
+            .collect();
+
+        if add_webhook_delivery_entities.is_empty() {
+            return Ok(());
+        }
+
+        self.webhooks_repository
+            .enqueue_deliveries(add_webhook_delivery_entities)
+            .await
+    }
+
+    // the sender checks again before every delivery, the host may point elsewhere by then
+    async fn check_webhook_host(&self, url: &Url) -> Result<()> {
+        let host = match url.host() {
+            Some(Host::Domain(domain)) => domain.to_string(),
+            Some(Host::Ipv4(ip)) => ip.to_string(),
+            Some(Host::Ipv6(ip)) => ip.to_string(),
+            None => return Err(anyhow::anyhow!("The webhook url must have a host")),
+        };
+        let port = url.port_or_known_default().unwrap_or(443);
+
+        let addresses = self
+            .webhook_host_resolver
+            .resolve(&host, port)
+            .await
+            .map_err(|_| anyhow::anyhow!("The webhook host could not be resolved"))?;
+
+        if addresses.is_empty() || !addresses.iter().all(is_public_webhook_address) {
+            return Err(anyhow::anyhow!(
+                "The webhook url must point at a public address"
+            ));
+        }
+
+        Ok(())
+    }
+
+    // another commander's webhook is reported as missing rather than forbidden
+    async fn owned_webhook(
+        &self,
+        webhook_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<WebhookEntity> {
+        let webhook = self.webhooks_repository.find(webhook_id).await?;
+
+        if webhook.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!("Webhook not found"));
+        }
+
+        Ok(webhook)
+    }
+}
+
+#[async_trait]
+impl<T1, T2, T3> DomainEventSubscriber for WebhooksUseCase<T1, T2, T3>
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: GuildsRepository + Send + Sync,
+    T3: WebhookHostResolver + Send + Sync,
+{
+    async fn handle(&self, event_id: i32, domain_event: &DomainEvent) -> Result<()> {
+        self.enqueue(event_id, domain_event).await
+    }
+}
+
+fn check_add_webhook_model(add_webhook_model: &AddWebhookModel) -> Result<Url> {
+    let url = add_webhook_model.url.trim();
+
+    if !url.starts_with("https://") {
+        return Err(anyhow::anyhow!("The webhook url must start with https://"));
+    }
+
+    if url.len() > MAX_WEBHOOK_URL_LENGTH {
+        return Err(anyhow::anyhow!(
+            "The webhook url must be at most {} characters",
+            MAX_WEBHOOK_URL_LENGTH
+        ));
+    }
+
+    let secret_length = add_webhook_model.secret.chars().count();
+
+    if !(MIN_WEBHOOK_SECRET_LENGTH..=MAX_WEBHOOK_SECRET_LENGTH).contains(&secret_length) {
+        return Err(anyhow::anyhow!(
+            "The webhook secret must be between {} and {} characters",
+            MIN_WEBHOOK_SECRET_LENGTH,
+            MAX_WEBHOOK_SECRET_LENGTH
+        ));
+    }
+
+    Url::parse(url).map_err(|_| anyhow::anyhow!("The webhook url is not valid"))
+}
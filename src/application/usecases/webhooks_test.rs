@@ -0,0 +1,352 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use anyhow::Ok;
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::{
+            usecases::webhooks::WebhooksUseCase, webhook_host_resolver::MockWebhookHostResolver,
+        },
+        domain::{
+            entities::{
+                guild_memberships::GuildMembershipEntity,
+                webhook_deliveries::WebhookDeliveryEntity, webhooks::WebhookEntity,
+            },
+            repositories::{guilds::MockGuildsRepository, webhooks::MockWebhooksRepository},
+            value_objects::{
+                domain_events::{DomainEvent, DomainEventTypes},
+                webhook_delivery_statuses::WebhookDeliveryStatuses,
+                webhook_model::{webhook_delivery_id, AddWebhookModel},
+            },
+        },
+    };
+
+    fn webhook_entity(id: i32, guild_commander_id: i32, event_types: Vec<String>) -> WebhookEntity {
+        WebhookEntity {
+            id,
+            guild_id: 1,
+            guild_commander_id,
+            url: format!("https://example.com/hooks/{}", id),
+            secret: "0123456789abcdef".to_string(),
+            event_types,
+            created_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_webhook_rejects_short_secret() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+        let mock_webhook_host_resolver = MockWebhookHostResolver::new();
+
+        mock_guilds_repo.expect_membership().times(0);
+        mock_webhooks_repo.expect_add().times(0);
+
+        let use_case = WebhooksUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_guilds_repo),
+            Arc::new(mock_webhook_host_resolver),
+        );
+
+        let result = use_case
+            .add(
+                1,
+                AddWebhookModel {
+                    guild_id: 1,
+                    url: "https://example.com/hooks".to_string(),
+                    secret: "short".to_string(),
+                    event_types: None,
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The webhook secret must be between 16 and 255 characters"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_webhook_success() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+        let mut mock_webhook_host_resolver = MockWebhookHostResolver::new();
+
+        mock_guilds_repo
+            .expect_membership()
+            .returning(|guild_id, _| {
+                Box::pin(async move {
+                    Ok(Some(GuildMembershipEntity {
+                        id: 1,
+                        guild_id,
+                        adventurer_id: None,
+                        guild_commander_id: Some(1),
+                        role: "Owner".to_string(),
+                        created_at: Utc::now().naive_utc(),
+                        updated_at: Utc::now().naive_utc(),
+                    }))
+                })
+            });
+        mock_webhook_host_resolver
+            .expect_resolve()
+            .withf(|host, port| host == "example.com" && *port == 443)
+            .returning(|_, _| Box::pin(async { Ok(vec!["93.184.215.14".parse().unwrap()]) }));
+        mock_webhooks_repo
+            .expect_by_guild()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_webhooks_repo
+            .expect_add()
+            .withf(|add_webhook_entity| {
+                add_webhook_entity.guild_commander_id == 1
+                    && add_webhook_entity.url == "https://example.com/hooks"
+                    && add_webhook_entity.event_types
+                        == vec!["QuestCompleted".to_string(), "QuestFailed".to_string()]
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        let use_case = WebhooksUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_guilds_repo),
+            Arc::new(mock_webhook_host_resolver),
+        );
+
+        let result = use_case
+            .add(
+                1,
+                AddWebhookModel {
+                    guild_id: 1,
+                    url: " https://example.com/hooks ".to_string(),
+                    secret: "0123456789abcdef".to_string(),
+                    event_types: Some(vec![
+                        DomainEventTypes::QuestFailed,
+                        DomainEventTypes::QuestCompleted,
+                        DomainEventTypes::QuestFailed,
+                    ]),
+                },
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_webhook_rejects_plain_http() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+        let mut mock_webhook_host_resolver = MockWebhookHostResolver::new();
+
+        mock_webhook_host_resolver.expect_resolve().times(0);
+        mock_guilds_repo.expect_membership().times(0);
+        mock_webhooks_repo.expect_add().times(0);
+
+        let use_case = WebhooksUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_guilds_repo),
+            Arc::new(mock_webhook_host_resolver),
+        );
+
+        let result = use_case
+            .add(
+                1,
+                AddWebhookModel {
+                    guild_id: 1,
+                    url: "http://example.com/hooks".to_string(),
+                    secret: "0123456789abcdef".to_string(),
+                    event_types: None,
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The webhook url must start with https://"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_webhook_rejects_host_pointing_at_private_address() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_guilds_repo = MockGuildsRepository::new();
+        let mut mock_webhook_host_resolver = MockWebhookHostResolver::new();
+
+        mock_webhook_host_resolver
+            .expect_resolve()
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![
+                        "93.184.215.14".parse().unwrap(),
+                        "10.0.0.5".parse().unwrap(),
+                    ])
+                })
+            });
+        mock_guilds_repo.expect_membership().times(0);
+        mock_webhooks_repo.expect_add().times(0);
+
+        let use_case = WebhooksUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_guilds_repo),
+            Arc::new(mock_webhook_host_resolver),
+        );
+
+        for url in [
+            "https://internal.example.com/hooks",
+            "https://127.0.0.1/hooks",
+            "https://[::1]/hooks",
+            "https://169.254.169.254/latest",
+        ] {
+            let result = use_case
+                .add(
+                    1,
+                    AddWebhookModel {
+                        guild_id: 1,
+                        url: url.to_string(),
+                        secret: "0123456789abcdef".to_string(),
+                        event_types: None,
+                    },
+                )
+                .await;
+
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "The webhook url must point at a public address"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_only_matching_webhooks() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mock_guilds_repo = MockGuildsRepository::new();
+        let mock_webhook_host_resolver = MockWebhookHostResolver::new();
+
+        mock_webhooks_repo
+            .expect_by_guild()
+            .withf(|guild_id| *guild_id == 1)
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        webhook_entity(1, 1, vec!["QuestCreated".to_string()]),
+                        webhook_entity(2, 1, vec![]),
+                        webhook_entity(3, 1, vec!["AdventurerJoined".to_string()]),
+                    ])
+                })
+            });
+        mock_webhooks_repo
+            .expect_enqueue_deliveries()
+            .withf(|add_webhook_delivery_entities| {
+                add_webhook_delivery_entities
+                    .iter()
+                    .map(|delivery| delivery.webhook_id)
+                    .collect::<Vec<i32>>()
+                    == vec![2, 3]
+                    && add_webhook_delivery_entities.iter().all(|delivery| {
+                        delivery.event_type == "AdventurerJoined"
+                            && delivery.status == WebhookDeliveryStatuses::Pending.to_string()
+                    })
+                    && add_webhook_delivery_entities.iter().all(|delivery| {
+                        delivery.delivery_id == webhook_delivery_id(7, delivery.webhook_id)
+                    })
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = WebhooksUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_guilds_repo),
+            Arc::new(mock_webhook_host_resolver),
+        );
+
+        let result = use_case
+            .enqueue(
+                7,
+                &DomainEvent::AdventurerJoined {
+                    quest_id: 1,
+                    guild_id: 1,
+                    adventurer_id: 1,
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_redeliver_fails_for_other_commanders_webhook() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mock_guilds_repo = MockGuildsRepository::new();
+        let mock_webhook_host_resolver = MockWebhookHostResolver::new();
+
+        mock_webhooks_repo.expect_find().returning(|webhook_id| {
+            Box::pin(async move { Ok(webhook_entity(webhook_id, 2, vec![])) })
+        });
+        mock_webhooks_repo.expect_find_delivery().times(0);
+        mock_webhooks_repo.expect_redeliver().times(0);
+
+        let use_case = WebhooksUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_guilds_repo),
+            Arc::new(mock_webhook_host_resolver),
+        );
+
+        let result = use_case.redeliver(1, 1, 1).await;
+
+        assert_eq!(result.unwrap_err().to_string(), "Webhook not found");
+    }
+
+    #[tokio::test]
+    async fn test_redeliver_fails_while_pending() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mock_guilds_repo = MockGuildsRepository::new();
+        let mock_webhook_host_resolver = MockWebhookHostResolver::new();
+
+        mock_webhooks_repo.expect_find().returning(|webhook_id| {
+            Box::pin(async move { Ok(webhook_entity(webhook_id, 1, vec![])) })
+        });
+        mock_webhooks_repo
+            .expect_find_delivery()
+            .returning(|webhook_id, id| {
+                Box::pin(async move {
+                    Ok(WebhookDeliveryEntity {
+                        id,
+                        delivery_id: "delivery".to_string(),
+                        webhook_id,
+                        event_type: "QuestCreated".to_string(),
+                        payload: serde_json::json!({}),
+                        status: WebhookDeliveryStatuses::Pending.to_string(),
+                        attempts: 1,
+                        next_attempt_at: Utc::now().naive_utc(),
+                        response_status: Some(500),
+                        last_error: None,
+                        delivered_at: None,
+                        created_at: Utc::now().naive_utc(),
+                        updated_at: Utc::now().naive_utc(),
+                    })
+                })
+            });
+        mock_webhooks_repo.expect_redeliver().times(0);
+
+        let use_case = WebhooksUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_guilds_repo),
+            Arc::new(mock_webhook_host_resolver),
+        );
+
+        let result = use_case.redeliver(1, 1, 1).await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The delivery is still pending"
+        );
+    }
+}
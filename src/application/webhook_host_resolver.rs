@@ -0,0 +1,12 @@
+use std::net::IpAddr;
+
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+// looks up every address a webhook host points at, an IP literal resolves to itself
+#[async_trait]
+#[automock]
+pub trait WebhookHostResolver {
+    async fn resolve(&self, host: &str, port: u16) -> Result<Vec<IpAddr>>;
+}
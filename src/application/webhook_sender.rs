@@ -0,0 +1,13 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::value_objects::webhook_model::WebhookRequestModel;
+
+// posts one signed delivery, Ok carries the receiver's status code whatever it was and Err
+// means no response arrived at all
+#[async_trait]
+#[automock]
+pub trait WebhookSender {
+    async fn send(&self, webhook_request: WebhookRequestModel) -> Result<u16>;
+}
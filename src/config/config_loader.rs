@@ -1,5 +1,7 @@
 use super::{
-    config_model::{AdventurerSecret, Database, DotEnvyConfig, GuildCommanderSecret, Server},
+    config_model::{
+        Admin, BoardEvents, Database, DotEnvyConfig, Outbox, Scheduler, Server, UserSecret, Webhook,
+    },
     stage::Stage,
 };
 use anyhow::{Ok, Result};
@@ -23,7 +25,46 @@ pub fn load() -> Result<DotEnvyConfig> {
         url: std::env::var("DATABASE_URL").expect("DATABASE_URL is invalid"),
     };
 
-    Ok(DotEnvyConfig { server, database })
+    let scheduler = Scheduler {
+        interval: std::env::var("SCHEDULER_INTERVAL")
+            .expect("SCHEDULER_INTERVAL is invalid")
+            .parse()?,
+    };
+
+    let outbox = Outbox {
+        interval: std::env::var("OUTBOX_INTERVAL")
+            .expect("OUTBOX_INTERVAL is invalid")
+            .parse()?,
+    };
+
+    let webhook = Webhook {
+        interval: std::env::var("WEBHOOK_INTERVAL")
+            .expect("WEBHOOK_INTERVAL is invalid")
+            .parse()?,
+    };
+
+    let board_events = BoardEvents {
+        notify: std::env::var("BOARD_EVENTS_NOTIFY")
+            .expect("BOARD_EVENTS_NOTIFY is invalid")
+            .parse()?,
+    };
+
+    let admin = Admin {
+        initial_username: std::env::var("ADMIN_INITIAL_USERNAME")
+            .ok()
+            .map(|username| username.trim().to_string())
+            .filter(|username| !username.is_empty()),
+    };
+
+    Ok(DotEnvyConfig {
+        server,
+        database,
+        scheduler,
+        outbox,
+        webhook,
+        board_events,
+        admin,
+    })
 }
 
 pub fn get_stage() -> Stage {
@@ -34,28 +75,15 @@ pub fn get_stage() -> Stage {
     Stage::try_form(&stage_str).unwrap_or_default()
 }
 
-pub fn get_adventurer_secret_env() -> Result<AdventurerSecret> {
-    dotenvy::dotenv().ok();
-
-    Ok(AdventurerSecret {
-        secret: std::env::var("JWT_ADVENTURER_SECRET")
-            .expect("JWT_ADVENTURER_SECRET is invalid")
-            .parse()?,
-        refresh_secret: std::env::var("JWT_ADVENTURER_REFRESH_SECRET")
-            .expect("JWT_ADVENTURER_REFRESH_SECRET is invalid")
-            .parse()?,
-    })
-}
-
-pub fn get_guild_commanders_secret_env() -> Result<GuildCommanderSecret> {
+pub fn get_user_secret_env() -> Result<UserSecret> {
     dotenvy::dotenv().ok();
 
-    Ok(GuildCommanderSecret {
-        secret: std::env::var("JWT_GUILD_COMMANDER_SECRET")
-            .expect("JWT_GUILD_COMMANDER_SECRET is invalid")
+    Ok(UserSecret {
+        secret: std::env::var("JWT_USER_SECRET")
+            .expect("JWT_USER_SECRET is invalid")
             .parse()?,
-        refresh_secret: std::env::var("JWT_GUILD_COMMANDER_REFRESH_SECRET")
-            .expect("JWT_GUILD_COMMANDER_REFRESH_SECRET is invalid")
+        refresh_secret: std::env::var("JWT_USER_REFRESH_SECRET")
+            .expect("JWT_USER_REFRESH_SECRET is invalid")
             .parse()?,
     })
 }
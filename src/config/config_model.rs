@@ -2,6 +2,11 @@
 pub struct DotEnvyConfig {
     pub server: Server,
     pub database: Database,
+    pub scheduler: Scheduler,
+    pub outbox: Outbox,
+    pub webhook: Webhook,
+    pub board_events: BoardEvents,
+    pub admin: Admin,
 }
 
 #[derive(Debug, Clone)]
@@ -17,13 +22,34 @@ pub struct Database {
 }
 
 #[derive(Debug, Clone)]
-pub struct AdventurerSecret {
-    pub secret: String,
-    pub refresh_secret: String,
+pub struct Scheduler {
+    pub interval: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Outbox {
+    pub interval: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub interval: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardEvents {
+    // fan out through postgres LISTEN/NOTIFY so every replica streams every event
+    pub notify: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Admin {
+    // the account given the Admin role at startup, admins cannot be created any other way
+    pub initial_username: Option<String>,
 }
 
 #[derive(Debug, Clone)]
-pub struct GuildCommanderSecret {
+pub struct UserSecret {
     pub secret: String,
     pub refresh_secret: String,
 }
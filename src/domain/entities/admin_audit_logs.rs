@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::admin_model::AdminAuditLogModel,
+    infrastructure::postgres::schema::admin_audit_logs,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = admin_audit_logs)]
+pub struct AdminAuditLogEntity {
+    pub id: i32,
+    pub admin_user_id: i32,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub details: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
+
+impl AdminAuditLogEntity {
+    pub fn to_model(&self) -> AdminAuditLogModel {
+        AdminAuditLogModel {
+            id: self.id,
+            admin_user_id: self.admin_user_id,
+            action: self.action.clone(),
+            target_type: self.target_type.clone(),
+            target_id: self.target_id,
+            details: self.details.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = admin_audit_logs)]
+pub struct AddAdminAuditLogEntity {
+    pub admin_user_id: i32,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub details: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
@@ -2,23 +2,40 @@ use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
 // import from schema.rs on infrastructure/postgres
-use crate::infrastructure::postgres::schema::adventurers;
+use crate::{
+    domain::value_objects::adventurer_model::AdventurerProfileModel,
+    infrastructure::postgres::schema::adventurers,
+};
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
 #[diesel(table_name = adventurers)]
 pub struct AdventurerEntity {
     pub id: i32,
     pub username: String,
-    pub password: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub experience: i64,
+    pub level: i32,
+    pub user_id: i32,
+}
+
+impl AdventurerEntity {
+    pub fn to_profile_model(&self) -> AdventurerProfileModel {
+        AdventurerProfileModel {
+            id: self.id,
+            username: self.username.clone(),
+            experience: self.experience,
+            level: self.level,
+            created_at: self.created_at,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Insertable, Queryable)]
 #[diesel(table_name = adventurers)]
 pub struct RegisterAdventurerEntity {
+    pub user_id: i32,
     pub username: String,
-    pub password: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
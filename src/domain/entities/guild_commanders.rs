@@ -9,16 +9,16 @@ use crate::infrastructure::postgres::schema::guild_commanders;
 pub struct GuildCommanderEntity {
     pub id: i32,
     pub username: String,
-    pub password: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub user_id: i32,
 }
 
 #[derive(Debug, Clone, Insertable, Queryable)]
 #[diesel(table_name = guild_commanders)]
 pub struct RegisterGuildCommanderEntity {
+    pub user_id: i32,
     pub username: String,
-    pub password: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
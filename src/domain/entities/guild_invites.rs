@@ -0,0 +1,51 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::guild_invite_model::GuildInviteModel,
+    infrastructure::postgres::schema::guild_invites,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = guild_invites)]
+pub struct GuildInviteEntity {
+    pub id: i32,
+    pub guild_id: i32,
+    pub guild_commander_id: i32,
+    pub code: String,
+    pub max_uses: Option<i32>,
+    pub uses: i32,
+    pub expires_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl GuildInviteEntity {
+    pub fn to_model(&self) -> GuildInviteModel {
+        GuildInviteModel {
+            id: self.id,
+            guild_id: self.guild_id,
+            guild_commander_id: self.guild_commander_id,
+            code: self.code.clone(),
+            link: format!("/guilds/invites/{}/accept", self.code),
+            max_uses: self.max_uses,
+            uses: self.uses,
+            expires_at: self.expires_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = guild_invites)]
+pub struct AddGuildInviteEntity {
+    pub guild_id: i32,
+    pub guild_commander_id: i32,
+    pub code: String,
+    pub max_uses: Option<i32>,
+    pub expires_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::guild_membership_model::{GuildMember, GuildMemberModel},
+    infrastructure::postgres::schema::guild_memberships,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = guild_memberships)]
+pub struct GuildMembershipEntity {
+    pub id: i32,
+    pub guild_id: i32,
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
+    pub role: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl GuildMembershipEntity {
+    pub fn member(&self) -> GuildMember {
+        match (self.adventurer_id, self.guild_commander_id) {
+            (Some(adventurer_id), _) => GuildMember::Adventurer(adventurer_id),
+            (None, guild_commander_id) => {
+                GuildMember::GuildCommander(guild_commander_id.unwrap_or_default())
+            }
+        }
+    }
+
+    pub fn to_member_model(&self, username: String) -> GuildMemberModel {
+        GuildMemberModel {
+            membership_id: self.id,
+            adventurer_id: self.adventurer_id,
+            guild_commander_id: self.guild_commander_id,
+            username,
+            role: self.role.clone(),
+            joined_at: self.created_at,
+        }
+    }
+}
+
+// exactly one of adventurer_id and guild_commander_id is set
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = guild_memberships)]
+pub struct AddGuildMembershipEntity {
+    pub guild_id: i32,
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
+    pub role: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::guild_model::GuildModel, infrastructure::postgres::schema::guilds,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = guilds)]
+pub struct GuildEntity {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl GuildEntity {
+    pub fn to_model(&self, role: Option<String>) -> GuildModel {
+        GuildModel {
+            id: self.id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            role,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = guilds)]
+pub struct AddGuildEntity {
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
@@ -1,3 +1,24 @@
+pub mod admin_audit_logs;
 pub mod adventurers;
 pub mod guild_commanders;
+pub mod guild_invites;
+pub mod guild_memberships;
+pub mod guilds;
+pub mod notification_preferences;
+pub mod notifications;
+pub mod outbox;
+pub mod quest_applications;
+pub mod quest_attempts;
+pub mod quest_comments;
+pub mod quest_invitations;
+pub mod quest_milestones;
+pub mod quest_prerequisites;
+pub mod quest_templates;
+pub mod quest_waitlist_entries;
 pub mod quests;
+pub mod tags;
+pub mod user_roles;
+pub mod users;
+pub mod wallet_transactions;
+pub mod webhook_deliveries;
+pub mod webhooks;
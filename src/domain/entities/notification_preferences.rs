@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::infrastructure::postgres::schema::notification_preferences;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = notification_preferences)]
+pub struct NotificationPreferenceEntity {
+    pub id: i32,
+    pub user_id: i32,
+    pub event_type: String,
+    pub enabled: bool,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = notification_preferences)]
+pub struct AddNotificationPreferenceEntity {
+    pub user_id: i32,
+    pub event_type: String,
+    pub enabled: bool,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,46 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::notification_model::NotificationModel,
+    infrastructure::postgres::schema::notifications,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = notifications)]
+pub struct NotificationEntity {
+    pub id: i32,
+    pub user_id: i32,
+    pub event_type: String,
+    pub quest_id: i32,
+    pub guild_id: i32,
+    pub message: String,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl NotificationEntity {
+    pub fn to_model(&self) -> NotificationModel {
+        NotificationModel {
+            id: self.id,
+            event_type: self.event_type.clone(),
+            quest_id: self.quest_id,
+            guild_id: self.guild_id,
+            message: self.message.clone(),
+            read_at: self.read_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = notifications)]
+pub struct AddNotificationEntity {
+    pub user_id: i32,
+    pub event_type: String,
+    pub quest_id: i32,
+    pub guild_id: i32,
+    pub message: String,
+    pub outbox_event_id: i32,
+}
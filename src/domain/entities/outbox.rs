@@ -0,0 +1,67 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::{domain_events::DomainEvent, outbox_model::OutboxBacklogModel},
+    infrastructure::postgres::schema::outbox,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = outbox)]
+pub struct OutboxEventEntity {
+    pub id: i32,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<NaiveDateTime>,
+    pub failed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl OutboxEventEntity {
+    pub fn to_domain_event(&self) -> Result<DomainEvent> {
+        Ok(serde_json::from_value(self.payload.clone())?)
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = outbox)]
+pub struct AddOutboxEventEntity {
+    pub event_type: String,
+    pub payload: serde_json::Value,
+}
+
+impl AddOutboxEventEntity {
+    pub fn new(domain_event: &DomainEvent) -> Result<Self> {
+        Ok(Self {
+            event_type: domain_event.event_type().to_string(),
+            payload: serde_json::to_value(domain_event)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutboxBacklogEntity {
+    pub pending: i64,
+    pub retrying: i64,
+    pub failed: i64,
+    pub oldest_pending_at: Option<NaiveDateTime>,
+}
+
+impl OutboxBacklogEntity {
+    pub fn to_model(&self, now: NaiveDateTime) -> OutboxBacklogModel {
+        OutboxBacklogModel {
+            pending: self.pending,
+            retrying: self.retrying,
+            failed: self.failed,
+            oldest_pending_at: self.oldest_pending_at,
+            oldest_pending_age_seconds: self
+                .oldest_pending_at
+                .map(|oldest_pending_at| (now - oldest_pending_at).num_seconds().max(0)),
+        }
+    }
+}
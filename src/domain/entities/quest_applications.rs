@@ -0,0 +1,42 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::quest_application_model::QuestApplicationModel,
+    infrastructure::postgres::schema::quest_applications,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_applications)]
+pub struct QuestApplicationEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub status: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestApplicationEntity {
+    pub fn to_model(&self) -> QuestApplicationModel {
+        QuestApplicationModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            adventurer_id: self.adventurer_id,
+            status: self.status.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_applications)]
+pub struct AddQuestApplicationEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub status: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,47 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::quest_attempt_model::QuestAttemptModel,
+    infrastructure::postgres::schema::{quest_attempt_crew, quest_attempts},
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_attempts)]
+pub struct QuestAttemptEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub attempt_number: i32,
+    pub outcome: Option<String>,
+    pub started_at: NaiveDateTime,
+    pub ended_at: Option<NaiveDateTime>,
+}
+
+impl QuestAttemptEntity {
+    pub fn to_model(&self, crew: Vec<i32>) -> QuestAttemptModel {
+        QuestAttemptModel {
+            id: self.id,
+            attempt_number: self.attempt_number,
+            outcome: self.outcome.clone(),
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+            crew,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_attempts)]
+pub struct AddQuestAttemptEntity {
+    pub quest_id: i32,
+    pub attempt_number: i32,
+    pub started_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable, Selectable, Queryable)]
+#[diesel(table_name = quest_attempt_crew)]
+pub struct QuestAttemptCrewEntity {
+    pub attempt_id: i32,
+    pub adventurer_id: i32,
+}
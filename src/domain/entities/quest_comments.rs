@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::quest_comment_model::QuestCommentModel,
+    infrastructure::postgres::schema::quest_comments,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_comments)]
+pub struct QuestCommentEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub author_id: i32,
+    pub author_role: String,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestCommentEntity {
+    pub fn to_model(&self) -> QuestCommentModel {
+        QuestCommentModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            author_id: self.author_id,
+            author_role: self.author_role.clone(),
+            body: self.body.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_comments)]
+pub struct AddQuestCommentEntity {
+    pub quest_id: i32,
+    pub author_id: i32,
+    pub author_role: String,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::quest_invitation_model::QuestInvitationModel,
+    infrastructure::postgres::schema::quest_invitations,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_invitations)]
+pub struct QuestInvitationEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub guild_commander_id: i32,
+    pub status: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestInvitationEntity {
+    pub fn to_model(&self) -> QuestInvitationModel {
+        QuestInvitationModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            adventurer_id: self.adventurer_id,
+            guild_commander_id: self.guild_commander_id,
+            status: self.status.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_invitations)]
+pub struct AddQuestInvitationEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub guild_commander_id: i32,
+    pub status: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
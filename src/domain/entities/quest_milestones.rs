@@ -0,0 +1,57 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::quest_milestone_model::QuestMilestoneModel,
+    infrastructure::postgres::schema::quest_milestones,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_milestones)]
+pub struct QuestMilestoneEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub required: bool,
+    pub completed_by: Option<i32>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestMilestoneEntity {
+    pub fn to_model(&self) -> QuestMilestoneModel {
+        QuestMilestoneModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            position: self.position,
+            title: self.title.clone(),
+            required: self.required,
+            completed_by: self.completed_by,
+            completed_at: self.completed_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_milestones)]
+pub struct AddQuestMilestoneEntity {
+    pub quest_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub required: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Queryable, AsChangeset)]
+#[diesel(table_name = quest_milestones)]
+pub struct EditQuestMilestoneEntity {
+    pub title: Option<String>,
+    pub required: Option<bool>,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,13 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::infrastructure::postgres::schema::quest_prerequisites;
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_prerequisites)]
+pub struct QuestPrerequisiteEntity {
+    pub quest_id: i32,
+    pub prerequisite_id: i32,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,87 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::{quest_model::AddQuestModel, quest_template_model::QuestTemplateModel},
+    infrastructure::postgres::schema::quest_templates,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_templates)]
+pub struct QuestTemplateEntity {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub capacity: i32,
+    pub reward: i64,
+    pub tags: Vec<String>,
+    pub recurrence: String,
+    pub cron_expression: Option<String>,
+    pub paused: bool,
+    pub next_run_at: NaiveDateTime,
+    pub last_run_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub guild_id: i32,
+}
+
+impl QuestTemplateEntity {
+    pub fn to_model(&self) -> QuestTemplateModel {
+        QuestTemplateModel {
+            id: self.id,
+            guild_commander_id: self.guild_commander_id,
+            guild_id: self.guild_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            capacity: self.capacity,
+            reward: self.reward,
+            tags: self.tags.clone(),
+            recurrence: self.recurrence.clone(),
+            cron_expression: self.cron_expression.clone(),
+            paused: self.paused,
+            next_run_at: self.next_run_at,
+            last_run_at: self.last_run_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    pub fn to_add_quest_model(&self) -> AddQuestModel {
+        AddQuestModel {
+            guild_id: self.guild_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            join_policy: None,
+            starts_at: None,
+            due_at: None,
+            reward: Some(self.reward),
+            experience: None,
+            experience_penalty: None,
+            min_level: None,
+            rank: None,
+            max_attempts: None,
+            capacity: Some(self.capacity),
+            tags: Some(self.tags.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_templates)]
+pub struct AddQuestTemplateEntity {
+    pub guild_commander_id: i32,
+    pub guild_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub capacity: i32,
+    pub reward: i64,
+    pub tags: Vec<String>,
+    pub recurrence: String,
+    pub cron_expression: Option<String>,
+    pub paused: bool,
+    pub next_run_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
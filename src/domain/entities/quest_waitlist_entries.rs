@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::infrastructure::postgres::schema::quest_waitlist_entries;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_waitlist_entries)]
+pub struct QuestWaitlistEntryEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_waitlist_entries)]
+pub struct AddQuestWaitlistEntryEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub created_at: NaiveDateTime,
+}
@@ -3,7 +3,8 @@ use diesel::prelude::*;
 
 // import from schema.rs on infrastructure/postgres
 use crate::{
-    domain::value_objects::quest_model::QuestModel, infrastructure::postgres::schema::quests,
+    domain::value_objects::{quest_model::QuestModel, quest_statuses::prerequisites_completed},
+    infrastructure::postgres::schema::quests,
 };
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -16,17 +17,54 @@ pub struct QuestEntity {
     pub guild_commander_id: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub join_policy: String,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward: i64,
+    pub experience: i64,
+    pub experience_penalty: i64,
+    pub min_level: Option<i32>,
+    pub rank: String,
+    pub max_attempts: Option<i32>,
+    pub capacity: i32,
+    pub guild_id: i32,
 }
 
 impl QuestEntity {
-    pub fn to_model(&self, adventurers_count: i64) -> QuestModel {
+    pub fn to_model(
+        &self,
+        adventurers_count: i64,
+        tags: Vec<String>,
+        completion_percentage: Option<i32>,
+        prerequisites: &[QuestEntity],
+    ) -> QuestModel {
         QuestModel {
             id: self.id,
             name: self.name.clone(),
             description: self.description.clone(),
             status: self.status.clone(),
             guild_commander_id: self.guild_commander_id,
+            guild_id: self.guild_id,
             adventurers_count,
+            join_policy: self.join_policy.clone(),
+            starts_at: self.starts_at,
+            due_at: self.due_at,
+            reward: self.reward,
+            experience: self.experience,
+            experience_penalty: self.experience_penalty,
+            min_level: self.min_level,
+            rank: self.rank.clone(),
+            max_attempts: self.max_attempts,
+            capacity: self.capacity,
+            tags,
+            completion_percentage,
+            prerequisite_ids: prerequisites
+                .iter()
+                .map(|prerequisite| prerequisite.id)
+                .collect(),
+            locked: !prerequisites_completed(prerequisites),
+            attempts: None,
+            headline: None,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -40,6 +78,17 @@ pub struct AddQuestEntity {
     pub description: Option<String>,
     pub status: String,
     pub guild_commander_id: i32,
+    pub join_policy: String,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward: i64,
+    pub experience: i64,
+    pub experience_penalty: i64,
+    pub min_level: Option<i32>,
+    pub rank: String,
+    pub max_attempts: Option<i32>,
+    pub capacity: i32,
+    pub guild_id: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
@@ -52,5 +101,15 @@ pub struct EditQuestEntity {
     pub name: Option<String>,
     pub description: Option<String>,
     pub guild_commander_id: i32,
+    pub join_policy: Option<String>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward: Option<i64>,
+    pub experience: Option<i64>,
+    pub experience_penalty: Option<i64>,
+    pub min_level: Option<i32>,
+    pub rank: Option<String>,
+    pub max_attempts: Option<i32>,
+    pub capacity: Option<i32>,
     pub updated_at: NaiveDateTime,
 }
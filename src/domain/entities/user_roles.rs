@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::infrastructure::postgres::schema::user_roles;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = user_roles)]
+pub struct UserRoleEntity {
+    pub id: i32,
+    pub user_id: i32,
+    pub role: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = user_roles)]
+pub struct AddUserRoleEntity {
+    pub user_id: i32,
+    pub role: String,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::quest_adventurer_junction::QuestAdventurerJunction,
+    infrastructure::postgres::schema::users,
+};
+
+use super::{
+    adventurers::AdventurerEntity, guild_memberships::GuildMembershipEntity,
+    quest_applications::QuestApplicationEntity, quest_comments::QuestCommentEntity,
+    quest_invitations::QuestInvitationEntity, wallet_transactions::WalletTransactionEntity,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = users)]
+pub struct UserEntity {
+    pub id: i32,
+    pub username: String,
+    pub password: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub disabled_at: Option<NaiveDateTime>,
+    pub deactivated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = users)]
+pub struct RegisterUserEntity {
+    pub username: String,
+    pub password: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+// everything kept about a user across its profiles, gathered for the data export
+#[derive(Debug, Clone, Default)]
+pub struct UserDataEntity {
+    pub adventurer: Option<AdventurerEntity>,
+    pub guild_memberships: Vec<GuildMembershipEntity>,
+    pub crew: Vec<QuestAdventurerJunction>,
+    pub quest_applications: Vec<QuestApplicationEntity>,
+    pub quest_invitations: Vec<QuestInvitationEntity>,
+    pub quest_comments: Vec<QuestCommentEntity>,
+    pub wallet_transactions: Vec<WalletTransactionEntity>,
+    pub commanded_quest_ids: Vec<i32>,
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::wallet_model::WalletTransactionModel,
+    infrastructure::postgres::schema::wallet_transactions,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = wallet_transactions)]
+pub struct WalletTransactionEntity {
+    pub id: i32,
+    pub adventurer_id: i32,
+    pub quest_id: Option<i32>,
+    pub amount: i64,
+    pub kind: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl WalletTransactionEntity {
+    pub fn to_model(&self) -> WalletTransactionModel {
+        WalletTransactionModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            amount: self.amount,
+            kind: self.kind.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Insertable, Queryable)]
+#[diesel(table_name = wallet_transactions)]
+pub struct AddWalletTransactionEntity {
+    pub adventurer_id: i32,
+    pub quest_id: Option<i32>,
+    pub amount: i64,
+    pub kind: String,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,56 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::webhook_model::WebhookDeliveryModel,
+    infrastructure::postgres::schema::webhook_deliveries,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct WebhookDeliveryEntity {
+    pub id: i32,
+    pub delivery_id: String,
+    pub webhook_id: i32,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl WebhookDeliveryEntity {
+    pub fn to_model(&self) -> WebhookDeliveryModel {
+        WebhookDeliveryModel {
+            id: self.id,
+            delivery_id: self.delivery_id.clone(),
+            webhook_id: self.webhook_id,
+            event_type: self.event_type.clone(),
+            payload: self.payload.clone(),
+            status: self.status.clone(),
+            attempts: self.attempts,
+            next_attempt_at: self.next_attempt_at,
+            response_status: self.response_status,
+            last_error: self.last_error.clone(),
+            delivered_at: self.delivered_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct AddWebhookDeliveryEntity {
+    pub delivery_id: String,
+    pub webhook_id: i32,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+}
@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+// import from schema.rs on infrastructure/postgres
+use crate::{
+    domain::value_objects::webhook_model::WebhookModel, infrastructure::postgres::schema::webhooks,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = webhooks)]
+pub struct WebhookEntity {
+    pub id: i32,
+    pub guild_id: i32,
+    pub guild_commander_id: i32,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl WebhookEntity {
+    // the secret never leaves the server once it is registered
+    pub fn to_model(&self) -> WebhookModel {
+        WebhookModel {
+            id: self.id,
+            guild_id: self.guild_id,
+            guild_commander_id: self.guild_commander_id,
+            url: self.url.clone(),
+            event_types: self.event_types.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = webhooks)]
+pub struct AddWebhookEntity {
+    pub guild_id: i32,
+    pub guild_commander_id: i32,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+}
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::{
+    entities::{
+        admin_audit_logs::{AddAdminAuditLogEntity, AdminAuditLogEntity},
+        quests::QuestEntity,
+        user_roles::UserRoleEntity,
+        users::UserEntity,
+    },
+    value_objects::admin_model::{AdminAuditLogFilter, UserSearchFilter},
+};
+
+// every change an admin makes is written together with its audit log entry
+#[async_trait]
+#[automock]
+pub trait AdminRepository {
+    async fn search_users(&self, filter: &UserSearchFilter) -> Result<Vec<UserEntity>>;
+    async fn roles_by_user_ids(&self, user_ids: Vec<i32>) -> Result<Vec<UserRoleEntity>>;
+    async fn set_user_disabled_at(
+        &self,
+        user_id: i32,
+        disabled_at: Option<NaiveDateTime>,
+        add_admin_audit_log_entity: AddAdminAuditLogEntity,
+    ) -> Result<()>;
+    // unlike the quest viewing lookup this also finds removed quests, with their deleted_at
+    async fn find_quest(&self, quest_id: i32) -> Result<(QuestEntity, Option<NaiveDateTime>)>;
+    async fn force_quest_status(
+        &self,
+        quest_id: i32,
+        status: String,
+        add_admin_audit_log_entity: AddAdminAuditLogEntity,
+    ) -> Result<()>;
+    async fn restore_quest(
+        &self,
+        quest_id: i32,
+        add_admin_audit_log_entity: AddAdminAuditLogEntity,
+    ) -> Result<()>;
+    async fn audit_logs(&self, filter: &AdminAuditLogFilter) -> Result<Vec<AdminAuditLogEntity>>;
+}
@@ -2,12 +2,10 @@ use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
-use crate::domain::entities::adventurers::{AdventurerEntity, RegisterAdventurerEntity};
+use crate::domain::entities::adventurers::AdventurerEntity;
 
 #[async_trait]
 #[automock] // mock generate
 pub trait AdventurersRepository {
-    async fn register(&self, register_adventurer_entity: RegisterAdventurerEntity) -> Result<i32>;
-
-    async fn find_by_username(&self, username: String) -> Result<AdventurerEntity>;
+    async fn find_by_id(&self, adventurer_id: i32) -> Result<AdventurerEntity>;
 }
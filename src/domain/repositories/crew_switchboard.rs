@@ -2,11 +2,76 @@ use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
-use crate::domain::value_objects::quest_adventurer_junction::QuestAdventurerJunction;
+use crate::domain::{
+    entities::{
+        quest_applications::{AddQuestApplicationEntity, QuestApplicationEntity},
+        quest_invitations::{AddQuestInvitationEntity, QuestInvitationEntity},
+        quest_waitlist_entries::AddQuestWaitlistEntryEntity,
+    },
+    value_objects::{
+        domain_events::DomainEvent, quest_adventurer_junction::QuestAdventurerJunction,
+        quest_join_outcomes::QuestJoinOutcomes,
+    },
+};
 
+// the domain event handed to a crew change goes to the outbox in the same transaction
 #[async_trait]
 #[automock] // mock generate
 pub trait CrewSwitchboardRepository {
-    async fn join(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
-    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    // rechecks the seat with the quest locked, a full crew or anyone already waiting puts the
+    // adventurer on the waitlist instead
+    async fn join(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        domain_event: DomainEvent,
+    ) -> Result<QuestJoinOutcomes>;
+    // also promotes the head of the quest waitlist when a crew seat opens up
+    async fn leave(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        domain_event: DomainEvent,
+    ) -> Result<()>;
+    async fn enqueue(
+        &self,
+        add_quest_waitlist_entry_entity: AddQuestWaitlistEntryEntity,
+    ) -> Result<i64>;
+    async fn waitlist_position(&self, quest_id: i32, adventurer_id: i32) -> Result<i64>;
+    async fn dequeue(&self, quest_id: i32, adventurer_id: i32) -> Result<()>;
+    async fn adventurer_level(&self, adventurer_id: i32) -> Result<i32>;
+    async fn is_guild_member(&self, guild_id: i32, adventurer_id: i32) -> Result<bool>;
+    async fn assign_role(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+        role: Option<String>,
+    ) -> Result<()>;
+    async fn set_reward_weight(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+        reward_weight: i32,
+    ) -> Result<()>;
+    async fn invite(&self, add_quest_invitation_entity: AddQuestInvitationEntity) -> Result<i32>;
+    async fn find_invitation(&self, invitation_id: i32) -> Result<QuestInvitationEntity>;
+    async fn pending_invitations(&self, adventurer_id: i32) -> Result<Vec<QuestInvitationEntity>>;
+    // same seat recheck as approve_application
+    async fn accept_invitation(
+        &self,
+        invitation_id: i32,
+        junction_body: QuestAdventurerJunction,
+        domain_event: DomainEvent,
+    ) -> Result<()>;
+    async fn decline_invitation(&self, invitation_id: i32) -> Result<()>;
+    async fn apply(&self, add_quest_application_entity: AddQuestApplicationEntity) -> Result<i32>;
+    async fn find_application(&self, application_id: i32) -> Result<QuestApplicationEntity>;
+    async fn pending_applications(&self, quest_id: i32) -> Result<Vec<QuestApplicationEntity>>;
+    // fails when the crew filled up since the use case checked, the quest is locked to recheck
+    async fn approve_application(
+        &self,
+        application_id: i32,
+        junction_body: QuestAdventurerJunction,
+        domain_event: DomainEvent,
+    ) -> Result<()>;
+    async fn reject_application(&self, application_id: i32) -> Result<()>;
+    async fn withdraw_application(&self, application_id: i32) -> Result<()>;
 }
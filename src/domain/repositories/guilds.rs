@@ -0,0 +1,57 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::{
+        guild_invites::{AddGuildInviteEntity, GuildInviteEntity},
+        guild_memberships::{AddGuildMembershipEntity, GuildMembershipEntity},
+        guilds::{AddGuildEntity, GuildEntity},
+    },
+    value_objects::guild_membership_model::{GuildMember, GuildResource},
+};
+
+#[async_trait]
+#[automock]
+pub trait GuildsRepository {
+    // the guild and the Owner membership of its founder are created together
+    async fn add(&self, add_guild_entity: AddGuildEntity, guild_commander_id: i32) -> Result<i32>;
+    async fn find(&self, guild_id: i32) -> Result<GuildEntity>;
+    // the guild the row belongs to, None when it does not exist or was removed
+    async fn resource_guild_id(&self, resource: GuildResource) -> Result<Option<i32>>;
+    async fn guilds_by_member(&self, member: GuildMember) -> Result<Vec<(GuildEntity, String)>>;
+    async fn membership(
+        &self,
+        guild_id: i32,
+        member: GuildMember,
+    ) -> Result<Option<GuildMembershipEntity>>;
+    async fn add_member(
+        &self,
+        add_guild_membership_entity: AddGuildMembershipEntity,
+    ) -> Result<i32>;
+    // every membership of the guild with the username of the member
+    async fn members_by_guild_id(
+        &self,
+        guild_id: i32,
+    ) -> Result<Vec<(GuildMembershipEntity, String)>>;
+    async fn find_membership(&self, membership_id: i32) -> Result<GuildMembershipEntity>;
+    async fn change_member_role(&self, membership_id: i32, role: String) -> Result<()>;
+    // an adventurer also leaves the guild's Open quests, each seat going to the waitlist, and
+    // loses their waitlist entries, pending applications and pending invitations for the
+    // quests of the guild, all in one transaction
+    async fn remove_member(&self, guild_id: i32, membership_id: i32) -> Result<()>;
+    async fn add_invite(
+        &self,
+        add_guild_invite_entity: AddGuildInviteEntity,
+    ) -> Result<GuildInviteEntity>;
+    async fn find_invite(&self, invite_id: i32) -> Result<GuildInviteEntity>;
+    async fn find_invite_by_code(&self, code: String) -> Result<GuildInviteEntity>;
+    async fn invites_by_guild_id(&self, guild_id: i32) -> Result<Vec<GuildInviteEntity>>;
+    async fn revoke_invite(&self, invite_id: i32) -> Result<()>;
+    // counts the use and adds the membership together, fails once max_uses is reached
+    async fn accept_invite(
+        &self,
+        invite_id: i32,
+        add_guild_membership_entity: AddGuildMembershipEntity,
+    ) -> Result<i32>;
+}
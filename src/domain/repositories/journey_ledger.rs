@@ -2,11 +2,34 @@ use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
+use crate::domain::{
+    entities::wallet_transactions::AddWalletTransactionEntity,
+    value_objects::domain_events::DomainEvent,
+};
+
+// the domain event handed to a status change goes to the outbox in the same transaction
 #[async_trait]
 #[automock]
 pub trait JourneyLedgerRepository {
-    async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
-    async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
-    async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
+    async fn in_journey(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        domain_event: DomainEvent,
+    ) -> Result<i32>;
+    async fn to_completed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        rewards: Vec<AddWalletTransactionEntity>,
+        experience_change: i64,
+        domain_event: DomainEvent,
+    ) -> Result<i32>;
+    async fn to_failed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        experience_change: i64,
+        domain_event: DomainEvent,
+    ) -> Result<i32>;
 }
- 
\ No newline at end of file
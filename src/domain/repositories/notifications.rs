@@ -0,0 +1,40 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::{
+    notification_preferences::{AddNotificationPreferenceEntity, NotificationPreferenceEntity},
+    notifications::{AddNotificationEntity, NotificationEntity},
+};
+
+#[async_trait]
+#[automock]
+pub trait NotificationsRepository {
+    // a user already notified about the same outbox event is skipped
+    async fn add(&self, add_notification_entities: Vec<AddNotificationEntity>) -> Result<()>;
+    // newest first
+    async fn by_user(
+        &self,
+        user_id: i32,
+        unread_only: bool,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<NotificationEntity>>;
+    async fn counting_by_user(&self, user_id: i32, unread_only: bool) -> Result<i64>;
+    async fn mark_read(&self, user_id: i32, notification_id: i32) -> Result<()>;
+    // returns how many were still unread
+    async fn mark_all_read(&self, user_id: i32) -> Result<usize>;
+    async fn preferences(&self, user_id: i32) -> Result<Vec<NotificationPreferenceEntity>>;
+    async fn set_preferences(
+        &self,
+        add_notification_preference_entities: Vec<AddNotificationPreferenceEntity>,
+    ) -> Result<()>;
+    // the users among user_ids who turned the event type off
+    async fn muted_user_ids(&self, event_type: String, user_ids: Vec<i32>) -> Result<Vec<i32>>;
+    // the adventurers on the quest now and the crew of its latest attempt, as user ids
+    async fn crew_user_ids(&self, quest_id: i32) -> Result<Vec<i32>>;
+    async fn guild_commander_user_id(&self, quest_id: i32) -> Result<i32>;
+    // removed quests keep their name so the inbox can still tell what happened to them
+    async fn quest_name(&self, quest_id: i32) -> Result<String>;
+    async fn adventurer_username(&self, adventurer_id: i32) -> Result<String>;
+}
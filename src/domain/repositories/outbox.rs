@@ -0,0 +1,44 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::entities::outbox::{OutboxBacklogEntity, OutboxEventEntity};
+
+// rows are written by the other repositories inside their own transactions, this side only
+// hands them out to the dispatcher and records how delivery went
+#[async_trait]
+#[automock]
+pub trait OutboxRepository {
+    // locks due rows with SKIP LOCKED and pushes their next_attempt_at to lease_until, so
+    // concurrent dispatchers never claim the same row
+    async fn claim_due(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+        batch_size: i64,
+    ) -> Result<Vec<OutboxEventEntity>>;
+    async fn mark_delivered(&self, outbox_event_id: i32, delivered_at: NaiveDateTime)
+        -> Result<()>;
+    async fn handled_subscribers(&self, outbox_event_id: i32) -> Result<Vec<String>>;
+    // a subscriber already recorded for the event is left as it is
+    async fn mark_handled(
+        &self,
+        outbox_event_id: i32,
+        subscribers: Vec<String>,
+        handled_at: NaiveDateTime,
+    ) -> Result<()>;
+    async fn schedule_retry(
+        &self,
+        outbox_event_id: i32,
+        next_attempt_at: NaiveDateTime,
+        last_error: String,
+    ) -> Result<()>;
+    async fn mark_failed(
+        &self,
+        outbox_event_id: i32,
+        failed_at: NaiveDateTime,
+        last_error: String,
+    ) -> Result<()>;
+    async fn backlog(&self) -> Result<OutboxBacklogEntity>;
+}
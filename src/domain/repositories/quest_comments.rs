@@ -0,0 +1,22 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::quest_comments::{AddQuestCommentEntity, QuestCommentEntity};
+
+#[async_trait]
+#[automock]
+pub trait QuestCommentsRepository {
+    async fn add(&self, add_quest_comment_entity: AddQuestCommentEntity) -> Result<i32>;
+    async fn find(&self, comment_id: i32) -> Result<QuestCommentEntity>;
+    async fn comments_by_quest_id(
+        &self,
+        quest_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<QuestCommentEntity>>;
+    async fn counting_by_quest_id(&self, quest_id: i32) -> Result<i64>;
+    async fn edit(&self, comment_id: i32, body: String) -> Result<()>;
+    async fn remove(&self, comment_id: i32) -> Result<()>;
+    async fn is_crew_member(&self, quest_id: i32, adventurer_id: i32) -> Result<bool>;
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::quest_milestones::{
+    AddQuestMilestoneEntity, EditQuestMilestoneEntity, QuestMilestoneEntity,
+};
+
+#[async_trait]
+#[automock]
+pub trait QuestMilestonesRepository {
+    async fn add(&self, add_quest_milestone_entity: AddQuestMilestoneEntity) -> Result<i32>;
+    async fn find(&self, milestone_id: i32) -> Result<QuestMilestoneEntity>;
+    async fn edit(
+        &self,
+        milestone_id: i32,
+        edit_quest_milestone_entity: EditQuestMilestoneEntity,
+        position: Option<i32>,
+    ) -> Result<i32>;
+    async fn remove(&self, milestone_id: i32) -> Result<()>;
+    async fn tick(&self, milestone_id: i32, adventurer_id: i32) -> Result<()>;
+    async fn untick(&self, milestone_id: i32) -> Result<()>;
+}
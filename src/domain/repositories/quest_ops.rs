@@ -2,12 +2,36 @@ use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
-use crate::domain::entities::quests::{AddQuestEntity, EditQuestEntity};
+use crate::domain::{
+    entities::{
+        quest_prerequisites::QuestPrerequisiteEntity,
+        quests::{AddQuestEntity, EditQuestEntity},
+    },
+    value_objects::domain_events::DomainEvent,
+};
 
+// the domain event handed to a write goes to the outbox in the same transaction
 #[async_trait]
 #[automock]
 pub trait QuestOpsRepository {
-    async fn add(&self, add_quest_entity: AddQuestEntity) -> Result<i32>;
-    async fn edit(&self, quest_id: i32, edit_quest_entity: EditQuestEntity) -> Result<i32>;
-    async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()>;
+    // records QuestCreated itself, the quest id only exists once the row is inserted
+    async fn add(&self, add_quest_entity: AddQuestEntity, tags: Vec<String>) -> Result<i32>;
+    async fn edit(
+        &self,
+        quest_id: i32,
+        edit_quest_entity: EditQuestEntity,
+        tags: Option<Vec<String>>,
+        domain_event: DomainEvent,
+    ) -> Result<i32>;
+    async fn remove(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        domain_event: DomainEvent,
+    ) -> Result<()>;
+    async fn add_prerequisite(
+        &self,
+        quest_prerequisite_entity: QuestPrerequisiteEntity,
+    ) -> Result<()>;
+    async fn remove_prerequisite(&self, quest_id: i32, prerequisite_id: i32) -> Result<()>;
 }
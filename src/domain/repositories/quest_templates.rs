@@ -0,0 +1,33 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::entities::quest_templates::{AddQuestTemplateEntity, QuestTemplateEntity};
+
+#[async_trait]
+#[automock]
+pub trait QuestTemplatesRepository {
+    async fn add(&self, add_quest_template_entity: AddQuestTemplateEntity) -> Result<i32>;
+    async fn find(&self, template_id: i32) -> Result<QuestTemplateEntity>;
+    async fn templates_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestTemplateEntity>>;
+    // active templates whose next run is at or before now, they are pushed to lease_until
+    // so another scheduler does not post them too while this run is underway
+    async fn claim_due(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+    ) -> Result<Vec<QuestTemplateEntity>>;
+    async fn pause(&self, template_id: i32) -> Result<()>;
+    async fn resume(&self, template_id: i32, next_run_at: NaiveDateTime) -> Result<()>;
+    async fn remove(&self, template_id: i32) -> Result<()>;
+    async fn record_run(
+        &self,
+        template_id: i32,
+        last_run_at: NaiveDateTime,
+        next_run_at: NaiveDateTime,
+    ) -> Result<()>;
+}
@@ -1,15 +1,70 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 use axum::async_trait;
+use chrono::NaiveDateTime;
 use mockall::automock;
 
 use crate::domain::{
-    entities::quests::QuestEntity, value_objects::board_checking_filter::BoardCheckingFilter,
+    entities::{
+        adventurers::AdventurerEntity, quest_attempts::QuestAttemptEntity,
+        quest_milestones::QuestMilestoneEntity, quests::QuestEntity,
+    },
+    value_objects::{
+        board_checking_filter::BoardCheckingFilter, guild_membership_model::GuildMember,
+        quest_adventurer_junction::QuestAdventurerJunction,
+    },
 };
 
 #[async_trait]
 #[automock]
 pub trait QuestViewingRepository {
     async fn view_details(&self, quest_id: i32) -> Result<QuestEntity>;
-    async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestEntity>>;
+    // the headline is only set when the filter has a search query,
+    // joinable_by keeps only the quests that adventurer can join right now
+    async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+        member: GuildMember,
+        joinable_by: Option<i32>,
+    ) -> Result<Vec<(QuestEntity, Option<String>)>>;
+    // the ids the same board holds, quest_id narrows it down to that one quest
+    async fn board_quest_ids(
+        &self,
+        filter: &BoardCheckingFilter,
+        member: GuildMember,
+        joinable_by: Option<i32>,
+        quest_id: Option<i32>,
+    ) -> Result<Vec<i32>>;
     async fn adventurers_counting_by_quest_id(&self, quest_id: i32) -> Result<i64>;
+    async fn tags_by_quest_id(&self, quest_id: i32) -> Result<Vec<String>>;
+    // oldest first, each attempt with the adventurer ids of its crew
+    async fn attempts_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<(QuestAttemptEntity, Vec<i32>)>>;
+    // the quests that must be Completed first, removed quests no longer count
+    async fn prerequisites_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestEntity>>;
+    // every quest reachable by following prerequisites from quest_id
+    async fn prerequisite_chain_ids(&self, quest_id: i32) -> Result<Vec<i32>>;
+    async fn milestones_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestMilestoneEntity>>;
+    async fn overdue_quests_in_journey(&self, now: NaiveDateTime) -> Result<Vec<QuestEntity>>;
+    async fn crew_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<(QuestAdventurerJunction, AdventurerEntity)>>;
+    // the same lookups for a whole board page at once, quests without rows are left out
+    async fn adventurers_counting_by_quest_ids(
+        &self,
+        quest_ids: &[i32],
+    ) -> Result<HashMap<i32, i64>>;
+    async fn tags_by_quest_ids(&self, quest_ids: &[i32]) -> Result<HashMap<i32, Vec<String>>>;
+    async fn prerequisites_by_quest_ids(
+        &self,
+        quest_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<QuestEntity>>>;
+    async fn milestones_by_quest_ids(
+        &self,
+        quest_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<QuestMilestoneEntity>>>;
 }
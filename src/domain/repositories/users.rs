@@ -0,0 +1,47 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::{
+    user_roles::UserRoleEntity,
+    users::{RegisterUserEntity, UserDataEntity, UserEntity},
+};
+
+#[async_trait]
+#[automock] // mock generate
+pub trait UsersRepository {
+    // the user, its role assignments and a profile per role are created together
+    async fn register(
+        &self,
+        register_user_entity: RegisterUserEntity,
+        roles: Vec<String>,
+    ) -> Result<i32>;
+
+    async fn find_by_id(&self, user_id: i32) -> Result<UserEntity>;
+
+    async fn find_by_username(&self, username: String) -> Result<UserEntity>;
+
+    // the per-role logins still take the username kept on the adventurer or guild commander
+    async fn find_by_adventurer_username(&self, username: String) -> Result<UserEntity>;
+
+    async fn find_by_guild_commander_username(&self, username: String) -> Result<UserEntity>;
+
+    async fn roles(&self, user_id: i32) -> Result<Vec<UserRoleEntity>>;
+
+    async fn adventurer_id(&self, user_id: i32) -> Result<Option<i32>>;
+
+    async fn guild_commander_id(&self, user_id: i32) -> Result<Option<i32>>;
+
+    async fn add_role(&self, user_id: i32, role: String) -> Result<()>;
+
+    async fn deactivate(&self, user_id: i32) -> Result<()>;
+
+    async fn reactivate(&self, user_id: i32) -> Result<()>;
+
+    // renames the user and its profiles, wipes the password hash, leaves the crews of open
+    // quests and drops whatever could still pull the user into a crew (waitlist entries,
+    // pending applications and invitations), the notification inbox goes with it; all or nothing
+    async fn anonymize(&self, user_id: i32, username: String) -> Result<()>;
+
+    async fn export_data(&self, user_id: i32) -> Result<UserDataEntity>;
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::wallet_transactions::WalletTransactionEntity;
+
+#[async_trait]
+#[automock]
+pub trait WalletRepository {
+    async fn balance(&self, adventurer_id: i32) -> Result<i64>;
+    async fn transactions(&self, adventurer_id: i32) -> Result<Vec<WalletTransactionEntity>>;
+}
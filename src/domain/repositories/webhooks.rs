@@ -0,0 +1,55 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::entities::{
+    webhook_deliveries::{AddWebhookDeliveryEntity, WebhookDeliveryEntity},
+    webhooks::{AddWebhookEntity, WebhookEntity},
+};
+
+#[async_trait]
+#[automock]
+pub trait WebhooksRepository {
+    async fn add(&self, add_webhook_entity: AddWebhookEntity) -> Result<i32>;
+    async fn find(&self, webhook_id: i32) -> Result<WebhookEntity>;
+    async fn remove(&self, webhook_id: i32) -> Result<()>;
+    async fn by_guild_commander(&self, guild_commander_id: i32) -> Result<Vec<WebhookEntity>>;
+    async fn by_guild(&self, guild_id: i32) -> Result<Vec<WebhookEntity>>;
+    // a delivery already queued for the same webhook and delivery id is left as it is
+    async fn enqueue_deliveries(
+        &self,
+        add_webhook_delivery_entities: Vec<AddWebhookDeliveryEntity>,
+    ) -> Result<()>;
+    // newest first
+    async fn deliveries(&self, webhook_id: i32) -> Result<Vec<WebhookDeliveryEntity>>;
+    async fn find_delivery(&self, webhook_id: i32, id: i32) -> Result<WebhookDeliveryEntity>;
+    // puts a finished delivery back in line with a fresh attempt budget, the delivery id stays
+    async fn redeliver(&self, id: i32, next_attempt_at: NaiveDateTime) -> Result<()>;
+    // same lease as the outbox, rows are locked with SKIP LOCKED and hidden until lease_until
+    async fn claim_due_deliveries(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+        batch_size: i64,
+    ) -> Result<Vec<(WebhookDeliveryEntity, WebhookEntity)>>;
+    async fn record_delivered(
+        &self,
+        id: i32,
+        response_status: i32,
+        delivered_at: NaiveDateTime,
+    ) -> Result<()>;
+    async fn record_retry(
+        &self,
+        id: i32,
+        response_status: Option<i32>,
+        next_attempt_at: NaiveDateTime,
+        last_error: String,
+    ) -> Result<()>;
+    async fn record_failed(
+        &self,
+        id: i32,
+        response_status: Option<i32>,
+        last_error: String,
+    ) -> Result<()>;
+}
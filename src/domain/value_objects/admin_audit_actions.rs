@@ -0,0 +1,37 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum AdminAuditActions {
+    DisableUser,
+    EnableUser,
+    ForceQuestStatus,
+    RestoreQuest,
+}
+
+impl fmt::Display for AdminAuditActions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdminAuditActions::DisableUser => write!(f, "DisableUser"),
+            AdminAuditActions::EnableUser => write!(f, "EnableUser"),
+            AdminAuditActions::ForceQuestStatus => write!(f, "ForceQuestStatus"),
+            AdminAuditActions::RestoreQuest => write!(f, "RestoreQuest"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum AdminAuditTargets {
+    User,
+    Quest,
+}
+
+impl fmt::Display for AdminAuditTargets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdminAuditTargets::User => write!(f, "User"),
+            AdminAuditTargets::Quest => write!(f, "Quest"),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::entities::admin_audit_logs::AddAdminAuditLogEntity,
+    infrastructure::jwt_authentication::jwt_model::Roles,
+};
+
+use super::{
+    admin_audit_actions::{AdminAuditActions, AdminAuditTargets},
+    quest_statuses::QuestStatuses,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UserSearchFilter {
+    // case-insensitive match anywhere in the username
+    pub q: Option<String>,
+    pub role: Option<Roles>,
+    pub disabled: Option<bool>,
+}
+
+impl UserSearchFilter {
+    pub fn search_query(&self) -> Option<String> {
+        self.q
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(str::to_string)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminUserModel {
+    pub id: i32,
+    pub username: String,
+    pub roles: Vec<Roles>,
+    pub disabled_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+// starting and completing a quest open attempts and pay the crew, so only the journey ledger
+// moves a quest into InJourney or Completed
+pub const FORCEABLE_QUEST_STATUSES: [QuestStatuses; 2] =
+    [QuestStatuses::Open, QuestStatuses::Failed];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForceQuestStatusModel {
+    pub status: QuestStatuses,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct AdminAuditLogFilter {
+    pub admin_user_id: Option<i32>,
+    pub target_type: Option<AdminAuditTargets>,
+    pub target_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminAuditLogModel {
+    pub id: i32,
+    pub admin_user_id: i32,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub details: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
+
+pub fn to_audit_log_entity(
+    admin_user_id: i32,
+    action: AdminAuditActions,
+    target_type: AdminAuditTargets,
+    target_id: i32,
+    details: serde_json::Value,
+) -> AddAdminAuditLogEntity {
+    AddAdminAuditLogEntity {
+        admin_user_id,
+        action: action.to_string(),
+        target_type: target_type.to_string(),
+        target_id,
+        details,
+        created_at: chrono::Utc::now().naive_utc(),
+    }
+}
@@ -1,20 +1,11 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::adventurers::RegisterAdventurerEntity;
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RegisterAdventurerModel {
+pub struct AdventurerProfileModel {
+    pub id: i32,
     pub username: String,
-    pub password: String,
-}
-
-impl RegisterAdventurerModel {
-    pub fn to_entity(&self) -> RegisterAdventurerEntity {
-        RegisterAdventurerEntity {
-            username: self.username.clone(),
-            password: self.password.clone(),
-            created_at: chrono::Utc::now().naive_utc(),
-            updated_at: chrono::Utc::now().naive_utc(),
-        }
-    }
+    pub experience: i64,
+    pub level: i32,
+    pub created_at: NaiveDateTime,
 }
@@ -1,11 +1,83 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use super::quest_statuses::QuestStatuses;
-
-
+use super::{
+    quest_ranks::QuestRanks,
+    quest_statuses::QuestStatuses,
+    quest_tags::{normalize_quest_tag, QuestTagsMatches},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BoardCheckingFilter {
+    // full-text search over name and description, results are ordered by relevance
+    pub q: Option<String>,
     pub name: Option<String>,
     pub status: Option<QuestStatuses>,
+    // comma separated, e.g. statuses=Open,Failed
+    pub statuses: Option<String>,
+    pub guild_commander_id: Option<i32>,
+    pub created_from: Option<NaiveDateTime>,
+    pub created_to: Option<NaiveDateTime>,
+    pub updated_from: Option<NaiveDateTime>,
+    pub updated_to: Option<NaiveDateTime>,
+    // only quests the signed in adventurer can join right now
+    pub joinable: Option<bool>,
+    pub min_rank: Option<QuestRanks>,
+    pub max_rank: Option<QuestRanks>,
+    // comma separated, e.g. tags=escort,night
+    pub tags: Option<String>,
+    pub tags_match: Option<QuestTagsMatches>,
+}
+
+impl BoardCheckingFilter {
+    pub fn search_query(&self) -> Option<String> {
+        self.q
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(str::to_string)
+    }
+
+    // fails on the first name that is not a quest status
+    pub fn status_names(&self) -> Result<Vec<String>> {
+        let mut status_names = self
+            .statuses
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|status| !status.is_empty())
+            .map(|status| QuestStatuses::try_from_str(status).map(|status| status.to_string()))
+            .collect::<Result<Vec<String>>>()?;
+
+        if let Some(status) = &self.status {
+            status_names.push(status.to_string());
+        }
+
+        Ok(status_names)
+    }
+
+    pub fn joinable_only(&self) -> bool {
+        self.joinable.unwrap_or(false)
+    }
+
+    // normalized and without duplicates, tags_match=All counts the distinct names
+    pub fn tag_names(&self) -> Vec<String> {
+        let mut tag_names: Vec<String> = Vec::new();
+
+        for tag in self
+            .tags
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(normalize_quest_tag)
+        {
+            if !tag.is_empty() && !tag_names.contains(&tag) {
+                tag_names.push(tag);
+            }
+        }
+
+        tag_names
+    }
 }
@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+use super::domain_events::DomainEvent;
+
+// how many events a slow board stream may fall behind before it skips some
+pub const BOARD_EVENTS_CAPACITY: usize = 1024;
+
+// a change pushed to a board stream, on_board tells whether the quest still matches its filter
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BoardEventModel {
+    #[serde(flatten)]
+    pub domain_event: DomainEvent,
+    pub on_board: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoardStreamMessage {
+    Event(BoardEventModel),
+    // the stream skipped this many events, the board has to be checked again
+    Lagged(u64),
+}
@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::crew_roles::CrewRoles;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrewMemberModel {
+    pub adventurer_id: i32,
+    pub username: String,
+    pub joined_at: NaiveDateTime,
+    pub role: Option<String>,
+    pub reward_weight: i32,
+    pub experience: i64,
+    pub level: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignCrewRoleModel {
+    // none clears the role of the crew member
+    pub role: Option<CrewRoles>,
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+// known party roles, anything else is a free-text role the commander defines for the quest
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "String", into = "String")]
+pub enum CrewRoles {
+    Leader,
+    Healer,
+    Scout,
+    Custom(String),
+}
+
+impl From<String> for CrewRoles {
+    fn from(role: String) -> Self {
+        match role.trim() {
+            "Leader" => CrewRoles::Leader,
+            "Healer" => CrewRoles::Healer,
+            "Scout" => CrewRoles::Scout,
+            custom => CrewRoles::Custom(custom.to_string()),
+        }
+    }
+}
+
+impl From<CrewRoles> for String {
+    fn from(role: CrewRoles) -> Self {
+        role.to_string()
+    }
+}
+
+impl fmt::Display for CrewRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrewRoles::Leader => write!(f, "Leader"),
+            CrewRoles::Healer => write!(f, "Healer"),
+            CrewRoles::Scout => write!(f, "Scout"),
+            CrewRoles::Custom(role) => write!(f, "{}", role),
+        }
+    }
+}
+
+pub const MAX_CREW_ROLE_LENGTH: usize = 255;
@@ -0,0 +1,160 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::quest_statuses::QuestStatuses;
+
+// what happened to a quest or its crew, every event names the quest and the guild it belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum DomainEvent {
+    QuestCreated {
+        quest_id: i32,
+        guild_id: i32,
+        guild_commander_id: i32,
+    },
+    QuestEdited {
+        quest_id: i32,
+        guild_id: i32,
+        guild_commander_id: i32,
+    },
+    QuestRemoved {
+        quest_id: i32,
+        guild_id: i32,
+        guild_commander_id: i32,
+    },
+    QuestRestored {
+        quest_id: i32,
+        guild_id: i32,
+        guild_commander_id: i32,
+    },
+    AdventurerJoined {
+        quest_id: i32,
+        guild_id: i32,
+        adventurer_id: i32,
+    },
+    AdventurerLeft {
+        quest_id: i32,
+        guild_id: i32,
+        adventurer_id: i32,
+    },
+    QuestStarted {
+        quest_id: i32,
+        guild_id: i32,
+        guild_commander_id: i32,
+    },
+    QuestCompleted {
+        quest_id: i32,
+        guild_id: i32,
+        guild_commander_id: i32,
+    },
+    QuestFailed {
+        quest_id: i32,
+        guild_id: i32,
+        guild_commander_id: i32,
+    },
+}
+
+impl DomainEvent {
+    // the event a quest moving into the given status stands for, reopening has none
+    pub fn status_changed(
+        status: &str,
+        quest_id: i32,
+        guild_id: i32,
+        guild_commander_id: i32,
+    ) -> Option<Self> {
+        if status == QuestStatuses::InJourney.to_string() {
+            Some(DomainEvent::QuestStarted {
+                quest_id,
+                guild_id,
+                guild_commander_id,
+            })
+        } else if status == QuestStatuses::Completed.to_string() {
+            Some(DomainEvent::QuestCompleted {
+                quest_id,
+                guild_id,
+                guild_commander_id,
+            })
+        } else if status == QuestStatuses::Failed.to_string() {
+            Some(DomainEvent::QuestFailed {
+                quest_id,
+                guild_id,
+                guild_commander_id,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn event_type(&self) -> DomainEventTypes {
+        match self {
+            DomainEvent::QuestCreated { .. } => DomainEventTypes::QuestCreated,
+            DomainEvent::QuestEdited { .. } => DomainEventTypes::QuestEdited,
+            DomainEvent::QuestRemoved { .. } => DomainEventTypes::QuestRemoved,
+            DomainEvent::QuestRestored { .. } => DomainEventTypes::QuestRestored,
+            DomainEvent::AdventurerJoined { .. } => DomainEventTypes::AdventurerJoined,
+            DomainEvent::AdventurerLeft { .. } => DomainEventTypes::AdventurerLeft,
+            DomainEvent::QuestStarted { .. } => DomainEventTypes::QuestStarted,
+            DomainEvent::QuestCompleted { .. } => DomainEventTypes::QuestCompleted,
+            DomainEvent::QuestFailed { .. } => DomainEventTypes::QuestFailed,
+        }
+    }
+
+    pub fn quest_id(&self) -> i32 {
+        match self {
+            DomainEvent::QuestCreated { quest_id, .. }
+            | DomainEvent::QuestEdited { quest_id, .. }
+            | DomainEvent::QuestRemoved { quest_id, .. }
+            | DomainEvent::QuestRestored { quest_id, .. }
+            | DomainEvent::AdventurerJoined { quest_id, .. }
+            | DomainEvent::AdventurerLeft { quest_id, .. }
+            | DomainEvent::QuestStarted { quest_id, .. }
+            | DomainEvent::QuestCompleted { quest_id, .. }
+            | DomainEvent::QuestFailed { quest_id, .. } => *quest_id,
+        }
+    }
+
+    pub fn guild_id(&self) -> i32 {
+        match self {
+            DomainEvent::QuestCreated { guild_id, .. }
+            | DomainEvent::QuestEdited { guild_id, .. }
+            | DomainEvent::QuestRemoved { guild_id, .. }
+            | DomainEvent::QuestRestored { guild_id, .. }
+            | DomainEvent::AdventurerJoined { guild_id, .. }
+            | DomainEvent::AdventurerLeft { guild_id, .. }
+            | DomainEvent::QuestStarted { guild_id, .. }
+            | DomainEvent::QuestCompleted { guild_id, .. }
+            | DomainEvent::QuestFailed { guild_id, .. } => *guild_id,
+        }
+    }
+}
+
+// the event names without their payload, for subscribers that filter by kind
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum DomainEventTypes {
+    QuestCreated,
+    QuestEdited,
+    QuestRemoved,
+    QuestRestored,
+    AdventurerJoined,
+    AdventurerLeft,
+    QuestStarted,
+    QuestCompleted,
+    QuestFailed,
+}
+
+impl fmt::Display for DomainEventTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainEventTypes::QuestCreated => write!(f, "QuestCreated"),
+            DomainEventTypes::QuestEdited => write!(f, "QuestEdited"),
+            DomainEventTypes::QuestRemoved => write!(f, "QuestRemoved"),
+            DomainEventTypes::QuestRestored => write!(f, "QuestRestored"),
+            DomainEventTypes::AdventurerJoined => write!(f, "AdventurerJoined"),
+            DomainEventTypes::AdventurerLeft => write!(f, "AdventurerLeft"),
+            DomainEventTypes::QuestStarted => write!(f, "QuestStarted"),
+            DomainEventTypes::QuestCompleted => write!(f, "QuestCompleted"),
+            DomainEventTypes::QuestFailed => write!(f, "QuestFailed"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use rand::{distributions::Alphanumeric, Rng};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::guild_invites::AddGuildInviteEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildInviteModel {
+    pub id: i32,
+    pub guild_id: i32,
+    pub guild_commander_id: i32,
+    pub code: String,
+    // the accept endpoint an adventurer can be sent to directly
+    pub link: String,
+    pub max_uses: Option<i32>,
+    pub uses: i32,
+    pub expires_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddGuildInviteModel {
+    pub max_uses: Option<i32>,
+    pub expires_at: Option<NaiveDateTime>,
+}
+
+impl AddGuildInviteModel {
+    pub fn to_entity(&self, guild_id: i32, guild_commander_id: i32) -> AddGuildInviteEntity {
+        AddGuildInviteEntity {
+            guild_id,
+            guild_commander_id,
+            code: generate_guild_invite_code(),
+            max_uses: self.max_uses,
+            expires_at: self.expires_at,
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+pub const GUILD_INVITE_CODE_LENGTH: usize = 12;
+
+pub fn generate_guild_invite_code() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(GUILD_INVITE_CODE_LENGTH)
+        .map(char::from)
+        .collect()
+}
@@ -0,0 +1,112 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::guild_memberships::AddGuildMembershipEntity;
+
+use super::guild_membership_roles::GuildMembershipRoles;
+
+// adventurer and guild commander ids overlap, so a member is always named with its kind
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GuildMember {
+    Adventurer(i32),
+    GuildCommander(i32),
+}
+
+// a row named in the path of a guild route, it belongs to exactly one guild
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GuildResource {
+    Guild(i32),
+    Quest(i32),
+    QuestComment(i32),
+    QuestMilestone(i32),
+    QuestInvitation(i32),
+    QuestApplication(i32),
+    GuildMembership(i32),
+    GuildInvite(i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GuildAccess {
+    Member,
+    NotMember,
+    NotFound,
+}
+
+impl GuildResource {
+    // None for parameters that are not scoped to a guild, an unknown name is an error so a
+    // new route cannot get past the guild check without being listed here
+    pub fn from_path_param(name: &str, id: i32) -> Result<Option<Self>> {
+        match name {
+            "guild_id" => Ok(Some(GuildResource::Guild(id))),
+            "quest_id" | "prerequisite_id" => Ok(Some(GuildResource::Quest(id))),
+            "comment_id" => Ok(Some(GuildResource::QuestComment(id))),
+            "milestone_id" => Ok(Some(GuildResource::QuestMilestone(id))),
+            "invitation_id" => Ok(Some(GuildResource::QuestInvitation(id))),
+            "application_id" => Ok(Some(GuildResource::QuestApplication(id))),
+            "membership_id" => Ok(Some(GuildResource::GuildMembership(id))),
+            "invite_id" => Ok(Some(GuildResource::GuildInvite(id))),
+            "adventurer_id" => Ok(None),
+            _ => Err(anyhow::anyhow!(
+                "The route parameter {} has no guild to check",
+                name
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddGuildMemberModel {
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
+    pub role: Option<GuildMembershipRoles>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberModel {
+    pub membership_id: i32,
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
+    pub username: String,
+    pub role: String,
+    pub joined_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditGuildMemberModel {
+    pub role: GuildMembershipRoles,
+}
+
+impl AddGuildMemberModel {
+    pub fn member(&self) -> Option<GuildMember> {
+        match (self.adventurer_id, self.guild_commander_id) {
+            (Some(adventurer_id), None) => Some(GuildMember::Adventurer(adventurer_id)),
+            (None, Some(guild_commander_id)) => {
+                Some(GuildMember::GuildCommander(guild_commander_id))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl GuildMember {
+    pub fn to_membership_entity(
+        &self,
+        guild_id: i32,
+        role: &GuildMembershipRoles,
+    ) -> AddGuildMembershipEntity {
+        let (adventurer_id, guild_commander_id) = match self {
+            GuildMember::Adventurer(adventurer_id) => (Some(*adventurer_id), None),
+            GuildMember::GuildCommander(guild_commander_id) => (None, Some(*guild_commander_id)),
+        };
+
+        AddGuildMembershipEntity {
+            guild_id,
+            adventurer_id,
+            guild_commander_id,
+            role: role.to_string(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+// Owners and Officers manage the guild, adventurers are always Members
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum GuildMembershipRoles {
+    Owner,
+    Officer,
+    #[default]
+    Member,
+}
+
+impl fmt::Display for GuildMembershipRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuildMembershipRoles::Owner => write!(f, "Owner"),
+            GuildMembershipRoles::Officer => write!(f, "Officer"),
+            GuildMembershipRoles::Member => write!(f, "Member"),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::guilds::AddGuildEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildModel {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    // the membership role of the caller, None when listed without a membership
+    pub role: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddGuildModel {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl AddGuildModel {
+    pub fn to_entity(&self) -> AddGuildEntity {
+        AddGuildEntity {
+            name: self.name.trim().to_string(),
+            description: self.description.clone(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+pub const MAX_GUILD_NAME_LENGTH: usize = 255;
@@ -1,6 +1,40 @@
+pub mod admin_audit_actions;
+pub mod admin_model;
 pub mod adventurer_model;
 pub mod board_checking_filter;
-pub mod guild_commander_model;
+pub mod board_event_model;
+pub mod crew_member_model;
+pub mod crew_roles;
+pub mod domain_events;
+pub mod guild_invite_model;
+pub mod guild_membership_model;
+pub mod guild_membership_roles;
+pub mod guild_model;
+pub mod notification_model;
+pub mod outbox_model;
 pub mod quest_adventurer_junction;
+pub mod quest_application_model;
+pub mod quest_application_statuses;
+pub mod quest_attempt_model;
+pub mod quest_attempt_outcomes;
+pub mod quest_comment_author_roles;
+pub mod quest_comment_model;
+pub mod quest_invitation_model;
+pub mod quest_invitation_statuses;
+pub mod quest_join_outcomes;
+pub mod quest_join_policies;
+pub mod quest_milestone_model;
 pub mod quest_model;
+pub mod quest_ranks;
+pub mod quest_rewards;
 pub mod quest_statuses;
+pub mod quest_tags;
+pub mod quest_template_model;
+pub mod quest_template_recurrences;
+pub mod quest_waitlist_model;
+pub mod retry_backoff;
+pub mod user_model;
+pub mod wallet_model;
+pub mod wallet_transaction_kinds;
+pub mod webhook_delivery_statuses;
+pub mod webhook_model;
@@ -0,0 +1,86 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::domain_events::{DomainEvent, DomainEventTypes};
+
+pub const DEFAULT_NOTIFICATIONS_PAGE_SIZE: i64 = 20;
+pub const MAX_NOTIFICATIONS_PAGE_SIZE: i64 = 100;
+
+// the events that land in an inbox, every one of them is on until the user turns it off
+pub const NOTIFIABLE_EVENT_TYPES: [DomainEventTypes; 4] = [
+    DomainEventTypes::QuestStarted,
+    DomainEventTypes::QuestCompleted,
+    DomainEventTypes::QuestFailed,
+    DomainEventTypes::AdventurerJoined,
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotificationModel {
+    pub id: i32,
+    pub event_type: String,
+    pub quest_id: i32,
+    pub guild_id: i32,
+    pub message: String,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotificationsPageModel {
+    pub notifications: Vec<NotificationModel>,
+    pub unread_count: i64,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotificationsFilter {
+    pub unread: Option<bool>,
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+impl NotificationsFilter {
+    pub fn unread_only(&self) -> bool {
+        self.unread.unwrap_or(false)
+    }
+
+    // newest first, pages start at 1 and the page size is capped like the comment threads
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    pub fn page_size(&self) -> i64 {
+        self.page_size
+            .unwrap_or(DEFAULT_NOTIFICATIONS_PAGE_SIZE)
+            .clamp(1, MAX_NOTIFICATIONS_PAGE_SIZE)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotificationPreferenceModel {
+    pub event_type: DomainEventTypes,
+    pub enabled: bool,
+}
+
+// what the inbox says about the event, quest_name and adventurer_name are looked up when it lands
+pub fn notification_message(
+    domain_event: &DomainEvent,
+    quest_name: &str,
+    adventurer_name: Option<&str>,
+) -> String {
+    match domain_event {
+        DomainEvent::QuestStarted { .. } => format!("The quest \"{}\" has started", quest_name),
+        DomainEvent::QuestCompleted { .. } => {
+            format!("The quest \"{}\" has been completed", quest_name)
+        }
+        DomainEvent::QuestFailed { .. } => format!("The quest \"{}\" has failed", quest_name),
+        DomainEvent::AdventurerJoined { .. } => format!(
+            "{} joined the quest \"{}\"",
+            adventurer_name.unwrap_or("An adventurer"),
+            quest_name
+        ),
+        _ => format!("The quest \"{}\" has changed", quest_name),
+    }
+}
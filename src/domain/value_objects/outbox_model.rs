@@ -0,0 +1,29 @@
+use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use super::retry_backoff::exponential_backoff;
+
+pub const OUTBOX_BATCH_SIZE: i64 = 50;
+pub const MAX_OUTBOX_ATTEMPTS: i32 = 10;
+// a claimed row is hidden from other dispatchers for this long, in case this one dies mid-batch
+pub const OUTBOX_LEASE_SECONDS: i64 = 60;
+const OUTBOX_BASE_BACKOFF_SECONDS: i64 = 2;
+const OUTBOX_MAX_BACKOFF_SECONDS: i64 = 600;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutboxBacklogModel {
+    pub pending: i64,
+    pub retrying: i64,
+    pub failed: i64,
+    pub oldest_pending_at: Option<NaiveDateTime>,
+    pub oldest_pending_age_seconds: Option<i64>,
+}
+
+// 2s, 4s, 8s ... up to ten minutes
+pub fn outbox_retry_delay(attempts: i32) -> Duration {
+    exponential_backoff(
+        attempts,
+        OUTBOX_BASE_BACKOFF_SECONDS,
+        OUTBOX_MAX_BACKOFF_SECONDS,
+    )
+}
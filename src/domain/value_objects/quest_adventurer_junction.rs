@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -5,13 +6,42 @@ use crate::domain::entities::adventurers::AdventurerEntity;
 use crate::domain::entities::quests::QuestEntity;
 use crate::infrastructure::postgres::schema::quest_adventurer_junction;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Insertable, Queryable, Associations)]
+use super::crew_member_model::CrewMemberModel;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Insertable, Queryable, Selectable, Associations)]
 #[diesel(belongs_to(AdventurerEntity, foreign_key = adventurer_id))]
 #[diesel(belongs_to(QuestEntity, foreign_key = quest_id))]
 #[diesel(table_name=quest_adventurer_junction)]
 pub struct QuestAdventurerJunction {
     pub quest_id: i32,
     pub adventurer_id: i32,
+    pub joined_at: NaiveDateTime,
+    pub role: Option<String>,
+    pub reward_weight: i32,
+}
+
+impl QuestAdventurerJunction {
+    pub fn new(quest_id: i32, adventurer_id: i32) -> Self {
+        Self {
+            quest_id,
+            adventurer_id,
+            joined_at: chrono::Utc::now().naive_utc(),
+            role: None,
+            reward_weight: 1,
+        }
+    }
+
+    pub fn to_crew_member_model(&self, adventurer: &AdventurerEntity) -> CrewMemberModel {
+        CrewMemberModel {
+            adventurer_id: self.adventurer_id,
+            username: adventurer.username.clone(),
+            joined_at: self.joined_at,
+            role: self.role.clone(),
+            reward_weight: self.reward_weight,
+            experience: adventurer.experience,
+            level: adventurer.level,
+        }
+    }
 }
 
 pub const MAX_ADVENTURERS_PER_QUEST: i64 = 4;
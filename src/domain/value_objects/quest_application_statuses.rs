@@ -0,0 +1,23 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestApplicationStatuses {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+    Withdrawn,
+}
+
+impl fmt::Display for QuestApplicationStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestApplicationStatuses::Pending => write!(f, "Pending"),
+            QuestApplicationStatuses::Approved => write!(f, "Approved"),
+            QuestApplicationStatuses::Rejected => write!(f, "Rejected"),
+            QuestApplicationStatuses::Withdrawn => write!(f, "Withdrawn"),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestAttemptModel {
+    pub id: i32,
+    pub attempt_number: i32,
+    // None while the attempt is still running
+    pub outcome: Option<String>,
+    pub started_at: NaiveDateTime,
+    pub ended_at: Option<NaiveDateTime>,
+    // adventurer ids of the crew when the attempt started
+    pub crew: Vec<i32>,
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestAttemptOutcomes {
+    #[default]
+    Completed,
+    Failed,
+}
+
+impl fmt::Display for QuestAttemptOutcomes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestAttemptOutcomes::Completed => write!(f, "Completed"),
+            QuestAttemptOutcomes::Failed => write!(f, "Failed"),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestCommentAuthorRoles {
+    #[default]
+    Adventurer,
+    GuildCommander,
+}
+
+impl fmt::Display for QuestCommentAuthorRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestCommentAuthorRoles::Adventurer => write!(f, "Adventurer"),
+            QuestCommentAuthorRoles::GuildCommander => write!(f, "GuildCommander"),
+        }
+    }
+}
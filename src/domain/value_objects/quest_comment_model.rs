@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_QUEST_COMMENT_LENGTH: usize = 2000;
+pub const DEFAULT_QUEST_COMMENTS_PAGE_SIZE: i64 = 20;
+pub const MAX_QUEST_COMMENTS_PAGE_SIZE: i64 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestCommentModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub author_id: i32,
+    pub author_role: String,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestCommentsPageModel {
+    pub comments: Vec<QuestCommentModel>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddQuestCommentModel {
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditQuestCommentModel {
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QuestCommentsPagination {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+impl QuestCommentsPagination {
+    // pages start at 1, the page size is capped so one request cannot load a whole thread
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    pub fn page_size(&self) -> i64 {
+        self.page_size
+            .unwrap_or(DEFAULT_QUEST_COMMENTS_PAGE_SIZE)
+            .clamp(1, MAX_QUEST_COMMENTS_PAGE_SIZE)
+    }
+}
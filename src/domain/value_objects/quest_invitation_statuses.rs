@@ -0,0 +1,21 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestInvitationStatuses {
+    #[default]
+    Pending,
+    Accepted,
+    Declined,
+}
+
+impl fmt::Display for QuestInvitationStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestInvitationStatuses::Pending => write!(f, "Pending"),
+            QuestInvitationStatuses::Accepted => write!(f, "Accepted"),
+            QuestInvitationStatuses::Declined => write!(f, "Declined"),
+        }
+    }
+}
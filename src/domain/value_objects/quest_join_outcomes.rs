@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+// join does not always take a crew seat right away, the caller needs to know what happened
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestJoinOutcomes {
+    Joined,
+    Applied { application_id: i32 },
+    Waitlisted { position: i64 },
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestJoinPolicies {
+    #[default]
+    Open,
+    ApprovalRequired,
+}
+
+impl fmt::Display for QuestJoinPolicies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestJoinPolicies::Open => write!(f, "Open"),
+            QuestJoinPolicies::ApprovalRequired => write!(f, "ApprovalRequired"),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::quest_milestones::{
+    AddQuestMilestoneEntity, EditQuestMilestoneEntity, QuestMilestoneEntity,
+};
+
+pub const MAX_MILESTONES_PER_QUEST: usize = 50;
+pub const MAX_QUEST_MILESTONE_TITLE_LENGTH: usize = 255;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestMilestoneModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub required: bool,
+    pub completed_by: Option<i32>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddQuestMilestoneModel {
+    pub title: String,
+    pub required: Option<bool>,
+}
+
+impl AddQuestMilestoneModel {
+    pub fn to_entity(&self, quest_id: i32, position: i32) -> AddQuestMilestoneEntity {
+        AddQuestMilestoneEntity {
+            quest_id,
+            position,
+            title: self.title.trim().to_string(),
+            required: self.required.unwrap_or(true),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditQuestMilestoneModel {
+    pub title: Option<String>,
+    pub required: Option<bool>,
+    // moves the milestone, the others shift to keep the checklist in order
+    pub position: Option<i32>,
+}
+
+impl EditQuestMilestoneModel {
+    pub fn to_entity(&self) -> EditQuestMilestoneEntity {
+        EditQuestMilestoneEntity {
+            title: self.title.as_ref().map(|title| title.trim().to_string()),
+            required: self.required,
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+// None when the quest has no checklist, otherwise the share of done milestones rounded down
+pub fn completion_percentage(milestones: &[QuestMilestoneEntity]) -> Option<i32> {
+    if milestones.is_empty() {
+        return None;
+    }
+
+    let done = milestones
+        .iter()
+        .filter(|milestone| milestone.completed_at.is_some())
+        .count();
+
+    Some((done * 100 / milestones.len()) as i32)
+}
+
+pub fn all_required_milestones_done(milestones: &[QuestMilestoneEntity]) -> bool {
+    milestones
+        .iter()
+        .filter(|milestone| milestone.required)
+        .all(|milestone| milestone.completed_at.is_some())
+}
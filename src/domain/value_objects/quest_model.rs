@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::domain::entities::quests::{AddQuestEntity, EditQuestEntity};
 
-use super::quest_statuses::QuestStatuses;
+use super::{
+    quest_adventurer_junction::MAX_ADVENTURERS_PER_QUEST, quest_attempt_model::QuestAttemptModel,
+    quest_join_policies::QuestJoinPolicies, quest_ranks::QuestRanks, quest_statuses::QuestStatuses,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuestModel {
@@ -12,15 +15,48 @@ pub struct QuestModel {
     pub description: Option<String>,
     pub status: String,
     pub guild_commander_id: i32,
+    pub guild_id: i32,
     pub adventurers_count: i64,
+    pub join_policy: String,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward: i64,
+    pub experience: i64,
+    pub experience_penalty: i64,
+    pub min_level: Option<i32>,
+    pub rank: String,
+    pub max_attempts: Option<i32>,
+    pub capacity: i32,
+    pub tags: Vec<String>,
+    // share of done milestones, None when the quest has no checklist
+    pub completion_percentage: Option<i32>,
+    pub prerequisite_ids: Vec<i32>,
+    // nobody can join until every prerequisite is Completed
+    pub locked: bool,
+    // every in_journey run of the quest, only set on the quest details
+    pub attempts: Option<Vec<QuestAttemptModel>>,
+    // matched words wrapped in <mark>, only set when the board is searched with q
+    pub headline: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddQuestModel {
+    pub guild_id: i32,
     pub name: String,
     pub description: Option<String>,
+    pub join_policy: Option<QuestJoinPolicies>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward: Option<i64>,
+    pub experience: Option<i64>,
+    pub experience_penalty: Option<i64>,
+    pub min_level: Option<i32>,
+    pub rank: Option<QuestRanks>,
+    pub max_attempts: Option<i32>,
+    pub capacity: Option<i32>,
+    pub tags: Option<Vec<String>>,
 }
 
 impl AddQuestModel {
@@ -30,6 +66,17 @@ impl AddQuestModel {
             description: self.description.clone(),
             status: QuestStatuses::Open.to_string(),
             guild_commander_id,
+            join_policy: self.join_policy.clone().unwrap_or_default().to_string(),
+            starts_at: self.starts_at,
+            due_at: self.due_at,
+            reward: self.reward.unwrap_or_default(),
+            experience: self.experience.unwrap_or_default(),
+            experience_penalty: self.experience_penalty.unwrap_or_default(),
+            min_level: self.min_level,
+            rank: self.rank.clone().unwrap_or_default().to_string(),
+            max_attempts: self.max_attempts,
+            capacity: self.capacity.unwrap_or(MAX_ADVENTURERS_PER_QUEST as i32),
+            guild_id: self.guild_id,
             created_at: chrono::Utc::now().naive_utc(),
             updated_at: chrono::Utc::now().naive_utc(),
         }
@@ -40,6 +87,17 @@ impl AddQuestModel {
 pub struct EditQuestModel {
     pub name: Option<String>,
     pub description: Option<String>,
+    pub join_policy: Option<QuestJoinPolicies>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward: Option<i64>,
+    pub experience: Option<i64>,
+    pub experience_penalty: Option<i64>,
+    pub min_level: Option<i32>,
+    pub rank: Option<QuestRanks>,
+    pub max_attempts: Option<i32>,
+    pub capacity: Option<i32>,
+    pub tags: Option<Vec<String>>,
 }
 
 impl EditQuestModel {
@@ -48,6 +106,19 @@ impl EditQuestModel {
             name: self.name.clone(),
             description: self.description.clone(),
             guild_commander_id,
+            join_policy: self
+                .join_policy
+                .as_ref()
+                .map(|join_policy| join_policy.to_string()),
+            starts_at: self.starts_at,
+            due_at: self.due_at,
+            reward: self.reward,
+            experience: self.experience,
+            experience_penalty: self.experience_penalty,
+            min_level: self.min_level,
+            rank: self.rank.as_ref().map(|rank| rank.to_string()),
+            max_attempts: self.max_attempts,
+            capacity: self.capacity,
             updated_at: chrono::Utc::now().naive_utc(),
         }
     }
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestRanks {
+    S,
+    A,
+    B,
+    C,
+    D,
+    #[default]
+    E,
+}
+
+impl QuestRanks {
+    // ordered from the easiest rank to the hardest
+    const ALL: [QuestRanks; 6] = [
+        QuestRanks::E,
+        QuestRanks::D,
+        QuestRanks::C,
+        QuestRanks::B,
+        QuestRanks::A,
+        QuestRanks::S,
+    ];
+
+    fn difficulty(&self) -> usize {
+        QuestRanks::ALL
+            .iter()
+            .position(|rank| rank == self)
+            .unwrap_or_default()
+    }
+
+    // every rank from min to max inclusive, an open side reaches to the easiest or hardest rank
+    pub fn between(min: Option<&QuestRanks>, max: Option<&QuestRanks>) -> Vec<QuestRanks> {
+        let min = min.map(|rank| rank.difficulty()).unwrap_or(0);
+        let max = max
+            .map(|rank| rank.difficulty())
+            .unwrap_or(QuestRanks::ALL.len() - 1);
+
+        QuestRanks::ALL
+            .iter()
+            .filter(|rank| (min..=max).contains(&rank.difficulty()))
+            .cloned()
+            .collect()
+    }
+}
+
+impl fmt::Display for QuestRanks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestRanks::S => write!(f, "S"),
+            QuestRanks::A => write!(f, "A"),
+            QuestRanks::B => write!(f, "B"),
+            QuestRanks::C => write!(f, "C"),
+            QuestRanks::D => write!(f, "D"),
+            QuestRanks::E => write!(f, "E"),
+        }
+    }
+}
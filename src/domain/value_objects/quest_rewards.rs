@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetRewardWeightModel {
+    pub reward_weight: i32,
+}
+
+// splits the reward by weight, the remainder goes one by one to the earliest crew members
+pub fn split_reward(reward: i64, weights: &[(i32, i32)]) -> Vec<(i32, i64)> {
+    let total_weight: i64 = weights.iter().map(|(_, weight)| i64::from(*weight)).sum();
+
+    if reward <= 0 || total_weight <= 0 {
+        return Vec::new();
+    }
+
+    let mut shares: Vec<(i32, i64)> = weights
+        .iter()
+        .map(|(adventurer_id, weight)| {
+            let share = i128::from(reward) * i128::from(*weight) / i128::from(total_weight);
+            (*adventurer_id, share as i64)
+        })
+        .collect();
+
+    let remainder = reward - shares.iter().map(|(_, share)| share).sum::<i64>();
+
+    for (_, share) in shares.iter_mut().take(remainder as usize) {
+        *share += 1;
+    }
+
+    shares.retain(|(_, share)| *share > 0);
+
+    shares
+}
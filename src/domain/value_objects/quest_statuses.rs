@@ -1,7 +1,10 @@
 use std::fmt;
 
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
+use crate::domain::entities::quests::QuestEntity;
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum QuestStatuses {
     #[default]
@@ -11,6 +14,29 @@ pub enum QuestStatuses {
     Failed,
 }
 
+// adventurers can only join or apply while the quest is in one of these statuses
+pub const JOINABLE_QUEST_STATUSES: [QuestStatuses; 2] =
+    [QuestStatuses::Open, QuestStatuses::Failed];
+
+// a quest with prerequisites stays locked until every one of them is Completed
+pub fn prerequisites_completed(prerequisites: &[QuestEntity]) -> bool {
+    prerequisites
+        .iter()
+        .all(|prerequisite| prerequisite.status == QuestStatuses::Completed.to_string())
+}
+
+impl QuestStatuses {
+    pub fn try_from_str(status: &str) -> Result<Self> {
+        match status {
+            "Open" => Ok(QuestStatuses::Open),
+            "InJourney" => Ok(QuestStatuses::InJourney),
+            "Completed" => Ok(QuestStatuses::Completed),
+            "Failed" => Ok(QuestStatuses::Failed),
+            _ => Err(anyhow::anyhow!("Invalid quest status: {}", status)),
+        }
+    }
+}
+
 impl fmt::Display for QuestStatuses {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
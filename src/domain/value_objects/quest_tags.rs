@@ -0,0 +1,27 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+pub const MAX_QUEST_TAG_LENGTH: usize = 64;
+pub const MAX_TAGS_PER_QUEST: usize = 10;
+
+// tags are matched case-insensitively, so they are stored trimmed and lowercased
+pub fn normalize_quest_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestTagsMatches {
+    #[default]
+    Any,
+    All,
+}
+
+impl fmt::Display for QuestTagsMatches {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestTagsMatches::Any => write!(f, "Any"),
+            QuestTagsMatches::All => write!(f, "All"),
+        }
+    }
+}
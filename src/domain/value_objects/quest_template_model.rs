@@ -0,0 +1,70 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::quest_templates::AddQuestTemplateEntity;
+
+use super::{
+    quest_adventurer_junction::MAX_ADVENTURERS_PER_QUEST,
+    quest_template_recurrences::QuestTemplateRecurrences,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestTemplateModel {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub guild_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub capacity: i32,
+    pub reward: i64,
+    pub tags: Vec<String>,
+    pub recurrence: String,
+    pub cron_expression: Option<String>,
+    pub paused: bool,
+    pub next_run_at: NaiveDateTime,
+    pub last_run_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddQuestTemplateModel {
+    pub guild_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub capacity: Option<i32>,
+    pub reward: Option<i64>,
+    pub tags: Option<Vec<String>>,
+    pub recurrence: QuestTemplateRecurrences,
+    pub cron_expression: Option<String>,
+    // defaults to now for Daily and Weekly, Cron templates follow their expression
+    pub first_run_at: Option<NaiveDateTime>,
+}
+
+impl AddQuestTemplateModel {
+    pub fn to_entity(
+        &self,
+        guild_commander_id: i32,
+        tags: Vec<String>,
+        next_run_at: NaiveDateTime,
+    ) -> AddQuestTemplateEntity {
+        AddQuestTemplateEntity {
+            guild_commander_id,
+            guild_id: self.guild_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            capacity: self.capacity.unwrap_or(MAX_ADVENTURERS_PER_QUEST as i32),
+            reward: self.reward.unwrap_or_default(),
+            tags,
+            recurrence: self.recurrence.to_string(),
+            cron_expression: self
+                .cron_expression
+                .as_ref()
+                .map(|cron_expression| cron_expression.trim().to_string()),
+            paused: false,
+            next_run_at,
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+use cron::Schedule;
+use serde::{Deserialize, Serialize};
+
+// a claimed template is hidden from other schedulers for this long, in case this one dies mid-run
+pub const QUEST_TEMPLATE_LEASE_SECONDS: i64 = 60;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum QuestTemplateRecurrences {
+    #[default]
+    Daily,
+    Weekly,
+    // 6 or 7 fields with seconds first, e.g. "0 0 9 * * Mon" for every Monday at 09:00 UTC
+    Cron,
+}
+
+impl fmt::Display for QuestTemplateRecurrences {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestTemplateRecurrences::Daily => write!(f, "Daily"),
+            QuestTemplateRecurrences::Weekly => write!(f, "Weekly"),
+            QuestTemplateRecurrences::Cron => write!(f, "Cron"),
+        }
+    }
+}
+
+pub fn parse_cron_expression(cron_expression: &str) -> Result<Schedule> {
+    Schedule::from_str(cron_expression.trim())
+        .map_err(|e| anyhow::anyhow!("Invalid cron expression: {}", e))
+}
+
+// the first run strictly after now, runs missed while the scheduler was down are skipped
+pub fn next_template_run_at(
+    recurrence: &str,
+    cron_expression: Option<&str>,
+    previous_run_at: NaiveDateTime,
+    now: NaiveDateTime,
+) -> Result<NaiveDateTime> {
+    let period = if recurrence == QuestTemplateRecurrences::Daily.to_string() {
+        Duration::days(1)
+    } else if recurrence == QuestTemplateRecurrences::Weekly.to_string() {
+        Duration::weeks(1)
+    } else if recurrence == QuestTemplateRecurrences::Cron.to_string() {
+        let schedule = parse_cron_expression(cron_expression.unwrap_or_default())?;
+
+        return schedule
+            .after(&Utc.from_utc_datetime(&now))
+            .next()
+            .map(|next_run_at| next_run_at.naive_utc())
+            .ok_or_else(|| anyhow::anyhow!("The cron expression has no upcoming run"));
+    } else {
+        return Err(anyhow::anyhow!("Unknown recurrence: {}", recurrence));
+    };
+
+    if previous_run_at > now {
+        return Ok(previous_run_at);
+    }
+
+    let missed_periods = (now - previous_run_at).num_seconds() / period.num_seconds();
+
+    Ok(previous_run_at + period * (missed_periods as i32 + 1))
+}
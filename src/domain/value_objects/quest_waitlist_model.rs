@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestWaitlistPositionModel {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub position: i64,
+}
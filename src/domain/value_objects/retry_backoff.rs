@@ -0,0 +1,8 @@
+use chrono::Duration;
+
+// doubles with every failed attempt starting from base_seconds, never waits longer than max_seconds
+pub fn exponential_backoff(attempts: i32, base_seconds: i64, max_seconds: i64) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+
+    Duration::seconds((base_seconds * 2_i64.pow(exponent)).min(max_seconds))
+}
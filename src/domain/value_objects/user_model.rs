@@ -0,0 +1,70 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::entities::users::RegisterUserEntity,
+    infrastructure::jwt_authentication::jwt_model::Roles,
+};
+
+use super::{
+    adventurer_model::AdventurerProfileModel, guild_membership_model::GuildMemberModel,
+    quest_adventurer_junction::QuestAdventurerJunction,
+    quest_application_model::QuestApplicationModel, quest_comment_model::QuestCommentModel,
+    quest_invitation_model::QuestInvitationModel, wallet_model::WalletTransactionModel,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterUserModel {
+    pub username: String,
+    pub password: String,
+    // an account without roles starts out as an adventurer
+    #[serde(default)]
+    pub roles: Vec<Roles>,
+}
+
+impl RegisterUserModel {
+    pub fn to_entity(&self) -> RegisterUserEntity {
+        RegisterUserEntity {
+            username: self.username.clone(),
+            password: self.password.clone(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserModel {
+    pub id: i32,
+    pub username: String,
+    pub roles: Vec<Roles>,
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddUserRoleModel {
+    pub role: Roles,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserDataExportModel {
+    pub user: UserModel,
+    pub adventurer: Option<AdventurerProfileModel>,
+    pub guild_memberships: Vec<GuildMemberModel>,
+    pub crew: Vec<QuestAdventurerJunction>,
+    pub quest_applications: Vec<QuestApplicationModel>,
+    pub quest_invitations: Vec<QuestInvitationModel>,
+    pub quest_comments: Vec<QuestCommentModel>,
+    pub wallet_transactions: Vec<WalletTransactionModel>,
+    pub commanded_quest_ids: Vec<i32>,
+    pub exported_at: NaiveDateTime,
+}
+
+// deleted accounts keep their row for the quest history, under a name nobody can register
+pub const DELETED_USERNAME_PREFIX: &str = "deleted-user-";
+
+pub fn anonymized_username(user_id: i32) -> String {
+    format!("{}{}", DELETED_USERNAME_PREFIX, user_id)
+}
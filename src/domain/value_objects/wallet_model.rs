@@ -0,0 +1,18 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletModel {
+    pub adventurer_id: i32,
+    pub balance: i64,
+    pub transactions: Vec<WalletTransactionModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletTransactionModel {
+    pub id: i32,
+    pub quest_id: Option<i32>,
+    pub amount: i64,
+    pub kind: String,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,17 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum WalletTransactionKinds {
+    #[default]
+    QuestReward,
+}
+
+impl fmt::Display for WalletTransactionKinds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WalletTransactionKinds::QuestReward => write!(f, "QuestReward"),
+        }
+    }
+}
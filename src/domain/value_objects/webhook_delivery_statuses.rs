@@ -0,0 +1,21 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum WebhookDeliveryStatuses {
+    #[default]
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl fmt::Display for WebhookDeliveryStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookDeliveryStatuses::Pending => write!(f, "Pending"),
+            WebhookDeliveryStatuses::Delivered => write!(f, "Delivered"),
+            WebhookDeliveryStatuses::Failed => write!(f, "Failed"),
+        }
+    }
+}
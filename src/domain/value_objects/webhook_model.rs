@@ -0,0 +1,170 @@
+use std::net::IpAddr;
+
+use chrono::{Duration, NaiveDateTime};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Builder;
+
+use crate::domain::entities::webhooks::AddWebhookEntity;
+
+use super::{
+    domain_events::{DomainEvent, DomainEventTypes},
+    retry_backoff::exponential_backoff,
+};
+
+pub const MAX_WEBHOOK_URL_LENGTH: usize = 2048;
+pub const MIN_WEBHOOK_SECRET_LENGTH: usize = 16;
+pub const MAX_WEBHOOK_SECRET_LENGTH: usize = 255;
+pub const MAX_WEBHOOKS_PER_GUILD: usize = 10;
+pub const WEBHOOK_BATCH_SIZE: i64 = 20;
+pub const MAX_WEBHOOK_ATTEMPTS: i32 = 8;
+pub const WEBHOOK_LEASE_SECONDS: i64 = 60;
+pub const WEBHOOK_TIMEOUT_SECONDS: u64 = 10;
+const WEBHOOK_BASE_BACKOFF_SECONDS: i64 = 10;
+const WEBHOOK_MAX_BACKOFF_SECONDS: i64 = 3600;
+
+// receivers verify the signature over "{timestamp}.{body}" with their secret and should
+// drop deliveries whose id they have already seen
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-quests-signature";
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "x-quests-timestamp";
+pub const WEBHOOK_DELIVERY_HEADER: &str = "x-quests-delivery";
+pub const WEBHOOK_EVENT_HEADER: &str = "x-quests-event";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookModel {
+    pub id: i32,
+    pub guild_id: i32,
+    pub guild_commander_id: i32,
+    pub url: String,
+    // empty means every event of the guild
+    pub event_types: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddWebhookModel {
+    pub guild_id: i32,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Option<Vec<DomainEventTypes>>,
+}
+
+impl AddWebhookModel {
+    pub fn to_entity(&self, guild_commander_id: i32) -> AddWebhookEntity {
+        let mut event_types: Vec<String> = self
+            .event_types
+            .clone()
+            .unwrap_or_default()
+            .iter()
+            .map(|event_type| event_type.to_string())
+            .collect();
+        event_types.sort();
+        event_types.dedup();
+
+        AddWebhookEntity {
+            guild_id: self.guild_id,
+            guild_commander_id,
+            url: self.url.trim().to_string(),
+            secret: self.secret.clone(),
+            event_types,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryModel {
+    pub id: i32,
+    pub delivery_id: String,
+    pub webhook_id: i32,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+// what goes over the wire for one attempt
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookRequestModel {
+    pub url: String,
+    pub delivery_id: String,
+    pub event_type: String,
+    pub timestamp: i64,
+    pub signature: String,
+    pub body: String,
+}
+
+pub fn webhook_matches(event_types: &[String], domain_event: &DomainEvent) -> bool {
+    event_types.is_empty() || event_types.contains(&domain_event.event_type().to_string())
+}
+
+// a webhook must not reach the server's own network, so only globally routable addresses pass
+pub fn is_public_webhook_address(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let octets = ip.octets();
+
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                // 100.64.0.0/10, carrier-grade NAT
+                || (octets[0] == 100 && (octets[1] & 0xc0) == 64)
+                // 0.0.0.0/8, "this network"
+                || octets[0] == 0)
+        }
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_public_webhook_address(&IpAddr::V4(ip));
+            }
+
+            let segments = ip.segments();
+
+            !(ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                // fc00::/7, unique local
+                || (segments[0] & 0xfe00) == 0xfc00
+                // fe80::/10, link-local
+                || (segments[0] & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+// the same outbox event always gives the same delivery id per webhook, so a retried event is
+// recognised instead of being queued twice
+pub fn webhook_delivery_id(event_id: i32, webhook_id: i32) -> String {
+    let mut bytes = [0u8; 16];
+    bytes[..4].copy_from_slice(&event_id.to_be_bytes());
+    bytes[12..].copy_from_slice(&webhook_id.to_be_bytes());
+
+    Builder::from_custom_bytes(bytes).into_uuid().to_string()
+}
+
+// hex encoded HMAC-SHA256, prefixed like "sha256=..." so the algorithm can change later
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+// 10s, 20s, 40s ... up to an hour
+pub fn webhook_retry_delay(attempts: i32) -> Duration {
+    exponential_backoff(
+        attempts,
+        WEBHOOK_BASE_BACKOFF_SECONDS,
+        WEBHOOK_MAX_BACKOFF_SECONDS,
+    )
+}
@@ -12,23 +12,38 @@ use tower_http::{
 use tracing::info;
 
 use crate::{
+    application::board_events::BoardEventsSender,
     config::config_model::DotEnvyConfig,
     infrastructure::{axum_http::routers, postgres::postgres_connection::PgPoolSquad},
 };
 
 use super::default_routers::{health_check, not_found};
 
-pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+pub async fn start(
+    config: Arc<DotEnvyConfig>,
+    db_pool: Arc<PgPoolSquad>,
+    board_events: BoardEventsSender,
+) -> Result<()> {
     let app = Router::new()
         .fallback(not_found)
         .route("/healthcheck", get(health_check))
         .nest(
             "/quest-ops",
-            routers::quest_ops::routes(Arc::clone(&&db_pool)),
+            routers::quest_ops::routes(Arc::clone(&db_pool)),
         )
         .nest(
             "/quest-viewing",
-            routers::quest_viewing::routes(Arc::clone(&db_pool)),
+            routers::quest_viewing::routes(Arc::clone(&db_pool))
+                .merge(routers::board_streaming::routes(
+                    Arc::clone(&db_pool),
+                    board_events,
+                ))
+                .merge(routers::quest_comments::routes(Arc::clone(&db_pool)))
+                .merge(routers::quest_milestones::routes(Arc::clone(&db_pool))),
+        )
+        .nest(
+            "/quest-templates",
+            routers::quest_templates::routes(Arc::clone(&db_pool)),
         )
         .nest(
             "/journey-ledger",
@@ -38,6 +53,7 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
             "/guild-commanders",
             routers::guild_commanders::routes(Arc::clone(&db_pool)),
         )
+        .nest("/guilds", routers::guilds::routes(Arc::clone(&db_pool)))
         .nest(
             "/crew-switchboard",
             routers::crew_switchboard::routes(Arc::clone(&db_pool)),
@@ -46,6 +62,10 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
             "/authentication",
             routers::authentication::routes(Arc::clone(&db_pool)),
         )
+        .nest("/users", routers::users::routes(Arc::clone(&db_pool)))
+        .nest("/me", routers::notifications::routes(Arc::clone(&db_pool)))
+        .nest("/admin", routers::admin::routes(Arc::clone(&db_pool)))
+        .nest("/webhooks", routers::webhooks::routes(Arc::clone(&db_pool)))
         .nest(
             "/adventurers",
             routers::adventurers::routes(Arc::clone(&db_pool)),
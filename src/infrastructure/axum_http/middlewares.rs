@@ -1,63 +1,235 @@
+use std::{collections::HashMap, sync::Arc};
+
 use axum::{
-    extract::Request,
+    extract::{Path, Request, State},
     http::{header, StatusCode},
     middleware::Next,
     response::Response,
+    Extension,
 };
 
 use crate::{
-    config::config_loader::{get_adventurer_secret_env, get_guild_commanders_secret_env},
-    infrastructure::jwt_authentication,
+    application::usecases::{authentication, guild_access::GuildAccessUseCase},
+    config::config_loader::get_user_secret_env,
+    domain::{
+        repositories::users::UsersRepository,
+        value_objects::guild_membership_model::{GuildAccess, GuildMember},
+    },
+    infrastructure::{
+        jwt_authentication::{
+            self,
+            jwt_model::{Claims, Roles},
+        },
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{guilds::GuildsPostgres, users::UsersPostgres},
+        },
+    },
 };
 
+pub async fn users_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let Some(claims) = verified_claims(&req) else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    check_user_active(db_pool, &claims).await?;
+
+    let Ok(user_id) = claims.sub.parse::<i32>() else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    req.extensions_mut().insert(user_id);
+    req.extensions_mut().insert(claims.roles);
+
+    Ok(next.run(req).await)
+}
+
 pub async fn adventurers_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
     mut req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if let Some(cookie_header) = req.headers().get(header::COOKIE) {
-        if let Ok(cookie_str) = cookie_header.to_str() {
-            let access_token = get_cookie_value(cookie_str, "act");
-
-            if let Some(token) = access_token {
-                if let Ok(secret_env) = get_adventurer_secret_env() {
-                    if let Ok(claims) = jwt_authentication::verify_token(secret_env.secret, token) {
-                        if let Ok(adventurer_id) = claims.sub.parse::<i32>() {
-                            req.extensions_mut().insert(adventurer_id);
-                            return Ok(next.run(req).await);
-                        }
-                    }
-                }
-            }
-        }
-    }
+    let Some(claims) = verified_claims(&req) else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
 
-    Err(StatusCode::UNAUTHORIZED)
+    check_user_active(db_pool, &claims).await?;
+
+    let Some(adventurer_id) = profile_id(&claims, &Roles::Adventurer) else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    req.extensions_mut().insert(adventurer_id);
+    req.extensions_mut().insert(Roles::Adventurer);
+
+    Ok(next.run(req).await)
 }
 
 pub async fn guild_commanders_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let Some(claims) = verified_claims(&req) else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    check_user_active(db_pool, &claims).await?;
+
+    let Some(guild_commander_id) = profile_id(&claims, &Roles::GuildCommander) else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    req.extensions_mut().insert(guild_commander_id);
+    req.extensions_mut().insert(Roles::GuildCommander);
+
+    Ok(next.run(req).await)
+}
+
+pub async fn admins_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
     mut req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if let Some(cookie_header) = req.headers().get(header::COOKIE) {
-        if let Ok(cookie_str) = cookie_header.to_str() {
-            let access_token = get_cookie_value(cookie_str, "act");
-
-            if let Some(token) = access_token {
-                if let Ok(secret_env) = get_guild_commanders_secret_env() {
-                    if let Ok(claims) = jwt_authentication::verify_token(secret_env.secret, token) {
-                        if let Ok(guild_commander_id) = claims.sub.parse::<i32>() {
-                            req.extensions_mut().insert(guild_commander_id);
-                            return Ok(next.run(req).await);
-                        }
-                    }
-                }
-            }
+    let Some(claims) = verified_claims(&req) else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    check_user_active(db_pool, &claims).await?;
+
+    if !claims.roles.contains(&Roles::Admin) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let Ok(admin_user_id) = claims.sub.parse::<i32>() else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    req.extensions_mut().insert(admin_user_id);
+
+    Ok(next.run(req).await)
+}
+
+// accepts either role, the handler gets the id and the role it acts as; a user holding both
+// acts as an adventurer unless the x-acting-role header asks for GuildCommander
+pub async fn adventurers_or_guild_commanders_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let Some(claims) = verified_claims(&req) else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    check_user_active(db_pool, &claims).await?;
+
+    let requested_role = req
+        .headers()
+        .get(ACTING_ROLE_HEADER)
+        .and_then(|header_value| header_value.to_str().ok())
+        .map(Roles::try_from_str);
+
+    let roles = match requested_role {
+        Some(Ok(role)) => vec![role],
+        Some(Err(_)) => return Err(StatusCode::BAD_REQUEST),
+        None => vec![Roles::Adventurer, Roles::GuildCommander],
+    };
+
+    for role in roles {
+        if let Some(user_id) = profile_id(&claims, &role) {
+            req.extensions_mut().insert(user_id);
+            req.extensions_mut().insert(role);
+            return Ok(next.run(req).await);
         }
     }
 
     Err(StatusCode::UNAUTHORIZED)
 }
 
+// runs after one of the authorizations above, the caller must belong to the guild of every
+// row named in the path, routes without path parameters are not scoped to a guild
+pub async fn guild_members_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    Extension(user_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
+    path_params: Option<Path<HashMap<String, String>>>,
+    req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let Some(guild_member) = to_guild_member(user_id, &role) else {
+        return Err(StatusCode::FORBIDDEN);
+    };
+
+    let Some(Path(path_params)) = path_params else {
+        return Ok(next.run(req).await);
+    };
+
+    let guild_access_use_case = GuildAccessUseCase::new(Arc::new(GuildsPostgres::new(db_pool)));
+
+    match guild_access_use_case
+        .check(guild_member, &path_params)
+        .await
+    {
+        Ok(GuildAccess::Member) => Ok(next.run(req).await),
+        Ok(GuildAccess::NotMember) => Err(StatusCode::FORBIDDEN),
+        Ok(GuildAccess::NotFound) => Err(StatusCode::NOT_FOUND),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+// an Admin has no profile a guild could hold, its user id is not an adventurer or guild
+// commander id, so it is no guild member at all
+pub fn to_guild_member(user_id: i32, role: &Roles) -> Option<GuildMember> {
+    match role {
+        Roles::Adventurer => Some(GuildMember::Adventurer(user_id)),
+        Roles::GuildCommander => Some(GuildMember::GuildCommander(user_id)),
+        Roles::Admin => None,
+    }
+}
+
+pub const ACTING_ROLE_HEADER: &str = "x-acting-role";
+
+fn verified_claims(req: &Request) -> Option<Claims> {
+    let cookie_str = req.headers().get(header::COOKIE)?.to_str().ok()?;
+
+    let token = get_cookie_value(cookie_str, "act")?;
+
+    let secret_env = get_user_secret_env().ok()?;
+
+    jwt_authentication::verify_token(secret_env.secret, token).ok()
+}
+
+// a disabled, deactivated or deleted account is turned away even while its token has not
+// expired yet
+async fn check_user_active(db_pool: Arc<PgPoolSquad>, claims: &Claims) -> Result<(), StatusCode> {
+    let Ok(user_id) = claims.sub.parse::<i32>() else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    let users_repository = UsersPostgres::new(db_pool);
+
+    match users_repository.find_by_id(user_id).await {
+        Ok(user) if authentication::check_user_active(&user).is_ok() => Ok(()),
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+fn profile_id(claims: &Claims, role: &Roles) -> Option<i32> {
+    if !claims.roles.contains(role) {
+        return None;
+    }
+
+    match role {
+        Roles::Adventurer => claims.adventurer_id,
+        Roles::GuildCommander => claims.guild_commander_id,
+        Roles::Admin => None,
+    }
+}
+
 fn get_cookie_value(cookie_header: &str, key: &str) -> Option<String> {
     cookie_header.split("; ").find_map(|cookie| {
         let mut parts = cookie.splitn(2, "=");
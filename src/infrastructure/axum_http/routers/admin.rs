@@ -0,0 +1,179 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch},
+    Extension, Json, Router,
+};
+
+use crate::{
+    application::usecases::admin::AdminUseCase,
+    domain::{
+        repositories::{admin::AdminRepository, outbox::OutboxRepository, users::UsersRepository},
+        value_objects::admin_model::{
+            AdminAuditLogFilter, ForceQuestStatusModel, UserSearchFilter,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::admins_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{admin::AdminPostgres, outbox::OutboxPostgres, users::UsersPostgres},
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let admin_repository = AdminPostgres::new(Arc::clone(&db_pool));
+    let users_repository = UsersPostgres::new(Arc::clone(&db_pool));
+    let outbox_repository = OutboxPostgres::new(Arc::clone(&db_pool));
+
+    let admin_use_case = AdminUseCase::new(
+        Arc::new(admin_repository),
+        Arc::new(users_repository),
+        Arc::new(outbox_repository),
+    );
+
+    Router::new()
+        .route("/users", get(users))
+        .route("/users/:user_id/disable", patch(disable_user))
+        .route("/users/:user_id/enable", patch(enable_user))
+        .route("/quests/:quest_id/status", patch(force_quest_status))
+        .route("/quests/:quest_id/restore", patch(restore_quest))
+        .route("/audit-logs", get(audit_logs))
+        .route("/outbox", get(outbox_backlog))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            admins_authorization,
+        ))
+        .with_state(Arc::new(admin_use_case))
+}
+
+pub async fn users<T1, T2, T3>(
+    State(admin_use_case): State<Arc<AdminUseCase<T1, T2, T3>>>,
+    filter: Query<UserSearchFilter>,
+) -> impl IntoResponse
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    match admin_use_case.users(&filter).await {
+        Ok(users_model) => (StatusCode::OK, Json(users_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn disable_user<T1, T2, T3>(
+    State(admin_use_case): State<Arc<AdminUseCase<T1, T2, T3>>>,
+    Extension(admin_user_id): Extension<i32>,
+    Path(user_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    match admin_use_case.disable_user(admin_user_id, user_id).await {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Disabled user id: {} successfully", user_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn enable_user<T1, T2, T3>(
+    State(admin_use_case): State<Arc<AdminUseCase<T1, T2, T3>>>,
+    Extension(admin_user_id): Extension<i32>,
+    Path(user_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    match admin_use_case.enable_user(admin_user_id, user_id).await {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Enabled user id: {} successfully", user_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn force_quest_status<T1, T2, T3>(
+    State(admin_use_case): State<Arc<AdminUseCase<T1, T2, T3>>>,
+    Extension(admin_user_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+    Json(force_quest_status_model): Json<ForceQuestStatusModel>,
+) -> impl IntoResponse
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    let status = force_quest_status_model.status.clone();
+
+    match admin_use_case
+        .force_quest_status(admin_user_id, quest_id, force_quest_status_model)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Forced quest id: {} to {} successfully", quest_id, status),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn restore_quest<T1, T2, T3>(
+    State(admin_use_case): State<Arc<AdminUseCase<T1, T2, T3>>>,
+    Extension(admin_user_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    match admin_use_case.restore_quest(admin_user_id, quest_id).await {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Restored quest id: {} successfully", quest_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn audit_logs<T1, T2, T3>(
+    State(admin_use_case): State<Arc<AdminUseCase<T1, T2, T3>>>,
+    filter: Query<AdminAuditLogFilter>,
+) -> impl IntoResponse
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    match admin_use_case.audit_logs(&filter).await {
+        Ok(audit_logs_model) => (StatusCode::OK, Json(audit_logs_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn outbox_backlog<T1, T2, T3>(
+    State(admin_use_case): State<Arc<AdminUseCase<T1, T2, T3>>>,
+) -> impl IntoResponse
+where
+    T1: AdminRepository + Send + Sync,
+    T2: UsersRepository + Send + Sync,
+    T3: OutboxRepository + Send + Sync,
+{
+    match admin_use_case.outbox_backlog().await {
+        Ok(outbox_backlog_model) => (StatusCode::OK, Json(outbox_backlog_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
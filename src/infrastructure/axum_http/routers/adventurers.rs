@@ -1,15 +1,33 @@
 use std::sync::Arc;
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Extension, Json, Router,
+};
 
 use crate::{
-    application::usecases::adventurers::AdventurersUseCase,
+    application::usecases::{
+        adventurers::AdventurersUseCase, users::UsersUseCase, wallet::WalletUseCase,
+    },
     domain::{
-        repositories::adventurers::AdventurersRepository,
-        value_objects::adventurer_model::RegisterAdventurerModel,
+        repositories::{
+            adventurers::AdventurersRepository, users::UsersRepository, wallet::WalletRepository,
+        },
+        value_objects::user_model::RegisterUserModel,
     },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::adventurers::AdventurersPostgres,
+    infrastructure::{
+        axum_http::middlewares::adventurers_authorization,
+        jwt_authentication::jwt_model::Roles,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                adventurers::AdventurersPostgres, users::UsersPostgres, wallet::WalletPostgres,
+            },
+        },
     },
 };
 
@@ -19,24 +37,47 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     // usecase
     // router state
 
-    let adventurers_repository = AdventurersPostgres::new(db_pool);
+    let adventurers_repository = AdventurersPostgres::new(Arc::clone(&db_pool));
+    let users_repository = UsersPostgres::new(Arc::clone(&db_pool));
+    let wallet_repository = WalletPostgres::new(Arc::clone(&db_pool));
 
     let adventurers_use_case = AdventurersUseCase::new(Arc::new(adventurers_repository));
+    let users_use_case = UsersUseCase::new(Arc::new(users_repository));
+    let wallet_use_case = WalletUseCase::new(Arc::new(wallet_repository));
+
+    let profile_routes = Router::new()
+        .route("/me", get(profile))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_authorization,
+        ))
+        .with_state(Arc::new(adventurers_use_case));
 
+    let wallet_routes = Router::new()
+        .route("/me/wallet", get(wallet))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_authorization,
+        ))
+        .with_state(Arc::new(wallet_use_case));
+
+    // kept for older clients, registers a user holding only the Adventurer role
     Router::new()
         .route("/", post(register))
-        .with_state(Arc::new(adventurers_use_case))
+        .with_state(Arc::new(users_use_case))
+        .merge(profile_routes)
+        .merge(wallet_routes)
 }
 
 pub async fn register<T>(
-    State(adventurers_use_case): State<Arc<AdventurersUseCase<T>>>,
-    Json(register_adventurer_model): Json<RegisterAdventurerModel>,
+    State(users_use_case): State<Arc<UsersUseCase<T>>>,
+    Json(register_user_model): Json<RegisterUserModel>,
 ) -> impl IntoResponse
 where
-    T: AdventurersRepository + Send + Sync,
+    T: UsersRepository + Send + Sync,
 {
-    match adventurers_use_case
-        .register(register_adventurer_model)
+    match users_use_case
+        .register_as(register_user_model, Roles::Adventurer)
         .await
     {
         Ok(adventurer_id) => (
@@ -47,3 +88,31 @@ where
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
+
+pub async fn profile<T>(
+    State(adventurers_use_case): State<Arc<AdventurersUseCase<T>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: AdventurersRepository + Send + Sync,
+{
+    match adventurers_use_case.profile(adventurer_id).await {
+        Ok(adventurer_profile_model) => {
+            (StatusCode::OK, Json(adventurer_profile_model)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn wallet<T>(
+    State(wallet_use_case): State<Arc<WalletUseCase<T>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: WalletRepository + Send + Sync,
+{
+    match wallet_use_case.wallet(adventurer_id).await {
+        Ok(wallet_model) => (StatusCode::OK, Json(wallet_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -13,199 +13,92 @@ use axum_extra::extract::cookie::{Cookie, CookieJar};
 use crate::{
     application::usecases::authentication::AuthenticationUseCase,
     config::{config_loader::get_stage, stage::Stage},
-    domain::repositories::{
-        adventurers::AdventurersRepository, guild_commanders::GuildCommandersRepository,
-    },
+    domain::repositories::users::UsersRepository,
     infrastructure::{
-        jwt_authentication::authentication_model::LoginModel,
-        postgres::{
-            postgres_connection::PgPoolSquad,
-            repositories::{
-                adventurers::AdventurersPostgres, guild_commanders::GuildCommandersPostgres,
-            },
-        },
+        jwt_authentication::{authentication_model::LoginModel, jwt_model::Passport},
+        postgres::{postgres_connection::PgPoolSquad, repositories::users::UsersPostgres},
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let adventurers_repository = AdventurersPostgres::new(Arc::clone(&db_pool));
-
-    let guild_commanders_repository = GuildCommandersPostgres::new(Arc::clone(&db_pool));
+    let users_repository = UsersPostgres::new(Arc::clone(&db_pool));
 
-    let authentication_use_case = AuthenticationUseCase::new(
-        Arc::new(adventurers_repository),
-        Arc::new(guild_commanders_repository),
-    );
+    let authentication_use_case = AuthenticationUseCase::new(Arc::new(users_repository));
 
+    // the per-role routes stay for older clients, every login hands out the same user token
     Router::new()
+        .route("/login", post(login))
+        .route("/refresh-token", post(refresh_token))
         .route("/adventurers/login", post(adventurers_login))
-        .route(
-            "/adventurers/refresh-token",
-            post(adventurers_refresh_token),
-        )
+        .route("/adventurers/refresh-token", post(refresh_token))
         .route("/guild-commanders/login", post(guild_commanders_login))
-        .route(
-            "/guild-commanders/refresh-token",
-            post(guild_commanders_refresh_token),
-        )
+        .route("/guild-commanders/refresh-token", post(refresh_token))
         .with_state(Arc::new(authentication_use_case))
 }
 
-pub async fn adventurers_login<T1, T2>(
-    State(authenticate_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+pub async fn login<T>(
+    State(authenticate_use_case): State<Arc<AuthenticationUseCase<T>>>,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T1: AdventurersRepository + Send + Sync,
-    T2: GuildCommandersRepository + Send + Sync,
+    T: UsersRepository + Send + Sync,
 {
-    match authenticate_use_case.adventurers_login(login_model).await {
-        Ok(passport) => {
-            let mut act_cookie = Cookie::build(("act", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            let mut rft_cookie = Cookie::build(("rft", passport.refresh_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            if get_stage() == Stage::Production {
-                act_cookie = act_cookie.secure(true);
-                rft_cookie = rft_cookie.secure(true);
-            }
-
-            let mut headers = HeaderMap::new();
-
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&act_cookie.to_string()).unwrap(),
-            );
-
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
-            );
-
-            (StatusCode::OK, headers, format!("Login successfully")).into_response()
-        }
+    match authenticate_use_case.login(login_model).await {
+        Ok(passport) => (
+            StatusCode::OK,
+            passport_cookies(&passport),
+            "Login successfully",
+        )
+            .into_response(),
         Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
     }
 }
 
-pub async fn adventurers_refresh_token<T1, T2>(
-    State(authenticate_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
-    jar: CookieJar,
+pub async fn adventurers_login<T>(
+    State(authenticate_use_case): State<Arc<AuthenticationUseCase<T>>>,
+    Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T1: AdventurersRepository + Send + Sync,
-    T2: GuildCommandersRepository + Send + Sync,
+    T: UsersRepository + Send + Sync,
 {
-    if jar.get("rft").is_none() {
-        return (StatusCode::BAD_REQUEST, "Refresh token not found").into_response();
-    }
-
-    let rft = jar.get("rft").unwrap();
-    let refresh_token = rft.value().to_string(); // extract value to static
-
-    match authenticate_use_case
-        .adventurers_refresh_token(refresh_token)
-        .await
-    {
-        Ok(passport) => {
-            let mut act_cookie = Cookie::build(("act", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            let mut rft_cookie = Cookie::build(("rft", passport.refresh_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            if get_stage() == Stage::Production {
-                act_cookie = act_cookie.secure(true);
-                rft_cookie = rft_cookie.secure(true);
-            }
-
-            let mut headers = HeaderMap::new();
-
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&act_cookie.to_string()).unwrap(),
-            );
-
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
-            );
-
-            (StatusCode::OK, headers, format!("Login successfully")).into_response()
-        }
+    match authenticate_use_case.adventurers_login(login_model).await {
+        Ok(passport) => (
+            StatusCode::OK,
+            passport_cookies(&passport),
+            "Login successfully",
+        )
+            .into_response(),
         Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
     }
 }
 
-pub async fn guild_commanders_login<T1, T2>(
-    State(authenticate_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+pub async fn guild_commanders_login<T>(
+    State(authenticate_use_case): State<Arc<AuthenticationUseCase<T>>>,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T1: AdventurersRepository + Send + Sync,
-    T2: GuildCommandersRepository + Send + Sync,
+    T: UsersRepository + Send + Sync,
 {
     match authenticate_use_case
         .guild_commanders_login(login_model)
         .await
     {
-        Ok(passport) => {
-            let mut act_cookie = Cookie::build(("act", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            let mut rft_cookie = Cookie::build(("rft", passport.refresh_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            if get_stage() == Stage::Production {
-                act_cookie = act_cookie.secure(true);
-                rft_cookie = rft_cookie.secure(true);
-            }
-
-            let mut headers = HeaderMap::new();
-
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&act_cookie.to_string()).unwrap(),
-            );
-
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
-            );
-
-            (StatusCode::OK, headers, "Login successfully").into_response()
-        }
+        Ok(passport) => (
+            StatusCode::OK,
+            passport_cookies(&passport),
+            "Login successfully",
+        )
+            .into_response(),
         Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
     }
 }
 
-pub async fn guild_commanders_refresh_token<T1, T2>(
-    State(authenticate_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+pub async fn refresh_token<T>(
+    State(authenticate_use_case): State<Arc<AuthenticationUseCase<T>>>,
     jar: CookieJar,
 ) -> impl IntoResponse
 where
-    T1: AdventurersRepository + Send + Sync,
-    T2: GuildCommandersRepository + Send + Sync,
+    T: UsersRepository + Send + Sync,
 {
     if jar.get("rft").is_none() {
         return (StatusCode::BAD_REQUEST, "Refresh token not found").into_response();
@@ -214,42 +107,46 @@ where
     let rft = jar.get("rft").unwrap();
     let refresh_token = rft.value().to_string(); // extract value to static
 
-    match authenticate_use_case
-        .guild_commanders_refresh_token(refresh_token)
-        .await
-    {
-        Ok(passport) => {
-            let mut act_cookie = Cookie::build(("act", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            let mut rft_cookie = Cookie::build(("rft", passport.refresh_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            if get_stage() == Stage::Production {
-                act_cookie = act_cookie.secure(true);
-                rft_cookie = rft_cookie.secure(true);
-            }
-
-            let mut headers = HeaderMap::new();
-
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&act_cookie.to_string()).unwrap(),
-            );
-
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
-            );
-
-            (StatusCode::OK, headers, format!("Login successfully")).into_response()
-        }
+    match authenticate_use_case.refresh_token(refresh_token).await {
+        Ok(passport) => (
+            StatusCode::OK,
+            passport_cookies(&passport),
+            "Login successfully",
+        )
+            .into_response(),
         Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
     }
 }
+
+fn passport_cookies(passport: &Passport) -> HeaderMap {
+    let mut act_cookie = Cookie::build(("act", passport.access_token.clone()))
+        .path("/")
+        .same_site(cookie::SameSite::Lax)
+        .http_only(true)
+        .max_age(Duration::days(14));
+
+    let mut rft_cookie = Cookie::build(("rft", passport.refresh_token.clone()))
+        .path("/")
+        .same_site(cookie::SameSite::Lax)
+        .http_only(true)
+        .max_age(Duration::days(14));
+
+    if get_stage() == Stage::Production {
+        act_cookie = act_cookie.secure(true);
+        rft_cookie = rft_cookie.secure(true);
+    }
+
+    let mut headers = HeaderMap::new();
+
+    headers.append(
+        header::SET_COOKIE,
+        HeaderValue::from_str(&act_cookie.to_string()).unwrap(),
+    );
+
+    headers.append(
+        header::SET_COOKIE,
+        HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
+    );
+
+    headers
+}
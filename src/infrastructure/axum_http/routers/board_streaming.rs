@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    routing::get,
+    Extension, Router,
+};
+use futures_util::stream;
+use tracing::error;
+
+use crate::{
+    application::{
+        board_events::BoardEventsSender, usecases::board_streaming::BoardStreamingUseCase,
+    },
+    domain::{
+        repositories::quest_viewing::QuestViewingRepository,
+        value_objects::{
+            board_checking_filter::BoardCheckingFilter, board_event_model::BoardStreamMessage,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::{adventurers_or_guild_commanders_authorization, to_guild_member},
+        jwt_authentication::jwt_model::Roles,
+        postgres::{
+            postgres_connection::PgPoolSquad, repositories::quest_viewing::QuestViewingPostgres,
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, board_events: BoardEventsSender) -> Router {
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+
+    let board_streaming_use_case =
+        BoardStreamingUseCase::new(Arc::new(quest_viewing_repository), board_events);
+
+    Router::new()
+        .route("/board-stream", get(board_stream))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_or_guild_commanders_authorization,
+        ))
+        .with_state(Arc::new(board_streaming_use_case))
+}
+
+// server-sent events named after the event type, takes the same query as board-checking
+pub async fn board_stream<T>(
+    State(board_streaming_use_case): State<Arc<BoardStreamingUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
+    filter: Query<BoardCheckingFilter>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync + 'static,
+{
+    if let Err(e) = filter.status_names() {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+
+    let Some(guild_member) = to_guild_member(user_id, &role) else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+
+    let subscription = match board_streaming_use_case
+        .subscribe(filter.0, guild_member)
+        .await
+    {
+        Ok(subscription) => subscription,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+
+    let events = stream::unfold(
+        (board_streaming_use_case, subscription),
+        |(board_streaming_use_case, mut subscription)| async move {
+            let event = match board_streaming_use_case.next(&mut subscription).await {
+                Ok(Some(BoardStreamMessage::Event(board_event_model))) => Event::default()
+                    .event(board_event_model.domain_event.event_type().to_string())
+                    .json_data(&board_event_model),
+                Ok(Some(BoardStreamMessage::Lagged(skipped))) => {
+                    Ok(Event::default().event("Lagged").data(skipped.to_string()))
+                }
+                Ok(None) => return None,
+                // the client reconnects and starts over from a fresh board
+                Err(e) => {
+                    error!("🔴 Failed to stream the board: {}", e);
+                    return None;
+                }
+            };
+
+            Some((event, (board_streaming_use_case, subscription)))
+        },
+    );
+
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
@@ -5,17 +5,25 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, post, Router},
-    Extension,
+    routing::{delete, get, patch, post, Router},
+    Extension, Json,
 };
 
 use crate::{
     application::usecases::crew_switchboard::CrewSwitchboardUseCase,
-    domain::repositories::{
-        crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+    domain::{
+        repositories::{
+            crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            crew_member_model::AssignCrewRoleModel, quest_join_outcomes::QuestJoinOutcomes,
+            quest_rewards::SetRewardWeightModel,
+        },
     },
     infrastructure::{
-        axum_http::middlewares::adventurers_authorization,
+        axum_http::middlewares::{
+            adventurers_authorization, guild_commanders_authorization, guild_members_authorization,
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{
@@ -36,10 +44,63 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         Arc::new(quest_viewing_repository),
     );
 
-    Router::new()
+    // route_layer only wraps the routes registered before it, so each role gets its own router
+    let adventurers_routes = Router::new()
         .route("/join/:quest_id", post(join))
         .route("/leave/:quest_id", delete(leave))
-        .route_layer(middleware::from_fn(adventurers_authorization))
+        .route("/waitlist/:quest_id", get(waitlist_position))
+        .route("/waitlist/:quest_id", delete(leave_waitlist))
+        .route("/invitations", get(invitations))
+        .route(
+            "/invitations/:invitation_id/accept",
+            patch(accept_invitation),
+        )
+        .route(
+            "/invitations/:invitation_id/decline",
+            patch(decline_invitation),
+        )
+        .route(
+            "/applications/:application_id/withdraw",
+            patch(withdraw_application),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_authorization,
+        ));
+
+    let guild_commanders_routes = Router::new()
+        .route("/invite/:quest_id/:adventurer_id", post(invite))
+        .route("/kick/:quest_id/:adventurer_id", delete(kick))
+        .route("/role/:quest_id/:adventurer_id", patch(assign_role))
+        .route(
+            "/reward-weight/:quest_id/:adventurer_id",
+            patch(set_reward_weight),
+        )
+        .route("/quest-applications/:quest_id", get(applications))
+        .route(
+            "/applications/:application_id/approve",
+            patch(approve_application),
+        )
+        .route(
+            "/applications/:application_id/reject",
+            patch(reject_application),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ));
+
+    Router::new()
+        .merge(adventurers_routes)
+        .merge(guild_commanders_routes)
         .with_state(Arc::new(crew_switchboard_use_case))
 }
 
@@ -53,13 +114,27 @@ where
     T2: QuestViewingRepository + Send + Sync,
 {
     match crew_switchboard_usecase.join(quest_id, adventurer_id).await {
-        Ok(()) => (
+        Ok(QuestJoinOutcomes::Joined) => (
             StatusCode::OK,
             format!(
                 "Adventurer id: {} joined quest id: {} successfully",
                 adventurer_id, quest_id
             ),
         ),
+        Ok(QuestJoinOutcomes::Applied { application_id }) => (
+            StatusCode::ACCEPTED,
+            format!(
+                "Adventurer id: {} applied to quest id: {} with application id: {}",
+                adventurer_id, quest_id, application_id
+            ),
+        ),
+        Ok(QuestJoinOutcomes::Waitlisted { position }) => (
+            StatusCode::ACCEPTED,
+            format!(
+                "Quest id: {} is full, adventurer id: {} is waitlisted at position: {}",
+                quest_id, adventurer_id, position
+            ),
+        ),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
     }
 }
@@ -87,3 +162,301 @@ where
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
     }
 }
+
+pub async fn waitlist_position<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .waitlist_position(quest_id, adventurer_id)
+        .await
+    {
+        Ok(waitlist_position_model) => {
+            (StatusCode::OK, Json(waitlist_position_model)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn leave_waitlist<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .leave_waitlist(quest_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Adventurer id: {} left the waitlist of quest id: {} successfully",
+                adventurer_id, quest_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn invitations<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase.invitations(adventurer_id).await {
+        Ok(invitations_model) => (StatusCode::OK, Json(invitations_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn accept_invitation<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(invitation_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .accept_invitation(invitation_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Adventurer id: {} accepted invitation id: {} successfully",
+                adventurer_id, invitation_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn decline_invitation<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(invitation_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .decline_invitation(invitation_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Adventurer id: {} declined invitation id: {} successfully",
+                adventurer_id, invitation_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn invite<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .invite(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(invitation_id) => (
+            StatusCode::CREATED,
+            format!(
+                "Invited adventurer id: {} to quest id: {} with invitation id: {}",
+                adventurer_id, quest_id, invitation_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn assign_role<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+    Json(assign_crew_role_model): Json<AssignCrewRoleModel>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .assign_role(
+            quest_id,
+            guild_commander_id,
+            adventurer_id,
+            assign_crew_role_model,
+        )
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Assigned role of adventurer id: {} on quest id: {} successfully",
+                adventurer_id, quest_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn set_reward_weight<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+    Json(set_reward_weight_model): Json<SetRewardWeightModel>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .set_reward_weight(
+            quest_id,
+            guild_commander_id,
+            adventurer_id,
+            set_reward_weight_model,
+        )
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Set reward weight of adventurer id: {} on quest id: {} successfully",
+                adventurer_id, quest_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn kick<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .kick(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Adventurer id: {} kicked from quest id: {} successfully",
+                adventurer_id, quest_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn withdraw_application<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(application_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .withdraw_application(application_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Adventurer id: {} withdrew application id: {} successfully",
+                adventurer_id, application_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn applications<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .applications(quest_id, guild_commander_id)
+        .await
+    {
+        Ok(applications_model) => (StatusCode::OK, Json(applications_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn approve_application<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(application_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .approve_application(application_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Approved application id: {} successfully", application_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn reject_application<T1, T2>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(application_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .reject_application(application_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Rejected application id: {} successfully", application_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
@@ -3,36 +3,34 @@ use std::sync::Arc;
 use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
 
 use crate::{
-    application::usecases::guild_commanders::GuildCommandersUseCase,
-    domain::{
-        repositories::guild_commanders::GuildCommandersRepository,
-        value_objects::guild_commander_model::RegisterGuildCommanderModel,
-    },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::guild_commanders::GuildCommandersPostgres,
+    application::usecases::users::UsersUseCase,
+    domain::{repositories::users::UsersRepository, value_objects::user_model::RegisterUserModel},
+    infrastructure::{
+        jwt_authentication::jwt_model::Roles,
+        postgres::{postgres_connection::PgPoolSquad, repositories::users::UsersPostgres},
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let guild_commanders_repository = GuildCommandersPostgres::new(db_pool);
+    let users_repository = UsersPostgres::new(db_pool);
 
-    let guild_commanders_use_case =
-        GuildCommandersUseCase::new(Arc::new(guild_commanders_repository));
+    let users_use_case = UsersUseCase::new(Arc::new(users_repository));
 
+    // kept for older clients, registers a user holding only the GuildCommander role
     Router::new()
         .route("/", post(register))
-        .with_state(Arc::new(guild_commanders_use_case))
+        .with_state(Arc::new(users_use_case))
 }
 
 pub async fn register<T>(
-    State(guild_commanders_use_case): State<Arc<GuildCommandersUseCase<T>>>,
-    Json(register_guild_commander_model): Json<RegisterGuildCommanderModel>,
+    State(users_use_case): State<Arc<UsersUseCase<T>>>,
+    Json(register_user_model): Json<RegisterUserModel>,
 ) -> impl IntoResponse
 where
-    T: GuildCommandersRepository + Send + Sync,
+    T: UsersRepository + Send + Sync,
 {
-    match guild_commanders_use_case
-        .register(register_guild_commander_model)
+    match users_use_case
+        .register_as(register_user_model, Roles::GuildCommander)
         .await
     {
         Ok(guild_commander_id) => (
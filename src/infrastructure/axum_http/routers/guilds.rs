@@ -0,0 +1,299 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+
+use crate::{
+    application::usecases::guilds::GuildsUseCase,
+    domain::{
+        repositories::guilds::GuildsRepository,
+        value_objects::{
+            guild_invite_model::AddGuildInviteModel,
+            guild_membership_model::{AddGuildMemberModel, EditGuildMemberModel},
+            guild_model::AddGuildModel,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::{
+            adventurers_authorization, adventurers_or_guild_commanders_authorization,
+            guild_commanders_authorization, guild_members_authorization, to_guild_member,
+        },
+        jwt_authentication::jwt_model::Roles,
+        postgres::{postgres_connection::PgPoolSquad, repositories::guilds::GuildsPostgres},
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let guilds_repository = GuildsPostgres::new(Arc::clone(&db_pool));
+
+    let guilds_use_case = GuildsUseCase::new(Arc::new(guilds_repository));
+
+    let members_routes = Router::new()
+        .route("/", get(guilds))
+        .route("/:guild_id", get(view))
+        .route("/:guild_id/members", get(members))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_or_guild_commanders_authorization,
+        ));
+
+    let guild_commanders_routes = Router::new()
+        .route("/", post(add))
+        .route("/:guild_id/members", post(add_member))
+        .route(
+            "/:guild_id/members/:membership_id",
+            patch(change_member_role).delete(remove_member),
+        )
+        .route("/:guild_id/invites", post(add_invite).get(invites))
+        .route("/:guild_id/invites/:invite_id", delete(revoke_invite))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ));
+
+    // the invite code is the only credential, so accepting is open to any adventurer
+    let adventurers_routes = Router::new()
+        .route("/invites/:code/accept", post(accept_invite))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_authorization,
+        ));
+
+    Router::new()
+        .merge(members_routes)
+        .merge(guild_commanders_routes)
+        .merge(adventurers_routes)
+        .with_state(Arc::new(guilds_use_case))
+}
+
+pub async fn add<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_guild_model): Json<AddGuildModel>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_use_case
+        .add(guild_commander_id, add_guild_model)
+        .await
+    {
+        Ok(guild_id) => {
+            let response = format!("Added guild success with id: {}", guild_id);
+            (StatusCode::CREATED, response)
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn guilds<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    let Some(guild_member) = to_guild_member(user_id, &role) else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+
+    match guilds_use_case.guilds(guild_member).await {
+        Ok(guilds_model) => (StatusCode::OK, Json(guilds_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn view<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    let Some(guild_member) = to_guild_member(user_id, &role) else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+
+    match guilds_use_case.view(guild_id, guild_member).await {
+        Ok(guild_model) => (StatusCode::OK, Json(guild_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add_member<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(guild_id): Path<i32>,
+    Json(add_guild_member_model): Json<AddGuildMemberModel>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_use_case
+        .add_member(guild_id, guild_commander_id, add_guild_member_model)
+        .await
+    {
+        Ok(membership_id) => {
+            let response = format!("Added guild member success with id: {}", membership_id);
+            (StatusCode::CREATED, response)
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn members<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    let Some(guild_member) = to_guild_member(user_id, &role) else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+
+    match guilds_use_case.members(guild_id, guild_member).await {
+        Ok(members_model) => (StatusCode::OK, Json(members_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn change_member_role<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((guild_id, membership_id)): Path<(i32, i32)>,
+    Json(edit_guild_member_model): Json<EditGuildMemberModel>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_use_case
+        .change_member_role(
+            guild_id,
+            guild_commander_id,
+            membership_id,
+            edit_guild_member_model,
+        )
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Changed role of guild member id: {} successfully",
+                membership_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn remove_member<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((guild_id, membership_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_use_case
+        .remove_member(guild_id, guild_commander_id, membership_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!(
+                "Removed guild member id: {} from guild id: {} successfully",
+                membership_id, guild_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn add_invite<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(guild_id): Path<i32>,
+    Json(add_guild_invite_model): Json<AddGuildInviteModel>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_use_case
+        .add_invite(guild_id, guild_commander_id, add_guild_invite_model)
+        .await
+    {
+        Ok(invite_model) => (StatusCode::CREATED, Json(invite_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn invites<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_use_case.invites(guild_id, guild_commander_id).await {
+        Ok(invites_model) => (StatusCode::OK, Json(invites_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn revoke_invite<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((guild_id, invite_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_use_case
+        .revoke_invite(guild_id, guild_commander_id, invite_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Revoked guild invite id: {} successfully", invite_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn accept_invite<T>(
+    State(guilds_use_case): State<Arc<GuildsUseCase<T>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(code): Path<String>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_use_case.accept_invite(code, adventurer_id).await {
+        Ok(membership_id) => {
+            let response = format!("Joined guild success with membership id: {}", membership_id);
+            (StatusCode::CREATED, response)
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
@@ -18,7 +18,7 @@ use crate::{
         value_objects::quest_statuses::QuestStatuses,
     },
     infrastructure::{
-        axum_http::middlewares::guild_commanders_authorization,
+        axum_http::middlewares::{guild_commanders_authorization, guild_members_authorization},
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{
@@ -41,7 +41,14 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/in-journey/:quest_id", patch(in_journey))
         .route("/to-completed/:quest_id", patch(to_completed))
         .route("/to-failed/:quest_id", patch(to_failed))
-        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ))
         .with_state(Arc::new(journey_ledger_use_case))
 }
 
@@ -1,7 +1,16 @@
+pub mod admin;
 pub mod adventurers;
 pub mod authentication;
+pub mod board_streaming;
 pub mod crew_switchboard;
 pub mod guild_commanders;
+pub mod guilds;
 pub mod journey_ledger;
+pub mod notifications;
+pub mod quest_comments;
+pub mod quest_milestones;
 pub mod quest_ops;
+pub mod quest_templates;
 pub mod quest_viewing;
+pub mod users;
+pub mod webhooks;
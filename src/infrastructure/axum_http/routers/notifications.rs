@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch},
+    Extension, Json, Router,
+};
+
+use crate::{
+    application::usecases::notifications::NotificationsUseCase,
+    domain::{
+        repositories::notifications::NotificationsRepository,
+        value_objects::notification_model::{NotificationPreferenceModel, NotificationsFilter},
+    },
+    infrastructure::{
+        axum_http::middlewares::users_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad, repositories::notifications::NotificationsPostgres,
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let notifications_repository = NotificationsPostgres::new(Arc::clone(&db_pool));
+
+    let notifications_use_case = NotificationsUseCase::new(Arc::new(notifications_repository));
+
+    // the inbox belongs to the user, whichever role the notification was meant for
+    Router::new()
+        .route("/notifications", get(notifications))
+        .route("/notifications/read-all", patch(mark_all_read))
+        .route("/notifications/:notification_id/read", patch(mark_read))
+        .route(
+            "/notification-preferences",
+            get(preferences).put(set_preferences),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            users_authorization,
+        ))
+        .with_state(Arc::new(notifications_use_case))
+}
+
+pub async fn notifications<T>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    filter: Query<NotificationsFilter>,
+) -> impl IntoResponse
+where
+    T: NotificationsRepository + Send + Sync,
+{
+    match notifications_use_case.notifications(user_id, &filter).await {
+        Ok(notifications_page_model) => {
+            (StatusCode::OK, Json(notifications_page_model)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mark_read<T>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(notification_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: NotificationsRepository + Send + Sync,
+{
+    match notifications_use_case
+        .mark_read(user_id, notification_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Marked notification id: {} as read", notification_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn mark_all_read<T>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: NotificationsRepository + Send + Sync,
+{
+    match notifications_use_case.mark_all_read(user_id).await {
+        Ok(marked) => (
+            StatusCode::OK,
+            format!("Marked {} notifications as read", marked),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn preferences<T>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: NotificationsRepository + Send + Sync,
+{
+    match notifications_use_case.preferences(user_id).await {
+        Ok(notification_preference_models) => {
+            (StatusCode::OK, Json(notification_preference_models)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn set_preferences<T>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Json(notification_preference_models): Json<Vec<NotificationPreferenceModel>>,
+) -> impl IntoResponse
+where
+    T: NotificationsRepository + Send + Sync,
+{
+    match notifications_use_case
+        .set_preferences(user_id, notification_preference_models)
+        .await
+    {
+        Ok(notification_preference_models) => {
+            (StatusCode::OK, Json(notification_preference_models)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+
+use crate::{
+    application::usecases::quest_comments::QuestCommentsUseCase,
+    domain::{
+        repositories::{
+            quest_comments::QuestCommentsRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_comment_author_roles::QuestCommentAuthorRoles,
+            quest_comment_model::{
+                AddQuestCommentModel, EditQuestCommentModel, QuestCommentsPagination,
+            },
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::{
+            adventurers_or_guild_commanders_authorization, guild_members_authorization,
+        },
+        jwt_authentication::jwt_model::Roles,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                quest_comments::QuestCommentsPostgres, quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+// merged into /quest-viewing, the thread lives next to the quest details
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_comments_repository = QuestCommentsPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+
+    let quest_comments_use_case = QuestCommentsUseCase::new(
+        Arc::new(quest_comments_repository),
+        Arc::new(quest_viewing_repository),
+    );
+
+    let members_routes = Router::new()
+        .route("/:quest_id/comments", get(comments))
+        .route("/:quest_id/comments", post(post_comment))
+        .route("/:quest_id/comments/:comment_id", patch(edit_comment))
+        .route("/:quest_id/comments/:comment_id", delete(remove_comment))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_or_guild_commanders_authorization,
+        ));
+
+    members_routes.with_state(Arc::new(quest_comments_use_case))
+}
+
+// an Admin has no adventurer or guild commander profile to post under
+fn to_author_role(role: &Roles) -> Option<QuestCommentAuthorRoles> {
+    match role {
+        Roles::Adventurer => Some(QuestCommentAuthorRoles::Adventurer),
+        Roles::GuildCommander => Some(QuestCommentAuthorRoles::GuildCommander),
+        Roles::Admin => None,
+    }
+}
+
+pub async fn comments<T1, T2>(
+    State(quest_comments_use_case): State<Arc<QuestCommentsUseCase<T1, T2>>>,
+    Path(quest_id): Path<i32>,
+    pagination: Query<QuestCommentsPagination>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_comments_use_case
+        .comments(quest_id, &pagination)
+        .await
+    {
+        Ok(quest_comments_page_model) => {
+            (StatusCode::OK, Json(quest_comments_page_model)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn post_comment<T1, T2>(
+    State(quest_comments_use_case): State<Arc<QuestCommentsUseCase<T1, T2>>>,
+    Extension(author_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
+    Path(quest_id): Path<i32>,
+    Json(add_quest_comment_model): Json<AddQuestCommentModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    let Some(author_role) = to_author_role(&role) else {
+        return (
+            StatusCode::FORBIDDEN,
+            "Only adventurers and guild commanders can comment".to_string(),
+        );
+    };
+
+    match quest_comments_use_case
+        .post(quest_id, author_id, author_role, add_quest_comment_model)
+        .await
+    {
+        Ok(comment_id) => (
+            StatusCode::CREATED,
+            format!(
+                "Posted comment id: {} on quest id: {} successfully",
+                comment_id, quest_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn edit_comment<T1, T2>(
+    State(quest_comments_use_case): State<Arc<QuestCommentsUseCase<T1, T2>>>,
+    Extension(author_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
+    Path((quest_id, comment_id)): Path<(i32, i32)>,
+    Json(edit_quest_comment_model): Json<EditQuestCommentModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    let Some(author_role) = to_author_role(&role) else {
+        return (
+            StatusCode::FORBIDDEN,
+            "Only adventurers and guild commanders can comment".to_string(),
+        );
+    };
+
+    match quest_comments_use_case
+        .edit(
+            quest_id,
+            comment_id,
+            author_id,
+            author_role,
+            edit_quest_comment_model,
+        )
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Edited comment id: {} successfully", comment_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn remove_comment<T1, T2>(
+    State(quest_comments_use_case): State<Arc<QuestCommentsUseCase<T1, T2>>>,
+    Extension(author_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
+    Path((quest_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    let Some(author_role) = to_author_role(&role) else {
+        return (
+            StatusCode::FORBIDDEN,
+            "Only adventurers and guild commanders can comment".to_string(),
+        );
+    };
+
+    match quest_comments_use_case
+        .remove(quest_id, comment_id, author_id, author_role)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Removed comment id: {} successfully", comment_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
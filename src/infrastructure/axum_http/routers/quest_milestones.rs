@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+
+use crate::{
+    application::usecases::quest_milestones::QuestMilestonesUseCase,
+    domain::{
+        repositories::{
+            quest_milestones::QuestMilestonesRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::quest_milestone_model::{AddQuestMilestoneModel, EditQuestMilestoneModel},
+    },
+    infrastructure::{
+        axum_http::middlewares::{
+            adventurers_authorization, adventurers_or_guild_commanders_authorization,
+            guild_commanders_authorization, guild_members_authorization,
+        },
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                quest_milestones::QuestMilestonesPostgres, quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+// merged into /quest-viewing, the checklist lives next to the quest details
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_milestones_repository = QuestMilestonesPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+
+    let quest_milestones_use_case = QuestMilestonesUseCase::new(
+        Arc::new(quest_milestones_repository),
+        Arc::new(quest_viewing_repository),
+    );
+
+    let members_routes = Router::new()
+        .route("/:quest_id/milestones", get(milestones))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_or_guild_commanders_authorization,
+        ));
+
+    let guild_commanders_routes = Router::new()
+        .route("/:quest_id/milestones", post(add))
+        .route("/:quest_id/milestones/:milestone_id", patch(edit))
+        .route("/:quest_id/milestones/:milestone_id", delete(remove))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ));
+
+    let adventurers_routes = Router::new()
+        .route("/:quest_id/milestones/:milestone_id/tick", post(tick))
+        .route("/:quest_id/milestones/:milestone_id/tick", delete(untick))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_authorization,
+        ));
+
+    Router::new()
+        .merge(members_routes)
+        .merge(guild_commanders_routes)
+        .merge(adventurers_routes)
+        .with_state(Arc::new(quest_milestones_use_case))
+}
+
+pub async fn milestones<T1, T2>(
+    State(quest_milestones_use_case): State<Arc<QuestMilestonesUseCase<T1, T2>>>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestMilestonesRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_milestones_use_case.milestones(quest_id).await {
+        Ok(quest_milestones_model) => {
+            (StatusCode::OK, Json(quest_milestones_model)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add<T1, T2>(
+    State(quest_milestones_use_case): State<Arc<QuestMilestonesUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+    Json(add_quest_milestone_model): Json<AddQuestMilestoneModel>,
+) -> impl IntoResponse
+where
+    T1: QuestMilestonesRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_milestones_use_case
+        .add(quest_id, guild_commander_id, add_quest_milestone_model)
+        .await
+    {
+        Ok(milestone_id) => (
+            StatusCode::CREATED,
+            format!(
+                "Added milestone id: {} to quest id: {} successfully",
+                milestone_id, quest_id
+            ),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(quest_milestones_use_case): State<Arc<QuestMilestonesUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, milestone_id)): Path<(i32, i32)>,
+    Json(edit_quest_milestone_model): Json<EditQuestMilestoneModel>,
+) -> impl IntoResponse
+where
+    T1: QuestMilestonesRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_milestones_use_case
+        .edit(
+            quest_id,
+            milestone_id,
+            guild_commander_id,
+            edit_quest_milestone_model,
+        )
+        .await
+    {
+        Ok(milestone_id) => (
+            StatusCode::OK,
+            format!("Edited milestone id: {} successfully", milestone_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(quest_milestones_use_case): State<Arc<QuestMilestonesUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, milestone_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestMilestonesRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_milestones_use_case
+        .remove(quest_id, milestone_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Removed milestone id: {} successfully", milestone_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn tick<T1, T2>(
+    State(quest_milestones_use_case): State<Arc<QuestMilestonesUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path((quest_id, milestone_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestMilestonesRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_milestones_use_case
+        .tick(quest_id, milestone_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Milestone id: {} is now done", milestone_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn untick<T1, T2>(
+    State(quest_milestones_use_case): State<Arc<QuestMilestonesUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path((quest_id, milestone_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestMilestonesRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_milestones_use_case
+        .untick(quest_id, milestone_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Milestone id: {} is no longer done", milestone_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
@@ -12,14 +12,20 @@ use axum::{
 use crate::{
     application::usecases::quest_ops::QuestOpsUseCase,
     domain::{
-        repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
+        repositories::{
+            guilds::GuildsRepository, quest_ops::QuestOpsRepository,
+            quest_viewing::QuestViewingRepository,
+        },
         value_objects::quest_model::{AddQuestModel, EditQuestModel},
     },
     infrastructure::{
-        axum_http::middlewares::guild_commanders_authorization,
+        axum_http::middlewares::{guild_commanders_authorization, guild_members_authorization},
         postgres::{
             postgres_connection::PgPoolSquad,
-            repositories::{quest_ops::QuestOpsPostgres, quest_viewing::QuestViewingPostgres},
+            repositories::{
+                guilds::GuildsPostgres, quest_ops::QuestOpsPostgres,
+                quest_viewing::QuestViewingPostgres,
+            },
         },
     },
 };
@@ -27,28 +33,46 @@ use crate::{
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let quest_ops_repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
     let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+    let guilds_repository = GuildsPostgres::new(Arc::clone(&db_pool));
 
     let quest_ops_use_case = QuestOpsUseCase::new(
         Arc::new(quest_ops_repository),
         Arc::new(quest_viewing_repository),
+        Arc::new(guilds_repository),
     );
 
     Router::new()
         .route("/", post(add))
         .route("/:quest_id", patch(edit))
         .route("/:quest_id", delete(remove))
-        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .route(
+            "/:quest_id/prerequisites/:prerequisite_id",
+            post(add_prerequisite),
+        )
+        .route(
+            "/:quest_id/prerequisites/:prerequisite_id",
+            delete(remove_prerequisite),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ))
         .with_state(Arc::new(quest_ops_use_case))
 }
 
-pub async fn add<T1, T2>(
-    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+pub async fn add<T1, T2, T3>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2, T3>>>,
     Extension(guild_commander_id): Extension<i32>,
     Json(add_quest_model): Json<AddQuestModel>,
 ) -> impl IntoResponse
 where
     T1: QuestOpsRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: GuildsRepository + Send + Sync,
 {
     match quest_ops_use_case
         .add(guild_commander_id, add_quest_model)
@@ -62,8 +86,8 @@ where
     }
 }
 
-pub async fn edit<T1, T2>(
-    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+pub async fn edit<T1, T2, T3>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2, T3>>>,
     Extension(guild_commander_id): Extension<i32>,
     Path(quest_id): Path<i32>,
     Json(edit_quest_model): Json<EditQuestModel>,
@@ -71,6 +95,7 @@ pub async fn edit<T1, T2>(
 where
     T1: QuestOpsRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: GuildsRepository + Send + Sync,
 {
     match quest_ops_use_case
         .edit(quest_id, guild_commander_id, edit_quest_model)
@@ -84,14 +109,15 @@ where
     }
 }
 
-pub async fn remove<T1, T2>(
-    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+pub async fn remove<T1, T2, T3>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2, T3>>>,
     Extension(guild_commander_id): Extension<i32>,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: QuestOpsRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: GuildsRepository + Send + Sync,
 {
     match quest_ops_use_case
         .remove(quest_id, guild_commander_id)
@@ -104,3 +130,53 @@ where
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
     }
 }
+
+pub async fn add_prerequisite<T1, T2, T3>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, prerequisite_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: GuildsRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .add_prerequisite(quest_id, prerequisite_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => {
+            let response = format!(
+                "Quest {} now requires quest {} to be completed",
+                quest_id, prerequisite_id
+            );
+            (StatusCode::CREATED, response)
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn remove_prerequisite<T1, T2, T3>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, prerequisite_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: GuildsRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .remove_prerequisite(quest_id, prerequisite_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => {
+            let response = format!(
+                "Quest {} no longer requires quest {}",
+                quest_id, prerequisite_id
+            );
+            (StatusCode::OK, response)
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
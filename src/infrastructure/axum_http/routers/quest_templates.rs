@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+
+use crate::{
+    application::{
+        clock::{Clock, SystemClock},
+        usecases::{quest_ops::QuestOpsUseCase, quest_templates::QuestTemplatesUseCase},
+    },
+    domain::{
+        repositories::{
+            guilds::GuildsRepository, quest_ops::QuestOpsRepository,
+            quest_templates::QuestTemplatesRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::quest_template_model::AddQuestTemplateModel,
+    },
+    infrastructure::{
+        axum_http::middlewares::guild_commanders_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                guilds::GuildsPostgres, quest_ops::QuestOpsPostgres,
+                quest_templates::QuestTemplatesPostgres, quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+// the use case carries the quest ops generics for posting the runs
+type QuestTemplatesState<T1, T2, T3, T4, C> = Arc<QuestTemplatesUseCase<T1, T2, T3, T4, C>>;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_templates_repository = QuestTemplatesPostgres::new(Arc::clone(&db_pool));
+    let quest_ops_repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+    let guilds_repository = GuildsPostgres::new(Arc::clone(&db_pool));
+
+    let quest_ops_use_case = QuestOpsUseCase::new(
+        Arc::new(quest_ops_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(guilds_repository),
+    );
+
+    let quest_templates_use_case = QuestTemplatesUseCase::new(
+        Arc::new(quest_templates_repository),
+        Arc::new(quest_ops_use_case),
+        Arc::new(SystemClock),
+    );
+
+    Router::new()
+        .route("/", get(templates))
+        .route("/", post(add))
+        .route("/:template_id/pause", patch(pause))
+        .route("/:template_id/resume", patch(resume))
+        .route("/:template_id", delete(remove))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ))
+        .with_state(Arc::new(quest_templates_use_case))
+}
+
+pub async fn templates<T1, T2, T3, T4, C>(
+    State(quest_templates_use_case): State<QuestTemplatesState<T1, T2, T3, T4, C>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: GuildsRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    match quest_templates_use_case.templates(guild_commander_id).await {
+        Ok(quest_templates_model) => (StatusCode::OK, Json(quest_templates_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add<T1, T2, T3, T4, C>(
+    State(quest_templates_use_case): State<QuestTemplatesState<T1, T2, T3, T4, C>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_quest_template_model): Json<AddQuestTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: GuildsRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    match quest_templates_use_case
+        .add(guild_commander_id, add_quest_template_model)
+        .await
+    {
+        Ok(template_id) => (
+            StatusCode::CREATED,
+            format!("Added quest template success with id: {}", template_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn pause<T1, T2, T3, T4, C>(
+    State(quest_templates_use_case): State<QuestTemplatesState<T1, T2, T3, T4, C>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: GuildsRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    match quest_templates_use_case
+        .pause(template_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Paused quest template id: {} successfully", template_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn resume<T1, T2, T3, T4, C>(
+    State(quest_templates_use_case): State<QuestTemplatesState<T1, T2, T3, T4, C>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: GuildsRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    match quest_templates_use_case
+        .resume(template_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Resumed quest template id: {} successfully", template_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn remove<T1, T2, T3, T4, C>(
+    State(quest_templates_use_case): State<QuestTemplatesState<T1, T2, T3, T4, C>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: GuildsRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    match quest_templates_use_case
+        .remove(template_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Removed quest template id: {} successfully", template_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
@@ -3,9 +3,10 @@ use std::sync::Arc;
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
+    middleware,
     response::IntoResponse,
     routing::get,
-    Json, Router,
+    Extension, Json, Router,
 };
 
 use crate::{
@@ -14,19 +15,36 @@ use crate::{
         repositories::quest_viewing::QuestViewingRepository,
         value_objects::board_checking_filter::BoardCheckingFilter,
     },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::quest_viewing::QuestViewingPostgres,
+    infrastructure::{
+        axum_http::middlewares::{
+            adventurers_or_guild_commanders_authorization, guild_members_authorization,
+            to_guild_member,
+        },
+        jwt_authentication::jwt_model::Roles,
+        postgres::{
+            postgres_connection::PgPoolSquad, repositories::quest_viewing::QuestViewingPostgres,
+        },
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let quest_viewing_repository = QuestViewingPostgres::new(db_pool);
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
 
     let quest_viewing_use_case = QuestViewingUseCase::new(Arc::new(quest_viewing_repository));
 
+    // quests are only shown to members of their guild, the board lists the caller's guilds
     Router::new()
-        .route("/:quest_id", get(view_details))
         .route("/board-checking", get(board_checking))
+        .route("/:quest_id", get(view_details))
+        .route("/:quest_id/crew", get(crew))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_members_authorization,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_or_guild_commanders_authorization,
+        ))
         .with_state(Arc::new(quest_viewing_use_case))
 }
 
@@ -45,13 +63,39 @@ where
 
 pub async fn board_checking<T>(
     State(quest_viewing_use_case): State<Arc<QuestViewingUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Extension(role): Extension<Roles>,
     filter: Query<BoardCheckingFilter>,
 ) -> impl IntoResponse
 where
     T: QuestViewingRepository + Send + Sync,
 {
-    match quest_viewing_use_case.board_checking(&filter).await {
+    if let Err(e) = filter.status_names() {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+
+    let Some(guild_member) = to_guild_member(user_id, &role) else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+
+    match quest_viewing_use_case
+        .board_checking(&filter, guild_member)
+        .await
+    {
         Ok(quests_model) => (StatusCode::OK, Json(quests_model)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
+
+pub async fn crew<T>(
+    State(quest_viewing_use_case): State<Arc<QuestViewingUseCase<T>>>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_use_case.crew(quest_id).await {
+        Ok(crew_members_model) => (StatusCode::OK, Json(crew_members_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
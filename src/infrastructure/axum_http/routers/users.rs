@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+
+use crate::{
+    application::usecases::users::UsersUseCase,
+    domain::{
+        repositories::users::UsersRepository,
+        value_objects::user_model::{AddUserRoleModel, RegisterUserModel},
+    },
+    infrastructure::{
+        axum_http::middlewares::users_authorization,
+        postgres::{postgres_connection::PgPoolSquad, repositories::users::UsersPostgres},
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let users_repository = UsersPostgres::new(Arc::clone(&db_pool));
+
+    let users_use_case = Arc::new(UsersUseCase::new(Arc::new(users_repository)));
+
+    let me_routes = Router::new()
+        .route("/me", get(me))
+        .route("/me", delete(delete_account))
+        .route("/me/roles", post(add_role))
+        .route("/me/deactivate", patch(deactivate))
+        .route("/me/export", get(export))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            users_authorization,
+        ))
+        .with_state(Arc::clone(&users_use_case));
+
+    Router::new()
+        .route("/", post(register))
+        .with_state(users_use_case)
+        .merge(me_routes)
+}
+
+pub async fn register<T>(
+    State(users_use_case): State<Arc<UsersUseCase<T>>>,
+    Json(register_user_model): Json<RegisterUserModel>,
+) -> impl IntoResponse
+where
+    T: UsersRepository + Send + Sync,
+{
+    match users_use_case.register(register_user_model).await {
+        Ok(user_id) => (
+            StatusCode::CREATED,
+            format!("Register user id: {} successfully", user_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn me<T>(
+    State(users_use_case): State<Arc<UsersUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: UsersRepository + Send + Sync,
+{
+    match users_use_case.me(user_id).await {
+        Ok(user_model) => (StatusCode::OK, Json(user_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+// the new role shows up in the token after the next login or refresh
+pub async fn add_role<T>(
+    State(users_use_case): State<Arc<UsersUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Json(add_user_role_model): Json<AddUserRoleModel>,
+) -> impl IntoResponse
+where
+    T: UsersRepository + Send + Sync,
+{
+    let role = add_user_role_model.role.clone();
+
+    match users_use_case.add_role(user_id, add_user_role_model).await {
+        Ok(()) => (
+            StatusCode::CREATED,
+            format!("Added role {} to user id: {} successfully", role, user_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn deactivate<T>(
+    State(users_use_case): State<Arc<UsersUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: UsersRepository + Send + Sync,
+{
+    match users_use_case.deactivate(user_id).await {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Deactivated user id: {} successfully", user_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn delete_account<T>(
+    State(users_use_case): State<Arc<UsersUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: UsersRepository + Send + Sync,
+{
+    match users_use_case.delete(user_id).await {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Deleted user id: {} successfully", user_id),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn export<T>(
+    State(users_use_case): State<Arc<UsersUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: UsersRepository + Send + Sync,
+{
+    match users_use_case.export(user_id).await {
+        Ok(user_data_export_model) => {
+            (StatusCode::OK, Json(user_data_export_model)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+    Extension, Json, Router,
+};
+
+use crate::{
+    application::{
+        usecases::webhooks::WebhooksUseCase, webhook_host_resolver::WebhookHostResolver,
+    },
+    domain::{
+        repositories::{guilds::GuildsRepository, webhooks::WebhooksRepository},
+        value_objects::webhook_model::AddWebhookModel,
+    },
+    infrastructure::{
+        axum_http::middlewares::guild_commanders_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{guilds::GuildsPostgres, webhooks::WebhooksPostgres},
+        },
+        webhook_http::DnsWebhookHostResolver,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let webhooks_repository = WebhooksPostgres::new(Arc::clone(&db_pool));
+    let guilds_repository = GuildsPostgres::new(Arc::clone(&db_pool));
+
+    let webhooks_use_case = WebhooksUseCase::new(
+        Arc::new(webhooks_repository),
+        Arc::new(guilds_repository),
+        Arc::new(DnsWebhookHostResolver),
+    );
+
+    Router::new()
+        .route("/", get(webhooks).post(add))
+        .route("/:webhook_id", delete(remove))
+        .route("/:webhook_id/deliveries", get(deliveries))
+        .route(
+            "/:webhook_id/deliveries/:delivery_id/redeliver",
+            post(redeliver),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ))
+        .with_state(Arc::new(webhooks_use_case))
+}
+
+pub async fn add<T1, T2, T3>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_webhook_model): Json<AddWebhookModel>,
+) -> impl IntoResponse
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: GuildsRepository + Send + Sync,
+    T3: WebhookHostResolver + Send + Sync,
+{
+    match webhooks_use_case
+        .add(guild_commander_id, add_webhook_model)
+        .await
+    {
+        Ok(webhook_id) => (
+            StatusCode::CREATED,
+            format!("Added webhook success with id: {}", webhook_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn webhooks<T1, T2, T3>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: GuildsRepository + Send + Sync,
+    T3: WebhookHostResolver + Send + Sync,
+{
+    match webhooks_use_case.webhooks(guild_commander_id).await {
+        Ok(webhooks_model) => (StatusCode::OK, Json(webhooks_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove<T1, T2, T3>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: GuildsRepository + Send + Sync,
+    T3: WebhookHostResolver + Send + Sync,
+{
+    match webhooks_use_case
+        .remove(webhook_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Removed webhook id: {} successfully", webhook_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+pub async fn deliveries<T1, T2, T3>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: GuildsRepository + Send + Sync,
+    T3: WebhookHostResolver + Send + Sync,
+{
+    match webhooks_use_case
+        .deliveries(webhook_id, guild_commander_id)
+        .await
+    {
+        Ok(deliveries_model) => (StatusCode::OK, Json(deliveries_model)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn redeliver<T1, T2, T3>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((webhook_id, delivery_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: GuildsRepository + Send + Sync,
+    T3: WebhookHostResolver + Send + Sync,
+{
+    match webhooks_use_case
+        .redeliver(webhook_id, delivery_id, guild_commander_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Scheduled redelivery of delivery id: {}", delivery_id),
+        ),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
@@ -1,3 +1,6 @@
+use std::{collections::HashSet, fmt};
+
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,13 +12,39 @@ pub struct Passport {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String, // user_id
-    pub role: Roles,
+    pub roles: HashSet<Roles>,
+    // the quest tables still point at the per-role profiles, so their ids travel with the token
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
     pub exp: usize, //  expire token
     pub iat: usize, //  created at token
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum Roles {
     Adventurer,
     GuildCommander,
+    // moderates every account and quest, never self-assigned
+    Admin,
+}
+
+impl fmt::Display for Roles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Roles::Adventurer => write!(f, "Adventurer"),
+            Roles::GuildCommander => write!(f, "GuildCommander"),
+            Roles::Admin => write!(f, "Admin"),
+        }
+    }
+}
+
+impl Roles {
+    pub fn try_from_str(role: &str) -> Result<Self> {
+        match role {
+            "Adventurer" => Ok(Roles::Adventurer),
+            "GuildCommander" => Ok(Roles::GuildCommander),
+            "Admin" => Ok(Roles::Admin),
+            _ => Err(anyhow::anyhow!("Invalid role")),
+        }
+    }
 }
@@ -2,3 +2,5 @@ pub mod argon2_hashing;
 pub mod axum_http;
 pub mod jwt_authentication;
 pub mod postgres;
+pub mod schedulers;
+pub mod webhook_http;
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use diesel::{prelude::*, sql_types::Text};
+use futures_util::{stream, StreamExt};
+use tokio::sync::mpsc;
+use tokio_postgres::{AsyncMessage, NoTls};
+use tracing::warn;
+
+use crate::{
+    application::{board_events::BoardEventsSender, domain_events::DomainEventSubscriber},
+    domain::value_objects::domain_events::DomainEvent,
+};
+
+use super::postgres_connection::PgPoolSquad;
+
+pub const BOARD_EVENTS_CHANNEL: &str = "board_events";
+
+// publishes through postgres instead of the local channel, every replica listening on
+// BOARD_EVENTS_CHANNEL feeds the event to its own board streams
+pub struct BoardEventsNotifier {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl BoardEventsNotifier {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl DomainEventSubscriber for BoardEventsNotifier {
+    async fn handle(&self, _event_id: i32, domain_event: &DomainEvent) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::sql_query("SELECT pg_notify($1, $2)")
+            .bind::<Text, _>(BOARD_EVENTS_CHANNEL)
+            .bind::<Text, _>(serde_json::to_string(domain_event)?)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
+
+// diesel cannot receive notifications, so the listener keeps its own connection open,
+// it only returns once that connection is lost
+pub async fn listen(database_url: &str, board_events: &BoardEventsSender) -> Result<()> {
+    let (client, mut connection) = tokio_postgres::connect(database_url, NoTls).await?;
+
+    let (notifications_sender, mut notifications) = mpsc::unbounded_channel();
+
+    // the connection only makes progress while its messages are polled
+    let connection_task = tokio::spawn(async move {
+        let mut messages = stream::poll_fn(move |cx| connection.poll_message(cx));
+
+        while let Some(message) = messages.next().await {
+            match message {
+                std::result::Result::Ok(AsyncMessage::Notification(notification)) => {
+                    if notifications_sender.send(notification).is_err() {
+                        break;
+                    }
+                }
+                std::result::Result::Ok(_) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        std::result::Result::Ok(())
+    });
+
+    client
+        .batch_execute(&format!("LISTEN {}", BOARD_EVENTS_CHANNEL))
+        .await?;
+
+    while let Some(notification) = notifications.recv().await {
+        match serde_json::from_str::<DomainEvent>(notification.payload()) {
+            std::result::Result::Ok(domain_event) => {
+                let _ = board_events.send(domain_event);
+            }
+            Err(e) => warn!("Skipping unreadable board event: {}", e),
+        }
+    }
+
+    connection_task.await??;
+
+    Err(anyhow::anyhow!("The board events connection was closed"))
+}
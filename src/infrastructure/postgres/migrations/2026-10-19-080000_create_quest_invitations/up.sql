@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TABLE quest_invitations (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    "status" VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- an adventurer can only hold one pending invitation per quest
+CREATE UNIQUE INDEX quest_invitations_pending_idx ON quest_invitations (quest_id, adventurer_id)
+WHERE
+    "status" = 'Pending';
+
+ALTER TABLE
+    quest_invitations
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id),
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id),
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
@@ -0,0 +1,26 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN join_policy VARCHAR(255) NOT NULL DEFAULT 'Open';
+
+CREATE TABLE quest_applications (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    "status" VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- an adventurer can only hold one pending application per quest
+CREATE UNIQUE INDEX quest_applications_pending_idx ON quest_applications (quest_id, adventurer_id)
+WHERE
+    "status" = 'Pending';
+
+ALTER TABLE
+    quest_applications
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id),
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
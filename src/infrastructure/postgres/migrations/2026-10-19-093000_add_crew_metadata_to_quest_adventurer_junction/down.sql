@@ -0,0 +1,3 @@
+ALTER TABLE
+    quest_adventurer_junction DROP COLUMN IF EXISTS "role",
+    DROP COLUMN IF EXISTS joined_at;
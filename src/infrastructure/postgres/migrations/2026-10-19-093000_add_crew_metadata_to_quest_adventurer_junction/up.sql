@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE
+    quest_adventurer_junction
+ADD
+    COLUMN joined_at TIMESTAMP NOT NULL DEFAULT now(),
+ADD
+    COLUMN "role" VARCHAR(255);
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS quests_status_due_at_idx;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS due_at,
+    DROP COLUMN IF EXISTS starts_at;
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN starts_at TIMESTAMP,
+ADD
+    COLUMN due_at TIMESTAMP;
+
+-- the deadline scheduler looks up overdue journeys on every tick
+CREATE INDEX quests_status_due_at_idx ON quests ("status", due_at)
+WHERE
+    due_at IS NOT NULL;
@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS wallet_transactions;
+
+DROP FUNCTION IF EXISTS reject_wallet_transactions_change;
+
+ALTER TABLE
+    quest_adventurer_junction DROP COLUMN IF EXISTS reward_weight;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS reward;
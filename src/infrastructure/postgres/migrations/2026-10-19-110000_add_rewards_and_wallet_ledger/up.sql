@@ -0,0 +1,47 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN reward BIGINT NOT NULL DEFAULT 0 CHECK (reward >= 0);
+
+ALTER TABLE
+    quest_adventurer_junction
+ADD
+    COLUMN reward_weight INTEGER NOT NULL DEFAULT 1 CHECK (reward_weight > 0);
+
+CREATE TABLE wallet_transactions (
+    id SERIAL PRIMARY KEY,
+    adventurer_id INTEGER NOT NULL,
+    quest_id INTEGER,
+    amount BIGINT NOT NULL,
+    kind VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    wallet_transactions
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
+
+ALTER TABLE
+    wallet_transactions
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id);
+
+CREATE INDEX wallet_transactions_adventurer_id_idx ON wallet_transactions (adventurer_id, created_at);
+
+-- a quest pays each crew member at most once
+CREATE UNIQUE INDEX wallet_transactions_quest_reward_idx ON wallet_transactions (quest_id, adventurer_id)
+WHERE
+    kind = 'QuestReward';
+
+-- the ledger is append-only, corrections are new transactions
+CREATE FUNCTION reject_wallet_transactions_change() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'wallet_transactions is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER wallet_transactions_append_only BEFORE
+UPDATE
+    OR DELETE ON wallet_transactions FOR EACH ROW EXECUTE FUNCTION reject_wallet_transactions_change();
@@ -0,0 +1,10 @@
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS min_level,
+    DROP COLUMN IF EXISTS experience_penalty,
+    DROP COLUMN IF EXISTS experience;
+
+ALTER TABLE
+    adventurers DROP COLUMN IF EXISTS level,
+    DROP COLUMN IF EXISTS experience;
+
+DROP TABLE IF EXISTS adventurer_levels;
@@ -0,0 +1,36 @@
+-- Your SQL goes here
+-- the level curve, an adventurer reaches a level once their experience is at least min_experience
+CREATE TABLE adventurer_levels (
+    level INTEGER PRIMARY KEY CHECK (level > 0),
+    min_experience BIGINT NOT NULL UNIQUE CHECK (min_experience >= 0)
+);
+
+INSERT INTO
+    adventurer_levels (level, min_experience)
+VALUES
+    (1, 0),
+    (2, 100),
+    (3, 300),
+    (4, 600),
+    (5, 1000),
+    (6, 1500),
+    (7, 2100),
+    (8, 2800),
+    (9, 3600),
+    (10, 4500);
+
+ALTER TABLE
+    adventurers
+ADD
+    COLUMN experience BIGINT NOT NULL DEFAULT 0 CHECK (experience >= 0),
+ADD
+    COLUMN level INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE
+    quests
+ADD
+    COLUMN experience BIGINT NOT NULL DEFAULT 0 CHECK (experience >= 0),
+ADD
+    COLUMN experience_penalty BIGINT NOT NULL DEFAULT 0 CHECK (experience_penalty >= 0),
+ADD
+    COLUMN min_level INTEGER CHECK (min_level > 0);
@@ -0,0 +1,8 @@
+DROP TABLE IF EXISTS quest_tags;
+
+DROP TABLE IF EXISTS tags;
+
+DROP INDEX IF EXISTS quests_rank_idx;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS "rank";
@@ -0,0 +1,31 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN "rank" VARCHAR(255) NOT NULL DEFAULT 'E';
+
+CREATE INDEX quests_rank_idx ON quests ("rank");
+
+CREATE TABLE tags (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE quest_tags (
+    quest_id INTEGER NOT NULL,
+    tag_id INTEGER NOT NULL,
+    PRIMARY KEY (quest_id, tag_id)
+);
+
+ALTER TABLE
+    quest_tags
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE;
+
+ALTER TABLE
+    quest_tags
+ADD
+    CONSTRAINT fk_tag FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE;
+
+CREATE INDEX quest_tags_tag_id_idx ON quest_tags (tag_id);
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS quests_search_vector_idx;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS search_vector;
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+-- names weigh more than descriptions when ranking search results
+ALTER TABLE
+    quests
+ADD
+    COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', coalesce(name, '')), 'A') || setweight(
+            to_tsvector('english', coalesce(description, '')),
+            'B'
+        )
+    ) STORED;
+
+CREATE INDEX quests_search_vector_idx ON quests USING GIN (search_vector);
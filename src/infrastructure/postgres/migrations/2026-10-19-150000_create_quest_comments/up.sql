@@ -0,0 +1,19 @@
+-- Your SQL goes here
+-- author_id points at adventurers or guild_commanders depending on author_role
+CREATE TABLE quest_comments (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    author_id INTEGER NOT NULL,
+    author_role VARCHAR(255) NOT NULL,
+    body TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    deleted_at TIMESTAMP
+);
+
+CREATE INDEX quest_comments_quest_id_idx ON quest_comments (quest_id, created_at);
+
+ALTER TABLE
+    quest_comments
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id);
@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TABLE quest_milestones (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    required BOOLEAN NOT NULL DEFAULT TRUE,
+    completed_by INTEGER,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX quest_milestones_quest_id_idx ON quest_milestones (quest_id, position);
+
+ALTER TABLE
+    quest_milestones
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id);
+
+ALTER TABLE
+    quest_milestones
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (completed_by) REFERENCES adventurers(id);
@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS quest_attempt_crew;
+
+DROP TABLE IF EXISTS quest_attempts;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS max_attempts;
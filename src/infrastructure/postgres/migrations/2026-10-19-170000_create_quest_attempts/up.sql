@@ -0,0 +1,42 @@
+-- Your SQL goes here
+-- no limit when max_attempts is null
+ALTER TABLE
+    quests
+ADD
+    COLUMN max_attempts INTEGER;
+
+CREATE TABLE quest_attempts (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    attempt_number INTEGER NOT NULL,
+    outcome VARCHAR(255),
+    started_at TIMESTAMP NOT NULL DEFAULT now(),
+    ended_at TIMESTAMP,
+    UNIQUE (quest_id, attempt_number)
+);
+
+-- at most one attempt of a quest is running at a time
+CREATE UNIQUE INDEX quest_attempts_running_idx ON quest_attempts (quest_id)
+WHERE
+    ended_at IS NULL;
+
+CREATE TABLE quest_attempt_crew (
+    attempt_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    PRIMARY KEY (attempt_id, adventurer_id)
+);
+
+ALTER TABLE
+    quest_attempts
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id);
+
+ALTER TABLE
+    quest_attempt_crew
+ADD
+    CONSTRAINT fk_attempt FOREIGN KEY (attempt_id) REFERENCES quest_attempts(id) ON DELETE CASCADE;
+
+ALTER TABLE
+    quest_attempt_crew
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
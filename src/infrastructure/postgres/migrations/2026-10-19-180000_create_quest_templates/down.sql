@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS quest_templates;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS capacity;
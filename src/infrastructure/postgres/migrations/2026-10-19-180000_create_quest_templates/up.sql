@@ -0,0 +1,34 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN capacity INTEGER NOT NULL DEFAULT 4;
+
+-- cron_expression is only set for the Cron recurrence
+CREATE TABLE quest_templates (
+    id SERIAL PRIMARY KEY,
+    guild_commander_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    capacity INTEGER NOT NULL DEFAULT 4,
+    reward BIGINT NOT NULL DEFAULT 0,
+    tags TEXT [] NOT NULL DEFAULT '{}',
+    recurrence VARCHAR(255) NOT NULL,
+    cron_expression VARCHAR(255),
+    paused BOOLEAN NOT NULL DEFAULT FALSE,
+    next_run_at TIMESTAMP NOT NULL,
+    last_run_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    deleted_at TIMESTAMP
+);
+
+CREATE INDEX quest_templates_next_run_at_idx ON quest_templates (next_run_at)
+WHERE
+    deleted_at IS NULL
+    AND paused = FALSE;
+
+ALTER TABLE
+    quest_templates
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
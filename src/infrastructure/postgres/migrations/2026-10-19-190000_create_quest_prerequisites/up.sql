@@ -0,0 +1,21 @@
+-- Your SQL goes here
+-- quest_id stays locked until prerequisite_id is Completed
+CREATE TABLE quest_prerequisites (
+    quest_id INTEGER NOT NULL,
+    prerequisite_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (quest_id, prerequisite_id),
+    CHECK (quest_id <> prerequisite_id)
+);
+
+CREATE INDEX quest_prerequisites_prerequisite_id_idx ON quest_prerequisites (prerequisite_id);
+
+ALTER TABLE
+    quest_prerequisites
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id);
+
+ALTER TABLE
+    quest_prerequisites
+ADD
+    CONSTRAINT fk_prerequisite FOREIGN KEY (prerequisite_id) REFERENCES quests(id);
@@ -0,0 +1,9 @@
+ALTER TABLE
+    quest_templates DROP COLUMN IF EXISTS guild_id;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS guild_id;
+
+DROP TABLE IF EXISTS guild_memberships;
+
+DROP TABLE IF EXISTS guilds;
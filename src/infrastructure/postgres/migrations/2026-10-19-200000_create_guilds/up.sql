@@ -0,0 +1,125 @@
+-- Your SQL goes here
+CREATE TABLE guilds (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    description TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- a membership belongs to exactly one adventurer or one guild commander
+CREATE TABLE guild_memberships (
+    id SERIAL PRIMARY KEY,
+    guild_id INTEGER NOT NULL,
+    adventurer_id INTEGER,
+    guild_commander_id INTEGER,
+    role VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CHECK (num_nonnulls(adventurer_id, guild_commander_id) = 1)
+);
+
+CREATE UNIQUE INDEX guild_memberships_adventurer_idx ON guild_memberships (guild_id, adventurer_id)
+WHERE
+    adventurer_id IS NOT NULL;
+
+CREATE UNIQUE INDEX guild_memberships_guild_commander_idx ON guild_memberships (guild_id, guild_commander_id)
+WHERE
+    guild_commander_id IS NOT NULL;
+
+ALTER TABLE
+    guild_memberships
+ADD
+    CONSTRAINT fk_guild FOREIGN KEY (guild_id) REFERENCES guilds(id);
+
+ALTER TABLE
+    guild_memberships
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
+
+ALTER TABLE
+    guild_memberships
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
+
+-- everyone and everything from before guilds lands in one guild, so no quest drops off a board
+INSERT INTO
+    guilds (name, description)
+VALUES
+    ('Founders Guild', 'Members and quests from before guilds existed');
+
+INSERT INTO
+    guild_memberships (guild_id, guild_commander_id, role)
+SELECT
+    guilds.id,
+    guild_commanders.id,
+    'Officer'
+FROM
+    guilds,
+    guild_commanders;
+
+INSERT INTO
+    guild_memberships (guild_id, adventurer_id, role)
+SELECT
+    guilds.id,
+    adventurers.id,
+    'Member'
+FROM
+    guilds,
+    adventurers;
+
+ALTER TABLE
+    quests
+ADD
+    COLUMN guild_id INTEGER;
+
+UPDATE
+    quests
+SET
+    guild_id = (
+        SELECT
+            id
+        FROM
+            guilds
+    );
+
+ALTER TABLE
+    quests
+ALTER COLUMN
+    guild_id
+SET
+    NOT NULL;
+
+ALTER TABLE
+    quests
+ADD
+    CONSTRAINT fk_guild FOREIGN KEY (guild_id) REFERENCES guilds(id);
+
+CREATE INDEX quests_guild_id_idx ON quests (guild_id);
+
+ALTER TABLE
+    quest_templates
+ADD
+    COLUMN guild_id INTEGER;
+
+UPDATE
+    quest_templates
+SET
+    guild_id = (
+        SELECT
+            id
+        FROM
+            guilds
+    );
+
+ALTER TABLE
+    quest_templates
+ALTER COLUMN
+    guild_id
+SET
+    NOT NULL;
+
+ALTER TABLE
+    quest_templates
+ADD
+    CONSTRAINT fk_guild FOREIGN KEY (guild_id) REFERENCES guilds(id);
@@ -0,0 +1,30 @@
+-- Your SQL goes here
+-- max_uses and expires_at are optional, an invite without them works until it is revoked
+CREATE TABLE guild_invites (
+    id SERIAL PRIMARY KEY,
+    guild_id INTEGER NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    code VARCHAR(64) NOT NULL UNIQUE,
+    max_uses INTEGER,
+    uses INTEGER NOT NULL DEFAULT 0,
+    expires_at TIMESTAMP,
+    revoked_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CHECK (
+        max_uses IS NULL
+        OR max_uses > 0
+    )
+);
+
+CREATE INDEX guild_invites_guild_id_idx ON guild_invites (guild_id);
+
+ALTER TABLE
+    guild_invites
+ADD
+    CONSTRAINT fk_guild FOREIGN KEY (guild_id) REFERENCES guilds(id);
+
+ALTER TABLE
+    guild_invites
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
@@ -0,0 +1,17 @@
+ALTER TABLE adventurers ADD COLUMN "password" VARCHAR(255);
+ALTER TABLE guild_commanders ADD COLUMN "password" VARCHAR(255);
+
+UPDATE adventurers SET "password" = users."password"
+FROM users WHERE users.id = adventurers.user_id;
+
+UPDATE guild_commanders SET "password" = users."password"
+FROM users WHERE users.id = guild_commanders.user_id;
+
+ALTER TABLE adventurers ALTER COLUMN "password" SET NOT NULL;
+ALTER TABLE guild_commanders ALTER COLUMN "password" SET NOT NULL;
+
+ALTER TABLE adventurers DROP COLUMN user_id;
+ALTER TABLE guild_commanders DROP COLUMN user_id;
+
+DROP TABLE IF EXISTS user_roles;
+DROP TABLE IF EXISTS users;
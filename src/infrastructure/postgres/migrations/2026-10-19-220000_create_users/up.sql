@@ -0,0 +1,100 @@
+-- Your SQL goes here
+CREATE TABLE users (
+    id SERIAL PRIMARY KEY,
+    username VARCHAR(255) UNIQUE NOT NULL,
+    "password" VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE user_roles (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    "role" VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (user_id, "role")
+);
+
+ALTER TABLE user_roles
+ADD CONSTRAINT fk_user
+FOREIGN KEY (user_id)
+REFERENCES users(id)
+ON DELETE CASCADE;
+
+-- adventurers and guild_commanders stay as the per-role profiles the quest tables point at
+ALTER TABLE adventurers ADD COLUMN user_id INTEGER;
+ALTER TABLE guild_commanders ADD COLUMN user_id INTEGER;
+
+UPDATE adventurers SET user_id = nextval('users_id_seq');
+
+INSERT INTO users (id, username, "password", created_at, updated_at)
+SELECT user_id, username, "password", created_at, updated_at
+FROM adventurers;
+
+-- a guild commander and an adventurer with the same username become one user holding both
+-- roles only when they store the same password hash; the hashes are salted, so that means one
+-- was copied from the other and both logins open with the same password
+UPDATE guild_commanders SET user_id = adventurers.user_id
+FROM adventurers
+WHERE adventurers.username = guild_commanders.username
+AND adventurers."password" = guild_commanders."password";
+
+-- any other clash cannot be merged without locking one of the two out, so the migration stops
+-- and lists them; rename one profile of each pair (or copy the hash over when it is the same
+-- person) and run it again
+DO $$
+DECLARE
+    clashes TEXT;
+BEGIN
+    SELECT string_agg(
+        format('%s (adventurer id %s, guild commander id %s)',
+            guild_commanders.username, adventurers.id, guild_commanders.id),
+        ', ' ORDER BY guild_commanders.username)
+    INTO clashes
+    FROM guild_commanders
+    JOIN adventurers ON adventurers.username = guild_commanders.username
+    WHERE guild_commanders.user_id IS NULL;
+
+    IF clashes IS NOT NULL THEN
+        RAISE EXCEPTION 'Adventurers and guild commanders share these usernames with different passwords: %', clashes
+            USING HINT = 'Rename one profile of each pair, or give both the same password hash to merge them into one user';
+    END IF;
+END $$;
+
+UPDATE guild_commanders SET user_id = nextval('users_id_seq') WHERE user_id IS NULL;
+
+INSERT INTO users (id, username, "password", created_at, updated_at)
+SELECT
+    guild_commanders.user_id,
+    guild_commanders.username,
+    guild_commanders."password",
+    guild_commanders.created_at,
+    guild_commanders.updated_at
+FROM guild_commanders
+WHERE NOT EXISTS (SELECT 1 FROM users WHERE users.id = guild_commanders.user_id);
+
+INSERT INTO user_roles (user_id, "role", created_at)
+SELECT user_id, 'Adventurer', created_at FROM adventurers;
+
+INSERT INTO user_roles (user_id, "role", created_at)
+SELECT user_id, 'GuildCommander', created_at FROM guild_commanders;
+
+ALTER TABLE adventurers ALTER COLUMN user_id SET NOT NULL;
+ALTER TABLE adventurers ADD CONSTRAINT adventurers_user_id_key UNIQUE (user_id);
+
+ALTER TABLE adventurers
+ADD CONSTRAINT fk_user
+FOREIGN KEY (user_id)
+REFERENCES users(id);
+
+ALTER TABLE guild_commanders ALTER COLUMN user_id SET NOT NULL;
+ALTER TABLE guild_commanders ADD CONSTRAINT guild_commanders_user_id_key UNIQUE (user_id);
+
+ALTER TABLE guild_commanders
+ADD CONSTRAINT fk_user
+FOREIGN KEY (user_id)
+REFERENCES users(id);
+
+-- credentials live on the user now
+ALTER TABLE adventurers DROP COLUMN "password";
+ALTER TABLE guild_commanders DROP COLUMN "password";
@@ -0,0 +1,19 @@
+-- Your SQL goes here
+ALTER TABLE users ADD COLUMN disabled_at TIMESTAMP;
+
+CREATE TABLE admin_audit_logs (
+    id SERIAL PRIMARY KEY,
+    admin_user_id INTEGER NOT NULL,
+    "action" VARCHAR(255) NOT NULL,
+    target_type VARCHAR(255) NOT NULL,
+    target_id INTEGER NOT NULL,
+    details JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX admin_audit_logs_target_idx ON admin_audit_logs (target_type, target_id);
+
+ALTER TABLE admin_audit_logs
+ADD CONSTRAINT fk_admin_user
+FOREIGN KEY (admin_user_id)
+REFERENCES users(id);
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE outbox (
+    id SERIAL PRIMARY KEY,
+    event_type VARCHAR(255) NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_error TEXT,
+    delivered_at TIMESTAMP,
+    failed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX outbox_pending_idx ON outbox (next_attempt_at, id)
+WHERE delivered_at IS NULL AND failed_at IS NULL;
@@ -0,0 +1,50 @@
+-- Your SQL goes here
+CREATE TABLE webhooks (
+    id SERIAL PRIMARY KEY,
+    guild_id INTEGER NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    url VARCHAR(2048) NOT NULL,
+    secret VARCHAR(255) NOT NULL,
+    event_types TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX webhooks_guild_id_idx ON webhooks (guild_id);
+
+ALTER TABLE webhooks
+ADD CONSTRAINT fk_guild
+FOREIGN KEY (guild_id)
+REFERENCES guilds(id);
+
+ALTER TABLE webhooks
+ADD CONSTRAINT fk_guild_commander
+FOREIGN KEY (guild_commander_id)
+REFERENCES guild_commanders(id);
+
+CREATE TABLE webhook_deliveries (
+    id SERIAL PRIMARY KEY,
+    delivery_id VARCHAR(36) NOT NULL UNIQUE,
+    webhook_id INTEGER NOT NULL,
+    event_type VARCHAR(255) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(255) NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP NOT NULL DEFAULT now(),
+    response_status INTEGER,
+    last_error TEXT,
+    delivered_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX webhook_deliveries_webhook_id_idx ON webhook_deliveries (webhook_id, id);
+
+CREATE INDEX webhook_deliveries_pending_idx ON webhook_deliveries (next_attempt_at, id)
+WHERE status = 'Pending';
+
+ALTER TABLE webhook_deliveries
+ADD CONSTRAINT fk_webhook
+FOREIGN KEY (webhook_id)
+REFERENCES webhooks(id)
+ON DELETE CASCADE;
@@ -0,0 +1,40 @@
+-- Your SQL goes here
+CREATE TABLE notifications (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    event_type VARCHAR(255) NOT NULL,
+    quest_id INTEGER NOT NULL,
+    guild_id INTEGER NOT NULL,
+    message TEXT NOT NULL,
+    read_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX notifications_user_id_idx ON notifications (user_id, id);
+
+CREATE INDEX notifications_unread_idx ON notifications (user_id)
+WHERE read_at IS NULL;
+
+ALTER TABLE notifications
+ADD CONSTRAINT fk_user
+FOREIGN KEY (user_id)
+REFERENCES users(id);
+
+ALTER TABLE notifications
+ADD CONSTRAINT fk_quest
+FOREIGN KEY (quest_id)
+REFERENCES quests(id);
+
+CREATE TABLE notification_preferences (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    event_type VARCHAR(255) NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (user_id, event_type)
+);
+
+ALTER TABLE notification_preferences
+ADD CONSTRAINT fk_user
+FOREIGN KEY (user_id)
+REFERENCES users(id);
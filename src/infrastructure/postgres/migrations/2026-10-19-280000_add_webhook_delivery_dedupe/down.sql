@@ -0,0 +1 @@
+DROP INDEX IF EXISTS webhook_deliveries_webhook_id_delivery_id_idx;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE UNIQUE INDEX webhook_deliveries_webhook_id_delivery_id_idx ON webhook_deliveries (webhook_id, delivery_id);
@@ -0,0 +1,3 @@
+ALTER TABLE notifications DROP CONSTRAINT IF EXISTS notifications_user_id_outbox_event_id_key;
+
+ALTER TABLE notifications DROP COLUMN IF EXISTS outbox_event_id;
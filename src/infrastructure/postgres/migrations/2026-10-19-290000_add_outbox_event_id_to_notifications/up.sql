@@ -0,0 +1,7 @@
+-- Your SQL goes here
+-- notifications written before the column existed keep a NULL id, unique lets those through
+ALTER TABLE notifications ADD COLUMN outbox_event_id INTEGER;
+
+ALTER TABLE notifications
+ADD CONSTRAINT notifications_user_id_outbox_event_id_key
+UNIQUE (user_id, outbox_event_id);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+-- one row per subscriber that handled an outbox event, a retry only reaches the others
+CREATE TABLE outbox_subscriber_deliveries (
+    outbox_event_id INTEGER NOT NULL REFERENCES outbox(id) ON DELETE CASCADE,
+    subscriber VARCHAR(255) NOT NULL,
+    handled_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (outbox_event_id, subscriber)
+);
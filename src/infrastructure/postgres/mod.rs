@@ -1,3 +1,4 @@
+pub mod board_events;
 pub mod postgres_connection;
 pub mod repositories;
 pub mod schema;
@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    dsl::{exists, insert_into, update},
+    prelude::*,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            admin_audit_logs::{AddAdminAuditLogEntity, AdminAuditLogEntity},
+            quests::QuestEntity,
+            user_roles::UserRoleEntity,
+            users::UserEntity,
+        },
+        repositories::admin::AdminRepository,
+        value_objects::{
+            admin_model::{AdminAuditLogFilter, UserSearchFilter},
+            domain_events::DomainEvent,
+            quest_attempt_outcomes::QuestAttemptOutcomes,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::outbox::insert_outbox_event,
+        schema::{admin_audit_logs, quest_attempts, quests, user_roles, users},
+    },
+};
+
+pub struct AdminPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AdminPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AdminRepository for AdminPostgres {
+    async fn search_users(&self, filter: &UserSearchFilter) -> Result<Vec<UserEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = users::table.into_boxed();
+
+        if let Some(q) = filter.search_query() {
+            query = query.filter(users::username.ilike(format!("%{}%", q)));
+        }
+
+        if let Some(role) = &filter.role {
+            query = query.filter(exists(
+                user_roles::table
+                    .filter(user_roles::user_id.eq(users::id))
+                    .filter(user_roles::role.eq(role.to_string())),
+            ));
+        }
+
+        match filter.disabled {
+            Some(true) => query = query.filter(users::disabled_at.is_not_null()),
+            Some(false) => query = query.filter(users::disabled_at.is_null()),
+            None => {}
+        }
+
+        let result = query
+            .order_by(users::id.asc())
+            .select(UserEntity::as_select())
+            .load::<UserEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn roles_by_user_ids(&self, user_ids: Vec<i32>) -> Result<Vec<UserRoleEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = user_roles::table
+            .filter(user_roles::user_id.eq_any(user_ids))
+            .order_by(user_roles::id.asc())
+            .select(UserRoleEntity::as_select())
+            .load::<UserRoleEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn set_user_disabled_at(
+        &self,
+        user_id: i32,
+        disabled_at: Option<NaiveDateTime>,
+        add_admin_audit_log_entity: AddAdminAuditLogEntity,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            let result = update(users::table)
+                .filter(users::id.eq(user_id))
+                .set((
+                    users::disabled_at.eq(disabled_at),
+                    users::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            if result == 0 {
+                return Err(anyhow::anyhow!("User not found"));
+            }
+
+            insert_into(admin_audit_logs::table)
+                .values(add_admin_audit_log_entity)
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn find_quest(&self, quest_id: i32) -> Result<(QuestEntity, Option<NaiveDateTime>)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quests::table
+            .filter(quests::id.eq(quest_id))
+            .select((QuestEntity::as_select(), quests::deleted_at))
+            .first::<(QuestEntity, Option<NaiveDateTime>)>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Quest not found"))?;
+
+        Ok(result)
+    }
+
+    async fn force_quest_status(
+        &self,
+        quest_id: i32,
+        status: String,
+        add_admin_audit_log_entity: AddAdminAuditLogEntity,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            // a quest completed in the meantime keeps its status, the crew has been paid
+            let (guild_id, guild_commander_id) = update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::status.ne(QuestStatuses::Completed.to_string()))
+                .set((
+                    quests::status.eq(&status),
+                    quests::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .returning((quests::guild_id, quests::guild_commander_id))
+                .get_result::<(i32, i32)>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Quest not found"))?;
+
+            update(quest_attempts::table)
+                .filter(quest_attempts::quest_id.eq(quest_id))
+                .filter(quest_attempts::ended_at.is_null())
+                .set((
+                    quest_attempts::outcome.eq(QuestAttemptOutcomes::Failed.to_string()),
+                    quest_attempts::ended_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            if let Some(domain_event) =
+                DomainEvent::status_changed(&status, quest_id, guild_id, guild_commander_id)
+            {
+                insert_outbox_event(conn, &domain_event)?;
+            }
+
+            insert_into(admin_audit_logs::table)
+                .values(add_admin_audit_log_entity)
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn restore_quest(
+        &self,
+        quest_id: i32,
+        add_admin_audit_log_entity: AddAdminAuditLogEntity,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            let (guild_id, guild_commander_id) = update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_not_null())
+                .set((
+                    quests::deleted_at.eq(None::<NaiveDateTime>),
+                    quests::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .returning((quests::guild_id, quests::guild_commander_id))
+                .get_result::<(i32, i32)>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Quest not found"))?;
+
+            // the quest shows up on the board again, streams and webhooks hear about it
+            insert_outbox_event(
+                conn,
+                &DomainEvent::QuestRestored {
+                    quest_id,
+                    guild_id,
+                    guild_commander_id,
+                },
+            )?;
+
+            insert_into(admin_audit_logs::table)
+                .values(add_admin_audit_log_entity)
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn audit_logs(&self, filter: &AdminAuditLogFilter) -> Result<Vec<AdminAuditLogEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = admin_audit_logs::table.into_boxed();
+
+        if let Some(admin_user_id) = filter.admin_user_id {
+            query = query.filter(admin_audit_logs::admin_user_id.eq(admin_user_id));
+        }
+
+        if let Some(target_type) = &filter.target_type {
+            query = query.filter(admin_audit_logs::target_type.eq(target_type.to_string()));
+        }
+
+        if let Some(target_id) = filter.target_id {
+            query = query.filter(admin_audit_logs::target_id.eq(target_id));
+        }
+
+        let result = query
+            .order_by(admin_audit_logs::id.desc())
+            .select(AdminAuditLogEntity::as_select())
+            .load::<AdminAuditLogEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+}
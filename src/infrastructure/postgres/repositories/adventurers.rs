@@ -3,15 +3,13 @@ use std::sync::Arc;
 use anyhow::{Ok, Result};
 use axum::async_trait;
 use diesel::{
-    dsl::insert_into,
     query_dsl::methods::{FilterDsl, SelectDsl},
     ExpressionMethods, RunQueryDsl, SelectableHelper,
 };
 
 use crate::{
     domain::{
-        entities::adventurers::{AdventurerEntity, RegisterAdventurerEntity},
-        repositories::adventurers::AdventurersRepository,
+        entities::adventurers::AdventurerEntity, repositories::adventurers::AdventurersRepository,
     },
     infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::adventurers},
 };
@@ -29,22 +27,11 @@ impl AdventurersPostgres {
 
 #[async_trait]
 impl AdventurersRepository for AdventurersPostgres {
-    async fn register(&self, register_adventurer_entity: RegisterAdventurerEntity) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        let result = insert_into(adventurers::table)
-            .values(register_adventurer_entity)
-            .returning(adventurers::id)
-            .get_result::<i32>(&mut conn)?;
-
-        Ok(result)
-    }
-
-    async fn find_by_username(&self, username: String) -> Result<AdventurerEntity> {
+    async fn find_by_id(&self, adventurer_id: i32) -> Result<AdventurerEntity> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let result = adventurers::table
-            .filter(adventurers::username.eq(username))
+            .filter(adventurers::id.eq(adventurer_id))
             .select(AdventurerEntity::as_select())
             .first(&mut conn)?;
 
@@ -2,15 +2,36 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use axum::async_trait;
-use diesel::{delete, dsl::insert_into, ExpressionMethods, RunQueryDsl};
+use diesel::{
+    delete, dsl::insert_into, Connection, ExpressionMethods, OptionalExtension, QueryDsl,
+    RunQueryDsl, SelectableHelper,
+};
 
 use crate::{
     domain::{
+        entities::{
+            quest_applications::{AddQuestApplicationEntity, QuestApplicationEntity},
+            quest_invitations::{AddQuestInvitationEntity, QuestInvitationEntity},
+            quest_waitlist_entries::{AddQuestWaitlistEntryEntity, QuestWaitlistEntryEntity},
+            quests::QuestEntity,
+        },
         repositories::crew_switchboard::CrewSwitchboardRepository,
-        value_objects::quest_adventurer_junction::QuestAdventurerJunction,
+        value_objects::{
+            domain_events::DomainEvent,
+            quest_adventurer_junction::QuestAdventurerJunction,
+            quest_application_statuses::QuestApplicationStatuses,
+            quest_invitation_statuses::QuestInvitationStatuses,
+            quest_join_outcomes::QuestJoinOutcomes,
+            quest_statuses::{QuestStatuses, JOINABLE_QUEST_STATUSES},
+        },
     },
     infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, schema::quest_adventurer_junction,
+        postgres_connection::PgPoolSquad,
+        repositories::outbox::insert_outbox_event,
+        schema::{
+            adventurers, guild_memberships, quest_adventurer_junction, quest_applications,
+            quest_invitations, quest_prerequisites, quest_waitlist_entries, quests,
+        },
     },
 };
 
@@ -26,37 +47,590 @@ impl CrewSwitchBoardPostgres {
 
 #[async_trait]
 impl CrewSwitchboardRepository for CrewSwitchBoardPostgres {
-    async fn join(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+    async fn join(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        domain_event: DomainEvent,
+    ) -> Result<QuestJoinOutcomes> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        match insert_into(quest_adventurer_junction::table)
-            .values(junction_body)
-            .execute(&mut conn)
-        {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                return match e {
-                    diesel::result::Error::DatabaseError(
-                        diesel::result::DatabaseErrorKind::UniqueViolation,
-                        _,
-                    ) => Err(anyhow::anyhow!("You already joined quest")),
-                    _ => Err(anyhow::anyhow!(e.to_string())),
+        conn.transaction::<QuestJoinOutcomes, anyhow::Error, _>(|conn| {
+            let quest = lock_quest(conn, junction_body.quest_id)?;
+
+            let adventurers_count = crew_count(conn, quest.id)?;
+
+            let waitlist_count = quest_waitlist_entries::table
+                .filter(quest_waitlist_entries::quest_id.eq(quest.id))
+                .count()
+                .get_result::<i64>(conn)?;
+
+            // a free seat goes to whoever has been waiting and qualifies, a newcomer lines up
+            // behind them and only gets it when nobody ahead can take it
+            if adventurers_count >= quest.capacity as i64 || waitlist_count > 0 {
+                let position = enqueue_adventurer(
+                    conn,
+                    AddQuestWaitlistEntryEntity {
+                        quest_id: junction_body.quest_id,
+                        adventurer_id: junction_body.adventurer_id,
+                        created_at: chrono::Utc::now().naive_utc(),
+                    },
+                )?;
+
+                if adventurers_count >= quest.capacity as i64 {
+                    return Ok(QuestJoinOutcomes::Waitlisted { position });
                 }
+
+                promote_waitlist_head(conn, quest.id)?;
+
+                let promoted = diesel::select(diesel::dsl::exists(
+                    quest_adventurer_junction::table
+                        .filter(quest_adventurer_junction::quest_id.eq(quest.id))
+                        .filter(
+                            quest_adventurer_junction::adventurer_id
+                                .eq(junction_body.adventurer_id),
+                        ),
+                ))
+                .get_result::<bool>(conn)?;
+
+                if promoted {
+                    return Ok(QuestJoinOutcomes::Joined);
+                }
+
+                let position = waitlist_position(conn, quest.id, junction_body.adventurer_id)?;
+
+                return Ok(QuestJoinOutcomes::Waitlisted { position });
             }
+
+            insert_into(quest_adventurer_junction::table)
+                .values(junction_body)
+                .execute(conn)
+                .map_err(joining_error)?;
+
+            insert_outbox_event(conn, &domain_event)?;
+
+            Ok(QuestJoinOutcomes::Joined)
+        })
+    }
+    async fn leave(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        domain_event: DomainEvent,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            leave_quest(
+                conn,
+                junction_body.quest_id,
+                junction_body.adventurer_id,
+                &domain_event,
+            )
+        })
+    }
+    async fn enqueue(
+        &self,
+        add_quest_waitlist_entry_entity: AddQuestWaitlistEntryEntity,
+    ) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<i64, anyhow::Error, _>(|conn| {
+            enqueue_adventurer(conn, add_quest_waitlist_entry_entity)
+        })
+    }
+    async fn waitlist_position(&self, quest_id: i32, adventurer_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        waitlist_position(&mut conn, quest_id, adventurer_id)
+    }
+    async fn dequeue(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = delete(quest_waitlist_entries::table)
+            .filter(quest_waitlist_entries::quest_id.eq(quest_id))
+            .filter(quest_waitlist_entries::adventurer_id.eq(adventurer_id))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("You are not on the waitlist of quest"));
+        }
+
+        Ok(())
+    }
+    async fn adventurer_level(&self, adventurer_id: i32) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = adventurers::table
+            .filter(adventurers::id.eq(adventurer_id))
+            .select(adventurers::level)
+            .first::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn is_guild_member(&self, guild_id: i32, adventurer_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::select(diesel::dsl::exists(
+            guild_memberships::table
+                .filter(guild_memberships::guild_id.eq(guild_id))
+                .filter(guild_memberships::adventurer_id.eq(adventurer_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn assign_role(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+        role: Option<String>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_adventurer_junction::table)
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .set(quest_adventurer_junction::role.eq(role))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("Adventurer is not in the crew of quest"));
         }
+
+        Ok(())
     }
-    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+    async fn set_reward_weight(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+        reward_weight: i32,
+    ) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = delete(quest_adventurer_junction::table)
-            .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
-            .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+        let result = diesel::update(quest_adventurer_junction::table)
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .set(quest_adventurer_junction::reward_weight.eq(reward_weight))
             .execute(&mut conn)?;
 
         if result == 0 {
-            return Err(anyhow::anyhow!("Failed to leave quest"));
+            return Err(anyhow::anyhow!("Adventurer is not in the crew of quest"));
         }
 
         Ok(())
     }
+    async fn invite(&self, add_quest_invitation_entity: AddQuestInvitationEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        match insert_into(quest_invitations::table)
+            .values(add_quest_invitation_entity)
+            .returning(quest_invitations::id)
+            .get_result::<i32>(&mut conn)
+        {
+            Ok(invitation_id) => Ok(invitation_id),
+            Err(diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            )) => Err(anyhow::anyhow!("Adventurer already invited to quest")),
+            Err(e) => Err(anyhow::anyhow!(e.to_string())),
+        }
+    }
+    async fn find_invitation(&self, invitation_id: i32) -> Result<QuestInvitationEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_invitations::table
+            .filter(quest_invitations::id.eq(invitation_id))
+            .select(QuestInvitationEntity::as_select())
+            .first::<QuestInvitationEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn pending_invitations(&self, adventurer_id: i32) -> Result<Vec<QuestInvitationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_invitations::table
+            .filter(quest_invitations::adventurer_id.eq(adventurer_id))
+            .filter(quest_invitations::status.eq(QuestInvitationStatuses::Pending.to_string()))
+            .select(QuestInvitationEntity::as_select())
+            .order_by(quest_invitations::created_at.desc())
+            .load::<QuestInvitationEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn accept_invitation(
+        &self,
+        invitation_id: i32,
+        junction_body: QuestAdventurerJunction,
+        domain_event: DomainEvent,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // the invitation and the crew row must change together, otherwise a failed insert
+        // would leave an accepted invitation without a junction row
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            check_free_seat(conn, junction_body.quest_id)?;
+
+            respond_invitation(conn, invitation_id, QuestInvitationStatuses::Accepted)?;
+
+            insert_into(quest_adventurer_junction::table)
+                .values(junction_body)
+                .execute(conn)
+                .map_err(joining_error)?;
+
+            insert_outbox_event(conn, &domain_event)
+        })
+    }
+    async fn decline_invitation(&self, invitation_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        respond_invitation(&mut conn, invitation_id, QuestInvitationStatuses::Declined)
+    }
+    async fn apply(&self, add_quest_application_entity: AddQuestApplicationEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        match insert_into(quest_applications::table)
+            .values(add_quest_application_entity)
+            .returning(quest_applications::id)
+            .get_result::<i32>(&mut conn)
+        {
+            Ok(application_id) => Ok(application_id),
+            Err(diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            )) => Err(anyhow::anyhow!("You already applied to quest")),
+            Err(e) => Err(anyhow::anyhow!(e.to_string())),
+        }
+    }
+    async fn find_application(&self, application_id: i32) -> Result<QuestApplicationEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_applications::table
+            .filter(quest_applications::id.eq(application_id))
+            .select(QuestApplicationEntity::as_select())
+            .first::<QuestApplicationEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn pending_applications(&self, quest_id: i32) -> Result<Vec<QuestApplicationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_applications::table
+            .filter(quest_applications::quest_id.eq(quest_id))
+            .filter(quest_applications::status.eq(QuestApplicationStatuses::Pending.to_string()))
+            .select(QuestApplicationEntity::as_select())
+            .order_by(quest_applications::created_at.asc())
+            .load::<QuestApplicationEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn approve_application(
+        &self,
+        application_id: i32,
+        junction_body: QuestAdventurerJunction,
+        domain_event: DomainEvent,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            check_free_seat(conn, junction_body.quest_id)?;
+
+            respond_application(conn, application_id, QuestApplicationStatuses::Approved)?;
+
+            insert_into(quest_adventurer_junction::table)
+                .values(junction_body)
+                .execute(conn)
+                .map_err(joining_error)?;
+
+            insert_outbox_event(conn, &domain_event)
+        })
+    }
+    async fn reject_application(&self, application_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        respond_application(
+            &mut conn,
+            application_id,
+            QuestApplicationStatuses::Rejected,
+        )
+    }
+    async fn withdraw_application(&self, application_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        respond_application(
+            &mut conn,
+            application_id,
+            QuestApplicationStatuses::Withdrawn,
+        )
+    }
+}
+
+// takes the adventurer off the crew and hands the seat to the waitlist, for callers that
+// leave quests as part of a bigger transaction
+pub fn leave_quest(
+    conn: &mut diesel::PgConnection,
+    quest_id: i32,
+    adventurer_id: i32,
+    domain_event: &DomainEvent,
+) -> Result<()> {
+    let result = delete(quest_adventurer_junction::table)
+        .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .execute(conn)?;
+
+    if result == 0 {
+        return Err(anyhow::anyhow!("Failed to leave quest"));
+    }
+
+    insert_outbox_event(conn, domain_event)?;
+
+    promote_waitlist_head(conn, quest_id)
+}
+
+fn promote_waitlist_head(conn: &mut diesel::PgConnection, quest_id: i32) -> Result<()> {
+    let quest = lock_quest(conn, quest_id)?;
+
+    let joinable = JOINABLE_QUEST_STATUSES
+        .iter()
+        .any(|status| quest.status == status.to_string());
+
+    if !joinable || !prerequisites_completed(conn, quest_id)? {
+        return Ok(());
+    }
+
+    let mut adventurers_count = crew_count(conn, quest_id)?;
+
+    if adventurers_count >= quest.capacity as i64 {
+        return Ok(());
+    }
+
+    let entries = quest_waitlist_entries::table
+        .filter(quest_waitlist_entries::quest_id.eq(quest_id))
+        .order_by(quest_waitlist_entries::id.asc())
+        .select(QuestWaitlistEntryEntity::as_select())
+        .for_update()
+        .load::<QuestWaitlistEntryEntity>(conn)?;
+
+    // an adventurer who left the guild or is below the level keeps their place in line,
+    // the next ones who qualify take the free seats
+    for entry in entries {
+        let is_guild_member = diesel::select(diesel::dsl::exists(
+            guild_memberships::table
+                .filter(guild_memberships::guild_id.eq(quest.guild_id))
+                .filter(guild_memberships::adventurer_id.eq(entry.adventurer_id)),
+        ))
+        .get_result::<bool>(conn)?;
+
+        if !is_guild_member {
+            continue;
+        }
+
+        if let Some(min_level) = quest.min_level {
+            let level = adventurers::table
+                .filter(adventurers::id.eq(entry.adventurer_id))
+                .select(adventurers::level)
+                .first::<i32>(conn)?;
+
+            if level < min_level {
+                continue;
+            }
+        }
+
+        delete(quest_waitlist_entries::table)
+            .filter(quest_waitlist_entries::id.eq(entry.id))
+            .execute(conn)?;
+
+        insert_into(quest_adventurer_junction::table)
+            .values(QuestAdventurerJunction::new(quest_id, entry.adventurer_id))
+            .execute(conn)
+            .map_err(joining_error)?;
+
+        insert_adventurer_joined_event(conn, quest_id, entry.adventurer_id)?;
+
+        adventurers_count += 1;
+
+        if adventurers_count >= quest.capacity as i64 {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+// every path that takes a crew seat locks the quest row first and counts the crew after, so
+// concurrent joins, accepts, approvals and promotions cannot both see the same free seat
+fn lock_quest(conn: &mut diesel::PgConnection, quest_id: i32) -> Result<QuestEntity> {
+    let quest = quests::table
+        .filter(quests::id.eq(quest_id))
+        .select(QuestEntity::as_select())
+        .for_update()
+        .first::<QuestEntity>(conn)?;
+
+    Ok(quest)
+}
+
+// for the paths that seat one chosen adventurer, they get an error instead of a waitlist place
+fn check_free_seat(conn: &mut diesel::PgConnection, quest_id: i32) -> Result<()> {
+    let quest = lock_quest(conn, quest_id)?;
+
+    if crew_count(conn, quest_id)? >= quest.capacity as i64 {
+        return Err(anyhow::anyhow!("The quest has adventures full"));
+    }
+
+    Ok(())
+}
+
+fn crew_count(conn: &mut diesel::PgConnection, quest_id: i32) -> Result<i64> {
+    let result = quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    Ok(result)
+}
+
+fn enqueue_adventurer(
+    conn: &mut diesel::PgConnection,
+    add_quest_waitlist_entry_entity: AddQuestWaitlistEntryEntity,
+) -> Result<i64> {
+    let quest_id = add_quest_waitlist_entry_entity.quest_id;
+    let adventurer_id = add_quest_waitlist_entry_entity.adventurer_id;
+
+    let already_joined = quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    if already_joined > 0 {
+        return Err(anyhow::anyhow!("You already joined quest"));
+    }
+
+    match insert_into(quest_waitlist_entries::table)
+        .values(add_quest_waitlist_entry_entity)
+        .execute(conn)
+    {
+        Ok(_) => {}
+        Err(diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _,
+        )) => return Err(anyhow::anyhow!("You are already on the waitlist of quest")),
+        Err(e) => return Err(anyhow::anyhow!(e.to_string())),
+    }
+
+    waitlist_position(conn, quest_id, adventurer_id)
+}
+
+// the same lock the board's joinable filter and CrewSwitchboardUseCase::join apply
+fn prerequisites_completed(conn: &mut diesel::PgConnection, quest_id: i32) -> Result<bool> {
+    let prerequisite_ids = quest_prerequisites::table
+        .filter(quest_prerequisites::quest_id.eq(quest_id))
+        .select(quest_prerequisites::prerequisite_id);
+
+    let unfinished = quests::table
+        .filter(quests::id.eq_any(prerequisite_ids))
+        .filter(quests::deleted_at.is_null())
+        .filter(quests::status.ne(QuestStatuses::Completed.to_string()))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    Ok(unfinished == 0)
+}
+
+fn waitlist_position(
+    conn: &mut diesel::PgConnection,
+    quest_id: i32,
+    adventurer_id: i32,
+) -> Result<i64> {
+    let entry = quest_waitlist_entries::table
+        .filter(quest_waitlist_entries::quest_id.eq(quest_id))
+        .filter(quest_waitlist_entries::adventurer_id.eq(adventurer_id))
+        .select(QuestWaitlistEntryEntity::as_select())
+        .first::<QuestWaitlistEntryEntity>(conn)
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("You are not on the waitlist of quest"))?;
+
+    let position = quest_waitlist_entries::table
+        .filter(quest_waitlist_entries::quest_id.eq(quest_id))
+        .filter(quest_waitlist_entries::id.le(entry.id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    Ok(position)
+}
+
+fn respond_invitation(
+    conn: &mut diesel::PgConnection,
+    invitation_id: i32,
+    status: QuestInvitationStatuses,
+) -> Result<()> {
+    let result = diesel::update(quest_invitations::table)
+        .filter(quest_invitations::id.eq(invitation_id))
+        .filter(quest_invitations::status.eq(QuestInvitationStatuses::Pending.to_string()))
+        .set((
+            quest_invitations::status.eq(status.to_string()),
+            quest_invitations::updated_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .execute(conn)?;
+
+    if result == 0 {
+        return Err(anyhow::anyhow!("Invitation not found"));
+    }
+
+    Ok(())
+}
+
+fn respond_application(
+    conn: &mut diesel::PgConnection,
+    application_id: i32,
+    status: QuestApplicationStatuses,
+) -> Result<()> {
+    let result = diesel::update(quest_applications::table)
+        .filter(quest_applications::id.eq(application_id))
+        .filter(quest_applications::status.eq(QuestApplicationStatuses::Pending.to_string()))
+        .set((
+            quest_applications::status.eq(status.to_string()),
+            quest_applications::updated_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .execute(conn)?;
+
+    if result == 0 {
+        return Err(anyhow::anyhow!("Application not found"));
+    }
+
+    Ok(())
+}
+
+fn quest_guild_id(conn: &mut diesel::PgConnection, quest_id: i32) -> Result<i32> {
+    let result = quests::table
+        .filter(quests::id.eq(quest_id))
+        .select(quests::guild_id)
+        .first::<i32>(conn)?;
+
+    Ok(result)
+}
+
+fn insert_adventurer_joined_event(
+    conn: &mut diesel::PgConnection,
+    quest_id: i32,
+    adventurer_id: i32,
+) -> Result<()> {
+    let guild_id = quest_guild_id(conn, quest_id)?;
+
+    insert_outbox_event(
+        conn,
+        &DomainEvent::AdventurerJoined {
+            quest_id,
+            guild_id,
+            adventurer_id,
+        },
+    )
+}
+
+fn joining_error(e: diesel::result::Error) -> anyhow::Error {
+    match e {
+        diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _,
+        ) => anyhow::anyhow!("You already joined quest"),
+        _ => anyhow::anyhow!(e.to_string()),
+    }
 }
@@ -0,0 +1,450 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use diesel::{
+    dsl::{delete, insert_into, update},
+    pg::Pg,
+    prelude::*,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            guild_invites::{AddGuildInviteEntity, GuildInviteEntity},
+            guild_memberships::{AddGuildMembershipEntity, GuildMembershipEntity},
+            guilds::{AddGuildEntity, GuildEntity},
+        },
+        repositories::guilds::GuildsRepository,
+        value_objects::{
+            domain_events::DomainEvent,
+            guild_membership_model::{GuildMember, GuildResource},
+            guild_membership_roles::GuildMembershipRoles,
+            quest_application_statuses::QuestApplicationStatuses,
+            quest_invitation_statuses::QuestInvitationStatuses,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::crew_switchboard::leave_quest,
+        schema::{
+            adventurers, guild_commanders, guild_invites, guild_memberships, guilds,
+            quest_adventurer_junction, quest_applications, quest_comments, quest_invitations,
+            quest_milestones, quest_waitlist_entries, quests,
+        },
+    },
+};
+
+pub struct GuildsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl GuildsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl GuildsRepository for GuildsPostgres {
+    async fn add(&self, add_guild_entity: AddGuildEntity, guild_commander_id: i32) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let guild_id = insert_into(guilds::table)
+                .values(add_guild_entity)
+                .returning(guilds::id)
+                .get_result::<i32>(conn)?;
+
+            insert_into(guild_memberships::table)
+                .values(
+                    GuildMember::GuildCommander(guild_commander_id)
+                        .to_membership_entity(guild_id, &GuildMembershipRoles::Owner),
+                )
+                .execute(conn)?;
+
+            Ok(guild_id)
+        })?;
+
+        Ok(result)
+    }
+    async fn find(&self, guild_id: i32) -> Result<GuildEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guilds::table
+            .filter(guilds::id.eq(guild_id))
+            .select(GuildEntity::as_select())
+            .first::<GuildEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Guild not found"))?;
+
+        Ok(result)
+    }
+    async fn resource_guild_id(&self, resource: GuildResource) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // rows hanging off a quest are in the guild of that quest
+        let quest_id = match resource {
+            GuildResource::Guild(guild_id) => {
+                let result = guilds::table
+                    .filter(guilds::id.eq(guild_id))
+                    .select(guilds::id)
+                    .first::<i32>(&mut conn)
+                    .optional()?;
+
+                return Ok(result);
+            }
+            GuildResource::GuildMembership(membership_id) => {
+                let result = guild_memberships::table
+                    .filter(guild_memberships::id.eq(membership_id))
+                    .select(guild_memberships::guild_id)
+                    .first::<i32>(&mut conn)
+                    .optional()?;
+
+                return Ok(result);
+            }
+            GuildResource::GuildInvite(invite_id) => {
+                let result = guild_invites::table
+                    .filter(guild_invites::id.eq(invite_id))
+                    .select(guild_invites::guild_id)
+                    .first::<i32>(&mut conn)
+                    .optional()?;
+
+                return Ok(result);
+            }
+            GuildResource::Quest(quest_id) => Some(quest_id),
+            GuildResource::QuestComment(comment_id) => quest_comments::table
+                .filter(quest_comments::id.eq(comment_id))
+                .filter(quest_comments::deleted_at.is_null())
+                .select(quest_comments::quest_id)
+                .first::<i32>(&mut conn)
+                .optional()?,
+            GuildResource::QuestMilestone(milestone_id) => quest_milestones::table
+                .filter(quest_milestones::id.eq(milestone_id))
+                .select(quest_milestones::quest_id)
+                .first::<i32>(&mut conn)
+                .optional()?,
+            GuildResource::QuestInvitation(invitation_id) => quest_invitations::table
+                .filter(quest_invitations::id.eq(invitation_id))
+                .select(quest_invitations::quest_id)
+                .first::<i32>(&mut conn)
+                .optional()?,
+            GuildResource::QuestApplication(application_id) => quest_applications::table
+                .filter(quest_applications::id.eq(application_id))
+                .select(quest_applications::quest_id)
+                .first::<i32>(&mut conn)
+                .optional()?,
+        };
+
+        let Some(quest_id) = quest_id else {
+            return Ok(None);
+        };
+
+        let result = quests::table
+            .filter(quests::id.eq(quest_id))
+            .filter(quests::deleted_at.is_null())
+            .select(quests::guild_id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+    async fn guilds_by_member(&self, member: GuildMember) -> Result<Vec<(GuildEntity, String)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let guild_ids = memberships_of(member).select(guild_memberships::guild_id);
+
+        let memberships = memberships_of(member)
+            .select((guild_memberships::guild_id, guild_memberships::role))
+            .load::<(i32, String)>(&mut conn)?;
+
+        let guilds = guilds::table
+            .filter(guilds::id.eq_any(guild_ids))
+            .order_by(guilds::id.asc())
+            .select(GuildEntity::as_select())
+            .load::<GuildEntity>(&mut conn)?;
+
+        let result = guilds
+            .into_iter()
+            .filter_map(|guild| {
+                let role = memberships
+                    .iter()
+                    .find(|(guild_id, _)| *guild_id == guild.id)?
+                    .1
+                    .clone();
+
+                Some((guild, role))
+            })
+            .collect();
+
+        Ok(result)
+    }
+    async fn membership(
+        &self,
+        guild_id: i32,
+        member: GuildMember,
+    ) -> Result<Option<GuildMembershipEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = memberships_of(member)
+            .filter(guild_memberships::guild_id.eq(guild_id))
+            .select(GuildMembershipEntity::as_select())
+            .first::<GuildMembershipEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+    async fn add_member(
+        &self,
+        add_guild_membership_entity: AddGuildMembershipEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(guild_memberships::table)
+            .values(add_guild_membership_entity)
+            .returning(guild_memberships::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn members_by_guild_id(
+        &self,
+        guild_id: i32,
+    ) -> Result<Vec<(GuildMembershipEntity, String)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = guild_memberships::table
+            .left_join(adventurers::table)
+            .left_join(guild_commanders::table)
+            .filter(guild_memberships::guild_id.eq(guild_id))
+            .order_by(guild_memberships::id.asc())
+            .select((
+                GuildMembershipEntity::as_select(),
+                adventurers::username.nullable(),
+                guild_commanders::username.nullable(),
+            ))
+            .load::<(GuildMembershipEntity, Option<String>, Option<String>)>(&mut conn)?;
+
+        let result = results
+            .into_iter()
+            .map(
+                |(membership, adventurer_username, guild_commander_username)| {
+                    let username = adventurer_username
+                        .or(guild_commander_username)
+                        .unwrap_or_default();
+
+                    (membership, username)
+                },
+            )
+            .collect();
+
+        Ok(result)
+    }
+    async fn find_membership(&self, membership_id: i32) -> Result<GuildMembershipEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guild_memberships::table
+            .filter(guild_memberships::id.eq(membership_id))
+            .select(GuildMembershipEntity::as_select())
+            .first::<GuildMembershipEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Guild member not found"))?;
+
+        Ok(result)
+    }
+    async fn change_member_role(&self, membership_id: i32, role: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        update(guild_memberships::table)
+            .filter(guild_memberships::id.eq(membership_id))
+            .set((
+                guild_memberships::role.eq(role),
+                guild_memberships::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn remove_member(&self, guild_id: i32, membership_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // an adventurer outside the guild must not be promoted, approved or accept an invitation
+        // into one of its quests later on
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            let adventurer_id = delete(guild_memberships::table)
+                .filter(guild_memberships::id.eq(membership_id))
+                .filter(guild_memberships::guild_id.eq(guild_id))
+                .returning(guild_memberships::adventurer_id)
+                .get_result::<Option<i32>>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Guild member not found"))?;
+
+            let Some(adventurer_id) = adventurer_id else {
+                return Ok(());
+            };
+
+            let guild_quest_ids = quests::table
+                .filter(quests::guild_id.eq(guild_id))
+                .select(quests::id);
+
+            delete(quest_waitlist_entries::table)
+                .filter(quest_waitlist_entries::adventurer_id.eq(adventurer_id))
+                .filter(quest_waitlist_entries::quest_id.eq_any(guild_quest_ids))
+                .execute(conn)?;
+
+            delete(quest_applications::table)
+                .filter(quest_applications::adventurer_id.eq(adventurer_id))
+                .filter(quest_applications::quest_id.eq_any(guild_quest_ids))
+                .filter(
+                    quest_applications::status.eq(QuestApplicationStatuses::Pending.to_string()),
+                )
+                .execute(conn)?;
+
+            delete(quest_invitations::table)
+                .filter(quest_invitations::adventurer_id.eq(adventurer_id))
+                .filter(quest_invitations::quest_id.eq_any(guild_quest_ids))
+                .filter(quest_invitations::status.eq(QuestInvitationStatuses::Pending.to_string()))
+                .execute(conn)?;
+
+            // the seats go to the waitlist only once the membership and the adventurer's own
+            // entries are gone
+            let open_quest_ids = quest_adventurer_junction::table
+                .inner_join(quests::table)
+                .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+                .filter(quests::guild_id.eq(guild_id))
+                .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+                .filter(quests::deleted_at.is_null())
+                .select(quests::id)
+                .load::<i32>(conn)?;
+
+            for quest_id in open_quest_ids {
+                leave_quest(
+                    conn,
+                    quest_id,
+                    adventurer_id,
+                    &DomainEvent::AdventurerLeft {
+                        quest_id,
+                        guild_id,
+                        adventurer_id,
+                    },
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+    async fn add_invite(
+        &self,
+        add_guild_invite_entity: AddGuildInviteEntity,
+    ) -> Result<GuildInviteEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(guild_invites::table)
+            .values(add_guild_invite_entity)
+            .returning(GuildInviteEntity::as_returning())
+            .get_result::<GuildInviteEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn find_invite(&self, invite_id: i32) -> Result<GuildInviteEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guild_invites::table
+            .filter(guild_invites::id.eq(invite_id))
+            .select(GuildInviteEntity::as_select())
+            .first::<GuildInviteEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Invite not found"))?;
+
+        Ok(result)
+    }
+    async fn find_invite_by_code(&self, code: String) -> Result<GuildInviteEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guild_invites::table
+            .filter(guild_invites::code.eq(code))
+            .select(GuildInviteEntity::as_select())
+            .first::<GuildInviteEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Invite not found"))?;
+
+        Ok(result)
+    }
+    async fn invites_by_guild_id(&self, guild_id: i32) -> Result<Vec<GuildInviteEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guild_invites::table
+            .filter(guild_invites::guild_id.eq(guild_id))
+            .filter(guild_invites::revoked_at.is_null())
+            .order_by(guild_invites::id.asc())
+            .select(GuildInviteEntity::as_select())
+            .load::<GuildInviteEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn revoke_invite(&self, invite_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = update(guild_invites::table)
+            .filter(guild_invites::id.eq(invite_id))
+            .filter(guild_invites::revoked_at.is_null())
+            .set((
+                guild_invites::revoked_at.eq(chrono::Utc::now().naive_utc()),
+                guild_invites::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("Invite not found"));
+        }
+
+        Ok(())
+    }
+    async fn accept_invite(
+        &self,
+        invite_id: i32,
+        add_guild_membership_entity: AddGuildMembershipEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            // the use is counted with a guarded update so concurrent accepts cannot overshoot
+            let counted = update(guild_invites::table)
+                .filter(guild_invites::id.eq(invite_id))
+                .filter(guild_invites::revoked_at.is_null())
+                .filter(
+                    guild_invites::max_uses
+                        .is_null()
+                        .or(guild_invites::uses.nullable().lt(guild_invites::max_uses)),
+                )
+                .set((
+                    guild_invites::uses.eq(guild_invites::uses + 1),
+                    guild_invites::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            if counted == 0 {
+                return Err(anyhow::anyhow!("The invite has been used up"));
+            }
+
+            let result = insert_into(guild_memberships::table)
+                .values(add_guild_membership_entity)
+                .returning(guild_memberships::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(result)
+        })
+    }
+}
+
+fn memberships_of(member: GuildMember) -> guild_memberships::BoxedQuery<'static, Pg> {
+    match member {
+        GuildMember::Adventurer(adventurer_id) => guild_memberships::table
+            .filter(guild_memberships::adventurer_id.eq(adventurer_id))
+            .into_boxed(),
+        GuildMember::GuildCommander(guild_commander_id) => guild_memberships::table
+            .filter(guild_memberships::guild_commander_id.eq(guild_commander_id))
+            .into_boxed(),
+    }
+}
@@ -2,14 +2,33 @@ use std::sync::Arc;
 
 use anyhow::{Ok, Result};
 use axum::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl};
+use diesel::{
+    insert_into,
+    sql_types::{BigInt, Integer},
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, QueryResult,
+    RunQueryDsl,
+};
 
 use crate::{
     domain::{
+        entities::{
+            quest_attempts::{AddQuestAttemptEntity, QuestAttemptCrewEntity},
+            wallet_transactions::AddWalletTransactionEntity,
+        },
         repositories::journey_ledger::JourneyLedgerRepository,
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{
+            domain_events::DomainEvent, quest_attempt_outcomes::QuestAttemptOutcomes,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::outbox::insert_outbox_event,
+        schema::{
+            quest_adventurer_junction, quest_attempt_crew, quest_attempts, quests,
+            wallet_transactions,
+        },
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quests},
 };
 
 pub struct JourneyLedgerPostgres {
@@ -24,49 +43,189 @@ impl JourneyLedgerPostgres {
 
 #[async_trait]
 impl JourneyLedgerRepository for JourneyLedgerPostgres {
-    async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    async fn in_journey(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        domain_event: DomainEvent,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::InJourney.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
-
-        Ok(result)
+        // every departure starts a new attempt with a snapshot of the crew, a second
+        // request for the same departure finds the quest already InJourney and rolls back
+        conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq_any([
+                    QuestStatuses::Open.to_string(),
+                    QuestStatuses::Failed.to_string(),
+                ]))
+                .set((
+                    quests::status.eq(QuestStatuses::InJourney.to_string()),
+                    quests::guild_commander_id.eq(guild_commander_id),
+                ))
+                .returning(quests::id)
+                .get_result::<i32>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Cannot changed status of this quest"))?;
+
+            start_quest_attempt(conn, quest_id)?;
+
+            insert_outbox_event(conn, &domain_event)?;
+
+            Ok(result)
+        })
     }
-    async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    async fn to_completed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        rewards: Vec<AddWalletTransactionEntity>,
+        experience_change: i64,
+        domain_event: DomainEvent,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::Completed.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
-
-        Ok(result)
+        // the crew is only credited when the status change commits, and only the request
+        // that moves the quest out of InJourney gets that far
+        conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::InJourney.to_string()))
+                .set((
+                    quests::status.eq(QuestStatuses::Completed.to_string()),
+                    quests::guild_commander_id.eq(guild_commander_id),
+                ))
+                .returning(quests::id)
+                .get_result::<i32>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Cannot changed status of this quest"))?;
+
+            insert_into(wallet_transactions::table)
+                .values(&rewards)
+                .execute(conn)?;
+
+            change_crew_experience(conn, quest_id, experience_change)?;
+
+            end_quest_attempt(conn, quest_id, QuestAttemptOutcomes::Completed)?;
+
+            insert_outbox_event(conn, &domain_event)?;
+
+            Ok(result)
+        })
     }
-    async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    async fn to_failed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        experience_change: i64,
+        domain_event: DomainEvent,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::Failed.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
-
-        Ok(result)
+        // the penalty is only taken by the request that moves the quest out of InJourney
+        conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::InJourney.to_string()))
+                .set((
+                    quests::status.eq(QuestStatuses::Failed.to_string()),
+                    quests::guild_commander_id.eq(guild_commander_id),
+                ))
+                .returning(quests::id)
+                .get_result::<i32>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Cannot changed status of this quest"))?;
+
+            change_crew_experience(conn, quest_id, experience_change)?;
+
+            end_quest_attempt(conn, quest_id, QuestAttemptOutcomes::Failed)?;
+
+            insert_outbox_event(conn, &domain_event)?;
+
+            Ok(result)
+        })
     }
 }
+
+fn start_quest_attempt(conn: &mut PgConnection, quest_id: i32) -> QueryResult<i32> {
+    let last_attempt_number = quest_attempts::table
+        .filter(quest_attempts::quest_id.eq(quest_id))
+        .select(quest_attempts::attempt_number)
+        .order_by(quest_attempts::attempt_number.desc())
+        .first::<i32>(conn)
+        .optional()?;
+
+    let attempt_id = insert_into(quest_attempts::table)
+        .values(AddQuestAttemptEntity {
+            quest_id,
+            attempt_number: last_attempt_number.unwrap_or_default() + 1,
+            started_at: chrono::Utc::now().naive_utc(),
+        })
+        .returning(quest_attempts::id)
+        .get_result::<i32>(conn)?;
+
+    let crew: Vec<QuestAttemptCrewEntity> = quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .select(quest_adventurer_junction::adventurer_id)
+        .load::<i32>(conn)?
+        .into_iter()
+        .map(|adventurer_id| QuestAttemptCrewEntity {
+            attempt_id,
+            adventurer_id,
+        })
+        .collect();
+
+    insert_into(quest_attempt_crew::table)
+        .values(&crew)
+        .execute(conn)?;
+
+    QueryResult::Ok(attempt_id)
+}
+
+// quests that were already InJourney before attempts were tracked have nothing to close
+fn end_quest_attempt(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    outcome: QuestAttemptOutcomes,
+) -> QueryResult<usize> {
+    diesel::update(quest_attempts::table)
+        .filter(quest_attempts::quest_id.eq(quest_id))
+        .filter(quest_attempts::ended_at.is_null())
+        .set((
+            quest_attempts::outcome.eq(outcome.to_string()),
+            quest_attempts::ended_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .execute(conn)
+}
+
+// experience never drops below 0 and the level follows the curve in adventurer_levels
+fn change_crew_experience(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    experience_change: i64,
+) -> QueryResult<usize> {
+    if experience_change == 0 {
+        return QueryResult::Ok(0);
+    }
+
+    diesel::sql_query(
+        "UPDATE adventurers
+        SET experience = GREATEST(experience + $1, 0),
+            level = (
+                SELECT COALESCE(MAX(adventurer_levels.level), 1)
+                FROM adventurer_levels
+                WHERE adventurer_levels.min_experience <= GREATEST(adventurers.experience + $1, 0)
+            ),
+            updated_at = now()
+        WHERE id IN (
+            SELECT adventurer_id FROM quest_adventurer_junction WHERE quest_id = $2
+        )",
+    )
+    .bind::<BigInt, _>(experience_change)
+    .bind::<Integer, _>(quest_id)
+    .execute(conn)
+}
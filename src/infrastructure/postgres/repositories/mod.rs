@@ -1,6 +1,15 @@
+pub mod admin;
 pub mod adventurers;
 pub mod crew_switchboard;
-pub mod guild_commanders;
+pub mod guilds;
 pub mod journey_ledger;
+pub mod notifications;
+pub mod outbox;
+pub mod quest_comments;
+pub mod quest_milestones;
 pub mod quest_ops;
+pub mod quest_templates;
 pub mod quest_viewing;
+pub mod users;
+pub mod wallet;
+pub mod webhooks;
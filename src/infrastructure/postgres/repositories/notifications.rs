@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use diesel::{insert_into, prelude::*, upsert::excluded};
+
+use crate::{
+    domain::{
+        entities::{
+            notification_preferences::{
+                AddNotificationPreferenceEntity, NotificationPreferenceEntity,
+            },
+            notifications::{AddNotificationEntity, NotificationEntity},
+        },
+        repositories::notifications::NotificationsRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{
+            adventurers, guild_commanders, notification_preferences, notifications,
+            quest_adventurer_junction, quest_attempt_crew, quest_attempts, quests,
+        },
+    },
+};
+
+pub struct NotificationsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl NotificationsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NotificationsRepository for NotificationsPostgres {
+    async fn add(&self, add_notification_entities: Vec<AddNotificationEntity>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(notifications::table)
+            .values(&add_notification_entities)
+            .on_conflict((notifications::user_id, notifications::outbox_event_id))
+            .do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn by_user(
+        &self,
+        user_id: i32,
+        unread_only: bool,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<NotificationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = notifications::table
+            .filter(notifications::user_id.eq(user_id))
+            .into_boxed();
+
+        if unread_only {
+            query = query.filter(notifications::read_at.is_null());
+        }
+
+        let result = query
+            .order_by(notifications::id.desc())
+            .limit(limit)
+            .offset(offset)
+            .select(NotificationEntity::as_select())
+            .load::<NotificationEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn counting_by_user(&self, user_id: i32, unread_only: bool) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = notifications::table
+            .filter(notifications::user_id.eq(user_id))
+            .into_boxed();
+
+        if unread_only {
+            query = query.filter(notifications::read_at.is_null());
+        }
+
+        let result = query.count().get_result::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn mark_read(&self, user_id: i32, notification_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = notifications::table
+            .filter(notifications::id.eq(notification_id))
+            .filter(notifications::user_id.eq(user_id))
+            .select(notifications::read_at)
+            .first::<Option<chrono::NaiveDateTime>>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Notification not found"))?;
+
+        // marking twice keeps the time it was first read
+        if result.is_none() {
+            diesel::update(notifications::table)
+                .filter(notifications::id.eq(notification_id))
+                .filter(notifications::read_at.is_null())
+                .set(notifications::read_at.eq(chrono::Utc::now().naive_utc()))
+                .execute(&mut conn)?;
+        }
+
+        Ok(())
+    }
+    async fn mark_all_read(&self, user_id: i32) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(notifications::table)
+            .filter(notifications::user_id.eq(user_id))
+            .filter(notifications::read_at.is_null())
+            .set(notifications::read_at.eq(chrono::Utc::now().naive_utc()))
+            .execute(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn preferences(&self, user_id: i32) -> Result<Vec<NotificationPreferenceEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = notification_preferences::table
+            .filter(notification_preferences::user_id.eq(user_id))
+            .order_by(notification_preferences::id.asc())
+            .select(NotificationPreferenceEntity::as_select())
+            .load::<NotificationPreferenceEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn set_preferences(
+        &self,
+        add_notification_preference_entities: Vec<AddNotificationPreferenceEntity>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(notification_preferences::table)
+            .values(&add_notification_preference_entities)
+            .on_conflict((
+                notification_preferences::user_id,
+                notification_preferences::event_type,
+            ))
+            .do_update()
+            .set((
+                notification_preferences::enabled.eq(excluded(notification_preferences::enabled)),
+                notification_preferences::updated_at
+                    .eq(excluded(notification_preferences::updated_at)),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn muted_user_ids(&self, event_type: String, user_ids: Vec<i32>) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = notification_preferences::table
+            .filter(notification_preferences::event_type.eq(event_type))
+            .filter(notification_preferences::user_id.eq_any(user_ids))
+            .filter(notification_preferences::enabled.eq(false))
+            .select(notification_preferences::user_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn crew_user_ids(&self, quest_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // a finished attempt may already have lost its crew to a reopened quest
+        let latest_attempt_id = quest_attempts::table
+            .filter(quest_attempts::quest_id.eq(quest_id))
+            .order_by(quest_attempts::attempt_number.desc())
+            .select(quest_attempts::id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        let mut adventurer_ids = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .select(quest_adventurer_junction::adventurer_id)
+            .load::<i32>(&mut conn)?;
+
+        if let Some(latest_attempt_id) = latest_attempt_id {
+            adventurer_ids.extend(
+                quest_attempt_crew::table
+                    .filter(quest_attempt_crew::attempt_id.eq(latest_attempt_id))
+                    .select(quest_attempt_crew::adventurer_id)
+                    .load::<i32>(&mut conn)?,
+            );
+        }
+
+        let result = adventurers::table
+            .filter(adventurers::id.eq_any(adventurer_ids))
+            .order_by(adventurers::user_id.asc())
+            .select(adventurers::user_id)
+            .distinct()
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn guild_commander_user_id(&self, quest_id: i32) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quests::table
+            .inner_join(guild_commanders::table)
+            .filter(quests::id.eq(quest_id))
+            .select(guild_commanders::user_id)
+            .first::<i32>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Quest not found"))?;
+
+        Ok(result)
+    }
+    async fn quest_name(&self, quest_id: i32) -> Result<String> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quests::table
+            .filter(quests::id.eq(quest_id))
+            .select(quests::name)
+            .first::<String>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Quest not found"))?;
+
+        Ok(result)
+    }
+    async fn adventurer_username(&self, adventurer_id: i32) -> Result<String> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = adventurers::table
+            .filter(adventurers::id.eq(adventurer_id))
+            .select(adventurers::username)
+            .first::<String>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Adventurer not found"))?;
+
+        Ok(result)
+    }
+}
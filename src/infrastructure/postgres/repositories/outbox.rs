@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    dsl::insert_into, Connection, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl,
+    RunQueryDsl, SelectableHelper,
+};
+
+use crate::{
+    domain::{
+        entities::outbox::{AddOutboxEventEntity, OutboxBacklogEntity, OutboxEventEntity},
+        repositories::outbox::OutboxRepository,
+        value_objects::domain_events::DomainEvent,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{outbox, outbox_subscriber_deliveries},
+    },
+};
+
+pub struct OutboxPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl OutboxPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxPostgres {
+    async fn claim_due(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+        batch_size: i64,
+    ) -> Result<Vec<OutboxEventEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<Vec<OutboxEventEntity>, anyhow::Error, _>(|conn| {
+            let result = outbox::table
+                .filter(outbox::delivered_at.is_null())
+                .filter(outbox::failed_at.is_null())
+                .filter(outbox::next_attempt_at.le(now))
+                .order_by(outbox::id.asc())
+                .limit(batch_size)
+                .select(OutboxEventEntity::as_select())
+                .for_update()
+                .skip_locked()
+                .load::<OutboxEventEntity>(conn)?;
+
+            let ids: Vec<i32> = result.iter().map(|event| event.id).collect();
+
+            diesel::update(outbox::table)
+                .filter(outbox::id.eq_any(ids))
+                .set(outbox::next_attempt_at.eq(lease_until))
+                .execute(conn)?;
+
+            Ok(result)
+        })
+    }
+    async fn mark_delivered(
+        &self,
+        outbox_event_id: i32,
+        delivered_at: NaiveDateTime,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(outbox::table)
+            .filter(outbox::id.eq(outbox_event_id))
+            .set((
+                outbox::attempts.eq(outbox::attempts + 1),
+                outbox::delivered_at.eq(delivered_at),
+                outbox::last_error.eq(None::<String>),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn handled_subscribers(&self, outbox_event_id: i32) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = outbox_subscriber_deliveries::table
+            .filter(outbox_subscriber_deliveries::outbox_event_id.eq(outbox_event_id))
+            .select(outbox_subscriber_deliveries::subscriber)
+            .load::<String>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn mark_handled(
+        &self,
+        outbox_event_id: i32,
+        subscribers: Vec<String>,
+        handled_at: NaiveDateTime,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows: Vec<_> = subscribers
+            .iter()
+            .map(|subscriber| {
+                (
+                    outbox_subscriber_deliveries::outbox_event_id.eq(outbox_event_id),
+                    outbox_subscriber_deliveries::subscriber.eq(subscriber),
+                    outbox_subscriber_deliveries::handled_at.eq(handled_at),
+                )
+            })
+            .collect();
+
+        insert_into(outbox_subscriber_deliveries::table)
+            .values(rows)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn schedule_retry(
+        &self,
+        outbox_event_id: i32,
+        next_attempt_at: NaiveDateTime,
+        last_error: String,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(outbox::table)
+            .filter(outbox::id.eq(outbox_event_id))
+            .set((
+                outbox::attempts.eq(outbox::attempts + 1),
+                outbox::next_attempt_at.eq(next_attempt_at),
+                outbox::last_error.eq(last_error),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn mark_failed(
+        &self,
+        outbox_event_id: i32,
+        failed_at: NaiveDateTime,
+        last_error: String,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(outbox::table)
+            .filter(outbox::id.eq(outbox_event_id))
+            .set((
+                outbox::attempts.eq(outbox::attempts + 1),
+                outbox::failed_at.eq(failed_at),
+                outbox::last_error.eq(last_error),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn backlog(&self) -> Result<OutboxBacklogEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let pending = outbox::table
+            .filter(outbox::delivered_at.is_null())
+            .filter(outbox::failed_at.is_null());
+
+        let pending_count = pending.count().get_result::<i64>(&mut conn)?;
+
+        let oldest_pending_at = pending
+            .order_by(outbox::created_at.asc())
+            .select(outbox::created_at)
+            .first::<NaiveDateTime>(&mut conn)
+            .optional()?;
+
+        let retrying = pending
+            .filter(outbox::attempts.gt(0))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        let failed = outbox::table
+            .filter(outbox::failed_at.is_not_null())
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(OutboxBacklogEntity {
+            pending: pending_count,
+            retrying,
+            failed,
+            oldest_pending_at,
+        })
+    }
+}
+
+// called by the other repositories inside the transaction that makes the change, so the event
+// is stored if and only if the change commits
+pub fn insert_outbox_event(conn: &mut PgConnection, domain_event: &DomainEvent) -> Result<()> {
+    insert_into(outbox::table)
+        .values(AddOutboxEventEntity::new(domain_event)?)
+        .execute(conn)?;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use diesel::{
+    dsl::{exists, insert_into},
+    select, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+};
+
+use crate::{
+    domain::{
+        entities::quest_comments::{AddQuestCommentEntity, QuestCommentEntity},
+        repositories::quest_comments::QuestCommentsRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{quest_adventurer_junction, quest_comments},
+    },
+};
+
+pub struct QuestCommentsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestCommentsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestCommentsRepository for QuestCommentsPostgres {
+    async fn add(&self, add_quest_comment_entity: AddQuestCommentEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_comments::table)
+            .values(add_quest_comment_entity)
+            .returning(quest_comments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn find(&self, comment_id: i32) -> Result<QuestCommentEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_comments::table
+            .filter(quest_comments::id.eq(comment_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .select(QuestCommentEntity::as_select())
+            .first::<QuestCommentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn comments_by_quest_id(
+        &self,
+        quest_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<QuestCommentEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_comments::table
+            .filter(quest_comments::quest_id.eq(quest_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .order_by(quest_comments::created_at.asc())
+            .then_order_by(quest_comments::id.asc())
+            .limit(limit)
+            .offset(offset)
+            .select(QuestCommentEntity::as_select())
+            .load::<QuestCommentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn counting_by_quest_id(&self, quest_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_comments::table
+            .filter(quest_comments::quest_id.eq(quest_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .count()
+            .first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn edit(&self, comment_id: i32, body: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_comments::table)
+            .filter(quest_comments::id.eq(comment_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .set((
+                quest_comments::body.eq(body),
+                quest_comments::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("Comment not found"));
+        }
+
+        Ok(())
+    }
+    async fn remove(&self, comment_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_comments::table)
+            .filter(quest_comments::id.eq(comment_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .set(quest_comments::deleted_at.eq(chrono::Utc::now().naive_utc()))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("Comment not found"));
+        }
+
+        Ok(())
+    }
+    async fn is_crew_member(&self, quest_id: i32, adventurer_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = select(exists(
+            quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+                .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use diesel::{dsl::insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::quest_milestones::{
+            AddQuestMilestoneEntity, EditQuestMilestoneEntity, QuestMilestoneEntity,
+        },
+        repositories::quest_milestones::QuestMilestonesRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_milestones},
+};
+
+pub struct QuestMilestonesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestMilestonesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestMilestonesRepository for QuestMilestonesPostgres {
+    async fn add(&self, add_quest_milestone_entity: AddQuestMilestoneEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_milestones::table)
+            .values(add_quest_milestone_entity)
+            .returning(quest_milestones::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn find(&self, milestone_id: i32) -> Result<QuestMilestoneEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_milestones::table
+            .filter(quest_milestones::id.eq(milestone_id))
+            .select(QuestMilestoneEntity::as_select())
+            .first::<QuestMilestoneEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn edit(
+        &self,
+        milestone_id: i32,
+        edit_quest_milestone_entity: EditQuestMilestoneEntity,
+        position: Option<i32>,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let milestone = quest_milestones::table
+                .filter(quest_milestones::id.eq(milestone_id))
+                .select(QuestMilestoneEntity::as_select())
+                .for_update()
+                .first::<QuestMilestoneEntity>(conn)?;
+
+            if let Some(position) = position {
+                move_milestone(conn, &milestone, position)?;
+            }
+
+            let result = diesel::update(quest_milestones::table)
+                .filter(quest_milestones::id.eq(milestone_id))
+                .set(edit_quest_milestone_entity)
+                .returning(quest_milestones::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(result)
+        })?;
+
+        Ok(result)
+    }
+    async fn remove(&self, milestone_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            let milestone = diesel::delete(quest_milestones::table)
+                .filter(quest_milestones::id.eq(milestone_id))
+                .returning(QuestMilestoneEntity::as_returning())
+                .get_result::<QuestMilestoneEntity>(conn)?;
+
+            // close the gap so the checklist keeps counting from 1
+            diesel::update(quest_milestones::table)
+                .filter(quest_milestones::quest_id.eq(milestone.quest_id))
+                .filter(quest_milestones::position.gt(milestone.position))
+                .set(quest_milestones::position.eq(quest_milestones::position - 1))
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+    async fn tick(&self, milestone_id: i32, adventurer_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = diesel::update(quest_milestones::table)
+            .filter(quest_milestones::id.eq(milestone_id))
+            .filter(quest_milestones::completed_at.is_null())
+            .set((
+                quest_milestones::completed_by.eq(adventurer_id),
+                quest_milestones::completed_at.eq(now),
+                quest_milestones::updated_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("The milestone is already done"));
+        }
+
+        Ok(())
+    }
+    async fn untick(&self, milestone_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_milestones::table)
+            .filter(quest_milestones::id.eq(milestone_id))
+            .filter(quest_milestones::completed_at.is_not_null())
+            .set((
+                quest_milestones::completed_by.eq(None::<i32>),
+                quest_milestones::completed_at.eq(None::<chrono::NaiveDateTime>),
+                quest_milestones::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("The milestone is not done yet"));
+        }
+
+        Ok(())
+    }
+}
+
+// shifts the milestones between the old and the new position by one,
+// the new position is clamped into the checklist
+fn move_milestone(
+    conn: &mut PgConnection,
+    milestone: &QuestMilestoneEntity,
+    position: i32,
+) -> QueryResult<()> {
+    let milestones_count = quest_milestones::table
+        .filter(quest_milestones::quest_id.eq(milestone.quest_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    let position = position.clamp(1, milestones_count as i32);
+
+    if position < milestone.position {
+        diesel::update(quest_milestones::table)
+            .filter(quest_milestones::quest_id.eq(milestone.quest_id))
+            .filter(quest_milestones::position.ge(position))
+            .filter(quest_milestones::position.lt(milestone.position))
+            .set(quest_milestones::position.eq(quest_milestones::position + 1))
+            .execute(conn)?;
+    } else if position > milestone.position {
+        diesel::update(quest_milestones::table)
+            .filter(quest_milestones::quest_id.eq(milestone.quest_id))
+            .filter(quest_milestones::position.gt(milestone.position))
+            .filter(quest_milestones::position.le(position))
+            .set(quest_milestones::position.eq(quest_milestones::position - 1))
+            .execute(conn)?;
+    }
+
+    diesel::update(quest_milestones::table)
+        .filter(quest_milestones::id.eq(milestone.id))
+        .set(quest_milestones::position.eq(position))
+        .execute(conn)?;
+
+    QueryResult::Ok(())
+}
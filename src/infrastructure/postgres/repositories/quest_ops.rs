@@ -2,15 +2,26 @@ use std::sync::Arc;
 
 use anyhow::{Ok, Result};
 use axum::async_trait;
-use diesel::{insert_into, ExpressionMethods, RunQueryDsl};
+use diesel::{
+    delete, insert_into, upsert::excluded, Connection, ExpressionMethods, OptionalExtension,
+    PgConnection, QueryResult, RunQueryDsl,
+};
 
 use crate::{
     domain::{
-        entities::quests::{AddQuestEntity, EditQuestEntity},
+        entities::{
+            quest_prerequisites::QuestPrerequisiteEntity,
+            quests::{AddQuestEntity, EditQuestEntity},
+            tags::{AddTagEntity, QuestTagEntity},
+        },
         repositories::quest_ops::QuestOpsRepository,
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{domain_events::DomainEvent, quest_statuses::QuestStatuses},
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::outbox::insert_outbox_event,
+        schema::{quest_prerequisites, quest_tags, quests, tags},
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quests},
 };
 
 pub struct QuestOpsPostgres {
@@ -25,45 +36,153 @@ impl QuestOpsPostgres {
 
 #[async_trait]
 impl QuestOpsRepository for QuestOpsPostgres {
-    async fn add(&self, add_quest_entity: AddQuestEntity) -> Result<i32> {
+    async fn add(&self, add_quest_entity: AddQuestEntity, tags: Vec<String>) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let guild_id = add_quest_entity.guild_id;
+        let guild_commander_id = add_quest_entity.guild_commander_id;
+
+        conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let result = insert_into(quests::table)
+                .values(add_quest_entity)
+                .returning(quests::id)
+                .get_result::<i32>(conn)?;
+
+            replace_quest_tags(conn, result, &tags)?;
+
+            insert_outbox_event(
+                conn,
+                &DomainEvent::QuestCreated {
+                    quest_id: result,
+                    guild_id,
+                    guild_commander_id,
+                },
+            )?;
+
+            Ok(result)
+        })
+    }
+    async fn edit(
+        &self,
+        quest_id: i32,
+        edit_quest_entity: EditQuestEntity,
+        tags: Option<Vec<String>>,
+        domain_event: DomainEvent,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+                .set(edit_quest_entity)
+                .returning(quests::id)
+                .get_result::<i32>(conn)?;
+
+            if let Some(tags) = tags {
+                replace_quest_tags(conn, result, &tags)?;
+            }
+
+            insert_outbox_event(conn, &domain_event)?;
+
+            Ok(result)
+        })
+    }
+    async fn remove(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        domain_event: DomainEvent,
+    ) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = insert_into(quests::table)
-            .values(add_quest_entity)
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+                .set((
+                    quests::deleted_at.eq(chrono::Utc::now().naive_utc()),
+                    quests::guild_commander_id.eq(guild_commander_id),
+                ))
+                .returning(quests::id)
+                .get_result::<i32>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Quest not found"))?;
+
+            insert_outbox_event(conn, &domain_event)?;
 
-        Ok(result)
+            Ok(())
+        })
     }
-    async fn edit(&self, quest_id: i32, edit_quest_entity: EditQuestEntity) -> Result<i32> {
+    async fn add_prerequisite(
+        &self,
+        quest_prerequisite_entity: QuestPrerequisiteEntity,
+    ) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .filter(quests::status.eq(QuestStatuses::Open.to_string()))
-            .set(edit_quest_entity)
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        insert_into(quest_prerequisites::table)
+            .values(quest_prerequisite_entity)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
 
-        Ok(result)
+        Ok(())
     }
-    async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()> {
+    async fn remove_prerequisite(&self, quest_id: i32, prerequisite_id: i32) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .filter(quests::status.eq(QuestStatuses::Open.to_string()))
-            .set((
-                quests::deleted_at.eq(chrono::Utc::now().naive_utc()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
+        let result = delete(quest_prerequisites::table)
+            .filter(quest_prerequisites::quest_id.eq(quest_id))
+            .filter(quest_prerequisites::prerequisite_id.eq(prerequisite_id))
             .execute(&mut conn)?;
 
         if result == 0 {
-            return Err(anyhow::anyhow!("Quest not found"));
+            return Err(anyhow::anyhow!("Prerequisite not found"));
         }
+
         Ok(())
     }
 }
+
+// creates the tags that do not exist yet, then points the quest at exactly these tags
+fn replace_quest_tags(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    tag_names: &[String],
+) -> QueryResult<()> {
+    delete(quest_tags::table)
+        .filter(quest_tags::quest_id.eq(quest_id))
+        .execute(conn)?;
+
+    if tag_names.is_empty() {
+        return QueryResult::Ok(());
+    }
+
+    let add_tag_entities: Vec<AddTagEntity> = tag_names
+        .iter()
+        .map(|name| AddTagEntity {
+            name: name.clone(),
+            created_at: chrono::Utc::now().naive_utc(),
+        })
+        .collect();
+
+    let tag_ids = insert_into(tags::table)
+        .values(&add_tag_entities)
+        .on_conflict(tags::name)
+        .do_update()
+        .set(tags::name.eq(excluded(tags::name)))
+        .returning(tags::id)
+        .get_results::<i32>(conn)?;
+
+    let quest_tag_entities: Vec<QuestTagEntity> = tag_ids
+        .into_iter()
+        .map(|tag_id| QuestTagEntity { quest_id, tag_id })
+        .collect();
+
+    insert_into(quest_tags::table)
+        .values(&quest_tag_entities)
+        .execute(conn)?;
+
+    QueryResult::Ok(())
+}
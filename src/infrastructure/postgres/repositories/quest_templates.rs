@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{dsl::insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::quest_templates::{AddQuestTemplateEntity, QuestTemplateEntity},
+        repositories::quest_templates::QuestTemplatesRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_templates},
+};
+
+pub struct QuestTemplatesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestTemplatesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestTemplatesRepository for QuestTemplatesPostgres {
+    async fn add(&self, add_quest_template_entity: AddQuestTemplateEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_templates::table)
+            .values(add_quest_template_entity)
+            .returning(quest_templates::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn find(&self, template_id: i32) -> Result<QuestTemplateEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_templates::table
+            .filter(quest_templates::id.eq(template_id))
+            .filter(quest_templates::deleted_at.is_null())
+            .select(QuestTemplateEntity::as_select())
+            .first::<QuestTemplateEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn templates_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestTemplateEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_templates::table
+            .filter(quest_templates::guild_commander_id.eq(guild_commander_id))
+            .filter(quest_templates::deleted_at.is_null())
+            .order_by(quest_templates::id.asc())
+            .select(QuestTemplateEntity::as_select())
+            .load::<QuestTemplateEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn claim_due(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+    ) -> Result<Vec<QuestTemplateEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<Vec<QuestTemplateEntity>, anyhow::Error, _>(|conn| {
+            let result = quest_templates::table
+                .filter(quest_templates::deleted_at.is_null())
+                .filter(quest_templates::paused.eq(false))
+                .filter(quest_templates::next_run_at.le(now))
+                .order_by(quest_templates::next_run_at.asc())
+                .select(QuestTemplateEntity::as_select())
+                .for_update()
+                .skip_locked()
+                .load::<QuestTemplateEntity>(conn)?;
+
+            let ids: Vec<i32> = result.iter().map(|template| template.id).collect();
+
+            diesel::update(quest_templates::table)
+                .filter(quest_templates::id.eq_any(ids))
+                .set(quest_templates::next_run_at.eq(lease_until))
+                .execute(conn)?;
+
+            Ok(result)
+        })
+    }
+    async fn pause(&self, template_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(quest_templates::table)
+            .filter(quest_templates::id.eq(template_id))
+            .filter(quest_templates::deleted_at.is_null())
+            .set((
+                quest_templates::paused.eq(true),
+                quest_templates::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn resume(&self, template_id: i32, next_run_at: NaiveDateTime) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(quest_templates::table)
+            .filter(quest_templates::id.eq(template_id))
+            .filter(quest_templates::deleted_at.is_null())
+            .set((
+                quest_templates::paused.eq(false),
+                quest_templates::next_run_at.eq(next_run_at),
+                quest_templates::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn remove(&self, template_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(quest_templates::table)
+            .filter(quest_templates::id.eq(template_id))
+            .filter(quest_templates::deleted_at.is_null())
+            .set((
+                quest_templates::deleted_at.eq(chrono::Utc::now().naive_utc()),
+                quest_templates::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn record_run(
+        &self,
+        template_id: i32,
+        last_run_at: NaiveDateTime,
+        next_run_at: NaiveDateTime,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(quest_templates::table)
+            .filter(quest_templates::id.eq(template_id))
+            .set((
+                quest_templates::last_run_at.eq(last_run_at),
+                quest_templates::next_run_at.eq(next_run_at),
+                quest_templates::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
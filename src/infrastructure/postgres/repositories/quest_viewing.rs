@@ -1,17 +1,39 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::{Ok, Result};
 use axum::async_trait;
-use diesel::prelude::*;
+use chrono::NaiveDateTime;
+use diesel::{
+    dsl::sql,
+    pg::Pg,
+    prelude::*,
+    sql_types::{Bool, Float, Integer, Nullable, Text},
+};
 
 use crate::{
     domain::{
-        entities::quests::QuestEntity, repositories::quest_viewing::QuestViewingRepository,
-        value_objects::board_checking_filter::BoardCheckingFilter,
+        entities::{
+            adventurers::AdventurerEntity, quest_attempts::QuestAttemptEntity,
+            quest_milestones::QuestMilestoneEntity, quests::QuestEntity,
+        },
+        repositories::quest_viewing::QuestViewingRepository,
+        value_objects::{
+            board_checking_filter::BoardCheckingFilter,
+            guild_membership_model::GuildMember,
+            quest_adventurer_junction::QuestAdventurerJunction,
+            quest_application_statuses::QuestApplicationStatuses,
+            quest_ranks::QuestRanks,
+            quest_statuses::{QuestStatuses, JOINABLE_QUEST_STATUSES},
+            quest_tags::QuestTagsMatches,
+        },
     },
     infrastructure::postgres::{
         postgres_connection::PgPoolSquad,
-        schema::{quest_adventurer_junction, quests},
+        schema::{
+            adventurers, guild_memberships, quest_adventurer_junction, quest_applications,
+            quest_attempt_crew, quest_attempts, quest_milestones, quest_prerequisites, quest_tags,
+            quest_waitlist_entries, quests, tags,
+        },
     },
 };
 
@@ -38,25 +60,68 @@ impl QuestViewingRepository for QuestViewingPostgres {
 
         Ok(result)
     }
-    async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestEntity>> {
+    async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+        member: GuildMember,
+        joinable_by: Option<i32>,
+    ) -> Result<Vec<(QuestEntity, Option<String>)>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let mut query = quests::table
-            .filter(quests::deleted_at.is_null())
-            .into_boxed(); // call rust estimate size of query
+        let query = board_query(filter, member, joinable_by)?;
 
-        if let Some(name) = &filter.name {
-            query = query.filter(quests::name.ilike(format!("%{}%", name)));
-        }
+        let search_query = filter.search_query();
+
+        let result = match search_query {
+            Some(q) => query
+                .select((
+                    QuestEntity::as_select(),
+                    // the text is escaped before it is highlighted, so <mark> is the only markup
+                    // that reaches the client
+                    sql::<Nullable<Text>>(
+                        "ts_headline('english', \
+                        replace(replace(replace(replace(replace(\
+                        quests.name || ' ' || coalesce(quests.description, ''), \
+                        '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '\"', '&quot;'), '''', '&#39;'), \
+                        websearch_to_tsquery('english', ",
+                    )
+                    .bind::<Text, _>(q.clone())
+                    .sql("), 'StartSel=<mark>, StopSel=</mark>, MaxFragments=2')"),
+                ))
+                .order_by(
+                    sql::<Float>("ts_rank_cd(quests.search_vector, websearch_to_tsquery('english', ")
+                        .bind::<Text, _>(q)
+                        .sql(")) DESC"),
+                )
+                .then_order_by(quests::created_at.desc())
+                .load::<(QuestEntity, Option<String>)>(&mut conn)?,
+            None => query
+                .select(QuestEntity::as_select())
+                .order_by(quests::created_at.desc())
+                .load::<QuestEntity>(&mut conn)?
+                .into_iter()
+                .map(|quest| (quest, None))
+                .collect(),
+        };
+
+        Ok(result)
+    }
+    async fn board_quest_ids(
+        &self,
+        filter: &BoardCheckingFilter,
+        member: GuildMember,
+        joinable_by: Option<i32>,
+        quest_id: Option<i32>,
+    ) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = board_query(filter, member, joinable_by)?;
 
-        if let Some(status) = &filter.status {
-            query = query.filter(quests::status.eq(status.to_string()));
+        if let Some(quest_id) = quest_id {
+            query = query.filter(quests::id.eq(quest_id));
         }
 
-        let result = query
-            .select(QuestEntity::as_select())
-            .order_by(quests::created_at.desc())
-            .load(&mut conn)?;
+        let result = query.select(quests::id).load::<i32>(&mut conn)?;
 
         Ok(result)
     }
@@ -70,4 +135,393 @@ impl QuestViewingRepository for QuestViewingPostgres {
 
         Ok(result)
     }
+    async fn tags_by_quest_id(&self, quest_id: i32) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_tags::table
+            .inner_join(tags::table)
+            .filter(quest_tags::quest_id.eq(quest_id))
+            .order_by(tags::name.asc())
+            .select(tags::name)
+            .load::<String>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn attempts_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<(QuestAttemptEntity, Vec<i32>)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let attempts = quest_attempts::table
+            .filter(quest_attempts::quest_id.eq(quest_id))
+            .order_by(quest_attempts::attempt_number.asc())
+            .select(QuestAttemptEntity::as_select())
+            .load::<QuestAttemptEntity>(&mut conn)?;
+
+        let attempt_ids: Vec<i32> = attempts.iter().map(|attempt| attempt.id).collect();
+
+        let crew = quest_attempt_crew::table
+            .filter(quest_attempt_crew::attempt_id.eq_any(attempt_ids))
+            .order_by(quest_attempt_crew::adventurer_id.asc())
+            .select((
+                quest_attempt_crew::attempt_id,
+                quest_attempt_crew::adventurer_id,
+            ))
+            .load::<(i32, i32)>(&mut conn)?;
+
+        let result = attempts
+            .into_iter()
+            .map(|attempt| {
+                let adventurer_ids = crew
+                    .iter()
+                    .filter(|(attempt_id, _)| *attempt_id == attempt.id)
+                    .map(|(_, adventurer_id)| *adventurer_id)
+                    .collect();
+
+                (attempt, adventurer_ids)
+            })
+            .collect();
+
+        Ok(result)
+    }
+    async fn prerequisites_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let prerequisite_ids = quest_prerequisites::table
+            .filter(quest_prerequisites::quest_id.eq(quest_id))
+            .select(quest_prerequisites::prerequisite_id);
+
+        let result = quests::table
+            .filter(quests::id.eq_any(prerequisite_ids))
+            .filter(quests::deleted_at.is_null())
+            .order_by(quests::id.asc())
+            .select(QuestEntity::as_select())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn prerequisite_chain_ids(&self, quest_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // UNION drops repeated ids, so an existing cycle cannot loop forever
+        let result = quests::table
+            .filter(
+                sql::<Bool>(
+                    "quests.id IN (
+                    WITH RECURSIVE chain(id) AS (
+                        SELECT prerequisite_id FROM quest_prerequisites WHERE quest_id = ",
+                )
+                .bind::<Integer, _>(quest_id)
+                .sql(
+                    "
+                        UNION
+                        SELECT quest_prerequisites.prerequisite_id
+                        FROM quest_prerequisites
+                        INNER JOIN chain ON quest_prerequisites.quest_id = chain.id
+                    )
+                    SELECT id FROM chain
+                )",
+                ),
+            )
+            .order_by(quests::id.asc())
+            .select(quests::id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn milestones_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestMilestoneEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_milestones::table
+            .filter(quest_milestones::quest_id.eq(quest_id))
+            .order_by(quest_milestones::position.asc())
+            .then_order_by(quest_milestones::id.asc())
+            .select(QuestMilestoneEntity::as_select())
+            .load::<QuestMilestoneEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn overdue_quests_in_journey(&self, now: NaiveDateTime) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quests::table
+            .filter(quests::deleted_at.is_null())
+            .filter(quests::status.eq(QuestStatuses::InJourney.to_string()))
+            .filter(quests::due_at.lt(now))
+            .select(QuestEntity::as_select())
+            .order_by(quests::due_at.asc())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn crew_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<(QuestAdventurerJunction, AdventurerEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_adventurer_junction::table
+            .inner_join(adventurers::table)
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .order_by(quest_adventurer_junction::joined_at.asc())
+            .select((
+                QuestAdventurerJunction::as_select(),
+                AdventurerEntity::as_select(),
+            ))
+            .load::<(QuestAdventurerJunction, AdventurerEntity)>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn adventurers_counting_by_quest_ids(
+        &self,
+        quest_ids: &[i32],
+    ) -> Result<HashMap<i32, i64>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::quest_id.eq_any(quest_ids))
+            .group_by(quest_adventurer_junction::quest_id)
+            .select((
+                quest_adventurer_junction::quest_id,
+                diesel::dsl::count_star(),
+            ))
+            .load::<(i32, i64)>(&mut conn)?
+            .into_iter()
+            .collect();
+
+        Ok(result)
+    }
+    async fn tags_by_quest_ids(&self, quest_ids: &[i32]) -> Result<HashMap<i32, Vec<String>>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows = quest_tags::table
+            .inner_join(tags::table)
+            .filter(quest_tags::quest_id.eq_any(quest_ids))
+            .order_by(tags::name.asc())
+            .select((quest_tags::quest_id, tags::name))
+            .load::<(i32, String)>(&mut conn)?;
+
+        let mut result: HashMap<i32, Vec<String>> = HashMap::new();
+
+        for (quest_id, tag) in rows {
+            result.entry(quest_id).or_default().push(tag);
+        }
+
+        Ok(result)
+    }
+    async fn prerequisites_by_quest_ids(
+        &self,
+        quest_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<QuestEntity>>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let pairs = quest_prerequisites::table
+            .filter(quest_prerequisites::quest_id.eq_any(quest_ids))
+            .select((
+                quest_prerequisites::quest_id,
+                quest_prerequisites::prerequisite_id,
+            ))
+            .load::<(i32, i32)>(&mut conn)?;
+
+        let prerequisites = quests::table
+            .filter(quests::id.eq_any(pairs.iter().map(|(_, prerequisite_id)| *prerequisite_id)))
+            .filter(quests::deleted_at.is_null())
+            .order_by(quests::id.asc())
+            .select(QuestEntity::as_select())
+            .load::<QuestEntity>(&mut conn)?;
+
+        let mut result: HashMap<i32, Vec<QuestEntity>> = HashMap::new();
+
+        for prerequisite in prerequisites {
+            for (quest_id, _) in pairs
+                .iter()
+                .filter(|(_, prerequisite_id)| *prerequisite_id == prerequisite.id)
+            {
+                result
+                    .entry(*quest_id)
+                    .or_default()
+                    .push(prerequisite.clone());
+            }
+        }
+
+        Ok(result)
+    }
+    async fn milestones_by_quest_ids(
+        &self,
+        quest_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<QuestMilestoneEntity>>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let milestones = quest_milestones::table
+            .filter(quest_milestones::quest_id.eq_any(quest_ids))
+            .order_by(quest_milestones::position.asc())
+            .then_order_by(quest_milestones::id.asc())
+            .select(QuestMilestoneEntity::as_select())
+            .load::<QuestMilestoneEntity>(&mut conn)?;
+
+        let mut result: HashMap<i32, Vec<QuestMilestoneEntity>> = HashMap::new();
+
+        for milestone in milestones {
+            result
+                .entry(milestone.quest_id)
+                .or_default()
+                .push(milestone);
+        }
+
+        Ok(result)
+    }
+}
+
+// the quests on the caller's board that match the filter, shared by the board and its stream
+fn board_query(
+    filter: &BoardCheckingFilter,
+    member: GuildMember,
+    joinable_by: Option<i32>,
+) -> Result<quests::BoxedQuery<'static, Pg>> {
+    // the board only holds quests of the guilds the caller belongs to
+    let member_guild_ids = match member {
+        GuildMember::Adventurer(adventurer_id) => guild_memberships::table
+            .filter(guild_memberships::adventurer_id.eq(adventurer_id))
+            .select(guild_memberships::guild_id)
+            .into_boxed(),
+        GuildMember::GuildCommander(guild_commander_id) => guild_memberships::table
+            .filter(guild_memberships::guild_commander_id.eq(guild_commander_id))
+            .select(guild_memberships::guild_id)
+            .into_boxed(),
+    };
+
+    let mut query = quests::table
+        .filter(quests::deleted_at.is_null())
+        .filter(quests::guild_id.eq_any(member_guild_ids))
+        .into_boxed(); // call rust estimate size of query
+
+    let search_query = filter.search_query();
+
+    if let Some(q) = &search_query {
+        query = query.filter(
+            sql::<Bool>("quests.search_vector @@ websearch_to_tsquery('english', ")
+                .bind::<Text, _>(q.clone())
+                .sql(")"),
+        );
+    }
+
+    if let Some(name) = &filter.name {
+        query = query.filter(quests::name.ilike(format!("%{}%", name)));
+    }
+
+    let status_names = filter.status_names()?;
+
+    if !status_names.is_empty() {
+        query = query.filter(quests::status.eq_any(status_names));
+    }
+
+    if let Some(guild_commander_id) = filter.guild_commander_id {
+        query = query.filter(quests::guild_commander_id.eq(guild_commander_id));
+    }
+
+    if let Some(created_from) = filter.created_from {
+        query = query.filter(quests::created_at.ge(created_from));
+    }
+
+    if let Some(created_to) = filter.created_to {
+        query = query.filter(quests::created_at.le(created_to));
+    }
+
+    if let Some(updated_from) = filter.updated_from {
+        query = query.filter(quests::updated_at.ge(updated_from));
+    }
+
+    if let Some(updated_to) = filter.updated_to {
+        query = query.filter(quests::updated_at.le(updated_to));
+    }
+
+    // the same rules CrewSwitchboardUseCase::join checks one quest at a time
+    if let Some(adventurer_id) = joinable_by {
+        let joinable_statuses: Vec<String> = JOINABLE_QUEST_STATUSES
+            .iter()
+            .map(|status| status.to_string())
+            .collect();
+
+        let joined_quest_ids = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .select(quest_adventurer_junction::quest_id);
+
+        // waiting for a seat or for an answer to an application is as good as joined
+        let waitlisted_quest_ids = quest_waitlist_entries::table
+            .filter(quest_waitlist_entries::adventurer_id.eq(adventurer_id))
+            .select(quest_waitlist_entries::quest_id);
+
+        let applied_quest_ids = quest_applications::table
+            .filter(quest_applications::adventurer_id.eq(adventurer_id))
+            .filter(quest_applications::status.eq(QuestApplicationStatuses::Pending.to_string()))
+            .select(quest_applications::quest_id);
+
+        // the crew size is compared against the capacity of each quest
+        let has_free_seat = sql::<Bool>(
+            "(SELECT COUNT(*) FROM quest_adventurer_junction \
+            WHERE quest_adventurer_junction.quest_id = quests.id) < quests.capacity",
+        );
+
+        // a quest stays locked until every prerequisite is completed
+        let is_unlocked = sql::<Bool>(
+            "NOT EXISTS (SELECT 1 FROM quest_prerequisites \
+            INNER JOIN quests AS prerequisites \
+            ON prerequisites.id = quest_prerequisites.prerequisite_id \
+            WHERE quest_prerequisites.quest_id = quests.id \
+            AND prerequisites.deleted_at IS NULL \
+            AND prerequisites.status <> ",
+        )
+        .bind::<Text, _>(QuestStatuses::Completed.to_string())
+        .sql(")");
+
+        let adventurer_level = adventurers::table
+            .filter(adventurers::id.eq(adventurer_id))
+            .select(adventurers::level)
+            .single_value();
+
+        query = query
+            .filter(quests::status.eq_any(joinable_statuses))
+            .filter(diesel::dsl::not(quests::id.eq_any(joined_quest_ids)))
+            .filter(diesel::dsl::not(quests::id.eq_any(waitlisted_quest_ids)))
+            .filter(diesel::dsl::not(quests::id.eq_any(applied_quest_ids)))
+            .filter(has_free_seat)
+            .filter(is_unlocked)
+            .filter(
+                quests::min_level
+                    .is_null()
+                    .or(quests::min_level.le(adventurer_level)),
+            );
+    }
+
+    if filter.min_rank.is_some() || filter.max_rank.is_some() {
+        let ranks: Vec<String> =
+            QuestRanks::between(filter.min_rank.as_ref(), filter.max_rank.as_ref())
+                .iter()
+                .map(|rank| rank.to_string())
+                .collect();
+
+        query = query.filter(quests::rank.eq_any(ranks));
+    }
+
+    let tag_names = filter.tag_names();
+
+    if !tag_names.is_empty() {
+        let tags_count = tag_names.len() as i64;
+
+        let tagged_quest_ids = quest_tags::table
+            .inner_join(tags::table)
+            .filter(tags::name.eq_any(tag_names))
+            .group_by(quest_tags::quest_id)
+            .select(quest_tags::quest_id);
+
+        query = match filter.tags_match.clone().unwrap_or_default() {
+            QuestTagsMatches::Any => query.filter(quests::id.eq_any(tagged_quest_ids)),
+            QuestTagsMatches::All => query.filter(quests::id.eq_any(
+                tagged_quest_ids.having(diesel::dsl::count(quest_tags::tag_id).eq(tags_count)),
+            )),
+        };
+    }
+
+    Ok(query)
 }
@@ -0,0 +1,450 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use diesel::{dsl::insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::{
+            adventurers::{AdventurerEntity, RegisterAdventurerEntity},
+            guild_commanders::RegisterGuildCommanderEntity,
+            guild_memberships::GuildMembershipEntity,
+            quest_applications::QuestApplicationEntity,
+            quest_comments::QuestCommentEntity,
+            quest_invitations::QuestInvitationEntity,
+            user_roles::{AddUserRoleEntity, UserRoleEntity},
+            users::{RegisterUserEntity, UserDataEntity, UserEntity},
+            wallet_transactions::WalletTransactionEntity,
+        },
+        repositories::users::UsersRepository,
+        value_objects::{
+            domain_events::DomainEvent, quest_adventurer_junction::QuestAdventurerJunction,
+            quest_application_statuses::QuestApplicationStatuses,
+            quest_comment_author_roles::QuestCommentAuthorRoles,
+            quest_invitation_statuses::QuestInvitationStatuses, quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::{
+        jwt_authentication::jwt_model::Roles,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::crew_switchboard::leave_quest,
+            schema::{
+                adventurers, guild_commanders, guild_memberships, notifications,
+                quest_adventurer_junction, quest_applications, quest_comments, quest_invitations,
+                quest_waitlist_entries, quests, user_roles, users, wallet_transactions,
+            },
+        },
+    },
+};
+
+pub struct UsersPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl UsersPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl UsersRepository for UsersPostgres {
+    async fn register(
+        &self,
+        register_user_entity: RegisterUserEntity,
+        roles: Vec<String>,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let username = register_user_entity.username.clone();
+
+            let user_id = insert_into(users::table)
+                .values(register_user_entity)
+                .returning(users::id)
+                .get_result::<i32>(conn)?;
+
+            for role in roles {
+                assign_role(conn, user_id, &username, &role)?;
+            }
+
+            Ok(user_id)
+        })
+    }
+
+    async fn find_by_id(&self, user_id: i32) -> Result<UserEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = users::table
+            .filter(users::id.eq(user_id))
+            .select(UserEntity::as_select())
+            .first::<UserEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+
+        Ok(result)
+    }
+
+    async fn find_by_username(&self, username: String) -> Result<UserEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = users::table
+            .filter(users::username.eq(username))
+            .select(UserEntity::as_select())
+            .first::<UserEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+
+        Ok(result)
+    }
+
+    async fn find_by_adventurer_username(&self, username: String) -> Result<UserEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = users::table
+            .inner_join(adventurers::table)
+            .filter(adventurers::username.eq(username))
+            .select(UserEntity::as_select())
+            .first::<UserEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+
+        Ok(result)
+    }
+
+    async fn find_by_guild_commander_username(&self, username: String) -> Result<UserEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = users::table
+            .inner_join(guild_commanders::table)
+            .filter(guild_commanders::username.eq(username))
+            .select(UserEntity::as_select())
+            .first::<UserEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))?;
+
+        Ok(result)
+    }
+
+    async fn roles(&self, user_id: i32) -> Result<Vec<UserRoleEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = user_roles::table
+            .filter(user_roles::user_id.eq(user_id))
+            .order_by(user_roles::id.asc())
+            .select(UserRoleEntity::as_select())
+            .load::<UserRoleEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn adventurer_id(&self, user_id: i32) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = adventurers::table
+            .filter(adventurers::user_id.eq(user_id))
+            .select(adventurers::id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn guild_commander_id(&self, user_id: i32) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guild_commanders::table
+            .filter(guild_commanders::user_id.eq(user_id))
+            .select(guild_commanders::id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn add_role(&self, user_id: i32, role: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            let username = users::table
+                .filter(users::id.eq(user_id))
+                .select(users::username)
+                .first::<String>(conn)?;
+
+            assign_role(conn, user_id, &username, &role)
+        })
+    }
+
+    async fn deactivate(&self, user_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(users::table)
+            .filter(users::id.eq(user_id))
+            .filter(users::deleted_at.is_null())
+            .set((
+                users::deactivated_at.eq(chrono::Utc::now().naive_utc()),
+                users::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("User not found"));
+        }
+
+        Ok(())
+    }
+
+    async fn reactivate(&self, user_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(users::table)
+            .filter(users::id.eq(user_id))
+            .set((
+                users::deactivated_at.eq(None::<chrono::NaiveDateTime>),
+                users::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn anonymize(&self, user_id: i32, username: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            let now = chrono::Utc::now().naive_utc();
+
+            let result = diesel::update(users::table)
+                .filter(users::id.eq(user_id))
+                .filter(users::deleted_at.is_null())
+                .set((
+                    users::username.eq(&username),
+                    users::password.eq(""),
+                    users::deleted_at.eq(now),
+                    users::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            if result == 0 {
+                return Err(anyhow::anyhow!("User not found"));
+            }
+
+            diesel::delete(notifications::table)
+                .filter(notifications::user_id.eq(user_id))
+                .execute(conn)?;
+
+            diesel::update(guild_commanders::table)
+                .filter(guild_commanders::user_id.eq(user_id))
+                .set((
+                    guild_commanders::username.eq(&username),
+                    guild_commanders::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            let adventurer_id = diesel::update(adventurers::table)
+                .filter(adventurers::user_id.eq(user_id))
+                .set((
+                    adventurers::username.eq(&username),
+                    adventurers::updated_at.eq(now),
+                ))
+                .returning(adventurers::id)
+                .get_result::<i32>(conn)
+                .optional()?;
+
+            let Some(adventurer_id) = adventurer_id else {
+                return Ok(());
+            };
+
+            // an adventurer already on the road stays in the crew's history
+            let open_quests = quest_adventurer_junction::table
+                .inner_join(quests::table)
+                .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+                .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+                .filter(quests::deleted_at.is_null())
+                .select((quests::id, quests::guild_id))
+                .load::<(i32, i32)>(conn)?;
+
+            for (quest_id, guild_id) in open_quests {
+                leave_quest(
+                    conn,
+                    quest_id,
+                    adventurer_id,
+                    &DomainEvent::AdventurerLeft {
+                        quest_id,
+                        guild_id,
+                        adventurer_id,
+                    },
+                )?;
+            }
+
+            diesel::delete(quest_waitlist_entries::table)
+                .filter(quest_waitlist_entries::adventurer_id.eq(adventurer_id))
+                .execute(conn)?;
+
+            diesel::update(quest_applications::table)
+                .filter(quest_applications::adventurer_id.eq(adventurer_id))
+                .filter(
+                    quest_applications::status.eq(QuestApplicationStatuses::Pending.to_string()),
+                )
+                .set((
+                    quest_applications::status.eq(QuestApplicationStatuses::Withdrawn.to_string()),
+                    quest_applications::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            diesel::update(quest_invitations::table)
+                .filter(quest_invitations::adventurer_id.eq(adventurer_id))
+                .filter(quest_invitations::status.eq(QuestInvitationStatuses::Pending.to_string()))
+                .set((
+                    quest_invitations::status.eq(QuestInvitationStatuses::Declined.to_string()),
+                    quest_invitations::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn export_data(&self, user_id: i32) -> Result<UserDataEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let adventurer = adventurers::table
+            .filter(adventurers::user_id.eq(user_id))
+            .select(AdventurerEntity::as_select())
+            .first::<AdventurerEntity>(&mut conn)
+            .optional()?;
+
+        let guild_commander_id = guild_commanders::table
+            .filter(guild_commanders::user_id.eq(user_id))
+            .select(guild_commanders::id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        let adventurer_id = adventurer.as_ref().map(|adventurer| adventurer.id);
+
+        let mut user_data = UserDataEntity {
+            adventurer,
+            ..Default::default()
+        };
+
+        if let Some(adventurer_id) = adventurer_id {
+            user_data.guild_memberships.extend(
+                guild_memberships::table
+                    .filter(guild_memberships::adventurer_id.eq(adventurer_id))
+                    .order_by(guild_memberships::id.asc())
+                    .select(GuildMembershipEntity::as_select())
+                    .load::<GuildMembershipEntity>(&mut conn)?,
+            );
+
+            user_data.crew = quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+                .order_by(quest_adventurer_junction::joined_at.asc())
+                .select(QuestAdventurerJunction::as_select())
+                .load::<QuestAdventurerJunction>(&mut conn)?;
+
+            user_data.quest_applications = quest_applications::table
+                .filter(quest_applications::adventurer_id.eq(adventurer_id))
+                .order_by(quest_applications::id.asc())
+                .select(QuestApplicationEntity::as_select())
+                .load::<QuestApplicationEntity>(&mut conn)?;
+
+            user_data.quest_invitations = quest_invitations::table
+                .filter(quest_invitations::adventurer_id.eq(adventurer_id))
+                .order_by(quest_invitations::id.asc())
+                .select(QuestInvitationEntity::as_select())
+                .load::<QuestInvitationEntity>(&mut conn)?;
+
+            user_data.quest_comments.extend(
+                quest_comments::table
+                    .filter(quest_comments::author_id.eq(adventurer_id))
+                    .filter(
+                        quest_comments::author_role
+                            .eq(QuestCommentAuthorRoles::Adventurer.to_string()),
+                    )
+                    .order_by(quest_comments::id.asc())
+                    .select(QuestCommentEntity::as_select())
+                    .load::<QuestCommentEntity>(&mut conn)?,
+            );
+
+            user_data.wallet_transactions = wallet_transactions::table
+                .filter(wallet_transactions::adventurer_id.eq(adventurer_id))
+                .order_by(wallet_transactions::id.asc())
+                .select(WalletTransactionEntity::as_select())
+                .load::<WalletTransactionEntity>(&mut conn)?;
+        }
+
+        if let Some(guild_commander_id) = guild_commander_id {
+            user_data.guild_memberships.extend(
+                guild_memberships::table
+                    .filter(guild_memberships::guild_commander_id.eq(guild_commander_id))
+                    .order_by(guild_memberships::id.asc())
+                    .select(GuildMembershipEntity::as_select())
+                    .load::<GuildMembershipEntity>(&mut conn)?,
+            );
+
+            user_data.quest_comments.extend(
+                quest_comments::table
+                    .filter(quest_comments::author_id.eq(guild_commander_id))
+                    .filter(
+                        quest_comments::author_role
+                            .eq(QuestCommentAuthorRoles::GuildCommander.to_string()),
+                    )
+                    .order_by(quest_comments::id.asc())
+                    .select(QuestCommentEntity::as_select())
+                    .load::<QuestCommentEntity>(&mut conn)?,
+            );
+
+            user_data.commanded_quest_ids = quests::table
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .order_by(quests::id.asc())
+                .select(quests::id)
+                .load::<i32>(&mut conn)?;
+        }
+
+        Ok(user_data)
+    }
+}
+
+// adventurer and guild commander assignments come with the profile row the quest tables refer to
+fn assign_role(
+    conn: &mut diesel::PgConnection,
+    user_id: i32,
+    username: &str,
+    role: &str,
+) -> Result<()> {
+    insert_into(user_roles::table)
+        .values(AddUserRoleEntity {
+            user_id,
+            role: role.to_string(),
+            created_at: chrono::Utc::now().naive_utc(),
+        })
+        .execute(conn)?;
+
+    match Roles::try_from_str(role)? {
+        Roles::Adventurer => {
+            insert_into(adventurers::table)
+                .values(RegisterAdventurerEntity {
+                    user_id,
+                    username: username.to_string(),
+                    created_at: chrono::Utc::now().naive_utc(),
+                    updated_at: chrono::Utc::now().naive_utc(),
+                })
+                .execute(conn)?;
+        }
+        Roles::GuildCommander => {
+            insert_into(guild_commanders::table)
+                .values(RegisterGuildCommanderEntity {
+                    user_id,
+                    username: username.to_string(),
+                    created_at: chrono::Utc::now().naive_utc(),
+                    updated_at: chrono::Utc::now().naive_utc(),
+                })
+                .execute(conn)?;
+        }
+        Roles::Admin => {}
+    }
+
+    Ok(())
+}
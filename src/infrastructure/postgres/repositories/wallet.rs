@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use diesel::{
+    dsl::sql, sql_types::BigInt, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+};
+
+use crate::{
+    domain::{
+        entities::wallet_transactions::WalletTransactionEntity,
+        repositories::wallet::WalletRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::wallet_transactions},
+};
+
+pub struct WalletPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl WalletPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl WalletRepository for WalletPostgres {
+    async fn balance(&self, adventurer_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // sum of BIGINT is NUMERIC in postgres, so cast it back before loading
+        let result = wallet_transactions::table
+            .filter(wallet_transactions::adventurer_id.eq(adventurer_id))
+            .select(sql::<BigInt>("COALESCE(SUM(amount), 0)::BIGINT"))
+            .first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn transactions(&self, adventurer_id: i32) -> Result<Vec<WalletTransactionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = wallet_transactions::table
+            .filter(wallet_transactions::adventurer_id.eq(adventurer_id))
+            .order_by(wallet_transactions::created_at.desc())
+            .then_order_by(wallet_transactions::id.desc())
+            .select(WalletTransactionEntity::as_select())
+            .load::<WalletTransactionEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+}
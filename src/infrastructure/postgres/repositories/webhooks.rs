@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{dsl::insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::{
+            webhook_deliveries::{AddWebhookDeliveryEntity, WebhookDeliveryEntity},
+            webhooks::{AddWebhookEntity, WebhookEntity},
+        },
+        repositories::webhooks::WebhooksRepository,
+        value_objects::webhook_delivery_statuses::WebhookDeliveryStatuses,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{webhook_deliveries, webhooks},
+    },
+};
+
+const DELIVERY_LOG_LIMIT: i64 = 100;
+
+pub struct WebhooksPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl WebhooksPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl WebhooksRepository for WebhooksPostgres {
+    async fn add(&self, add_webhook_entity: AddWebhookEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(webhooks::table)
+            .values(add_webhook_entity)
+            .returning(webhooks::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn find(&self, webhook_id: i32) -> Result<WebhookEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = webhooks::table
+            .filter(webhooks::id.eq(webhook_id))
+            .select(WebhookEntity::as_select())
+            .first::<WebhookEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Webhook not found"))?;
+
+        Ok(result)
+    }
+    async fn remove(&self, webhook_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::delete(webhooks::table)
+            .filter(webhooks::id.eq(webhook_id))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("Webhook not found"));
+        }
+
+        Ok(())
+    }
+    async fn by_guild_commander(&self, guild_commander_id: i32) -> Result<Vec<WebhookEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = webhooks::table
+            .filter(webhooks::guild_commander_id.eq(guild_commander_id))
+            .order_by(webhooks::id.asc())
+            .select(WebhookEntity::as_select())
+            .load::<WebhookEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn by_guild(&self, guild_id: i32) -> Result<Vec<WebhookEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = webhooks::table
+            .filter(webhooks::guild_id.eq(guild_id))
+            .order_by(webhooks::id.asc())
+            .select(WebhookEntity::as_select())
+            .load::<WebhookEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn enqueue_deliveries(
+        &self,
+        add_webhook_delivery_entities: Vec<AddWebhookDeliveryEntity>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(webhook_deliveries::table)
+            .values(&add_webhook_delivery_entities)
+            .on_conflict((
+                webhook_deliveries::webhook_id,
+                webhook_deliveries::delivery_id,
+            ))
+            .do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn deliveries(&self, webhook_id: i32) -> Result<Vec<WebhookDeliveryEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = webhook_deliveries::table
+            .filter(webhook_deliveries::webhook_id.eq(webhook_id))
+            .order_by(webhook_deliveries::id.desc())
+            .limit(DELIVERY_LOG_LIMIT)
+            .select(WebhookDeliveryEntity::as_select())
+            .load::<WebhookDeliveryEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn find_delivery(&self, webhook_id: i32, id: i32) -> Result<WebhookDeliveryEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = webhook_deliveries::table
+            .filter(webhook_deliveries::id.eq(id))
+            .filter(webhook_deliveries::webhook_id.eq(webhook_id))
+            .select(WebhookDeliveryEntity::as_select())
+            .first::<WebhookDeliveryEntity>(&mut conn)
+            .optional()?
+            .ok_or_else(|| anyhow::anyhow!("Delivery not found"))?;
+
+        Ok(result)
+    }
+    async fn redeliver(&self, id: i32, next_attempt_at: NaiveDateTime) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(webhook_deliveries::table)
+            .filter(webhook_deliveries::id.eq(id))
+            .filter(webhook_deliveries::status.ne(WebhookDeliveryStatuses::Pending.to_string()))
+            .set((
+                webhook_deliveries::status.eq(WebhookDeliveryStatuses::Pending.to_string()),
+                webhook_deliveries::attempts.eq(0),
+                webhook_deliveries::delivered_at.eq(None::<NaiveDateTime>),
+                webhook_deliveries::next_attempt_at.eq(next_attempt_at),
+                webhook_deliveries::updated_at.eq(next_attempt_at),
+            ))
+            .execute(&mut conn)?;
+
+        if result == 0 {
+            return Err(anyhow::anyhow!("The delivery is still pending"));
+        }
+
+        Ok(())
+    }
+    async fn claim_due_deliveries(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+        batch_size: i64,
+    ) -> Result<Vec<(WebhookDeliveryEntity, WebhookEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<Vec<(WebhookDeliveryEntity, WebhookEntity)>, anyhow::Error, _>(|conn| {
+            let deliveries = webhook_deliveries::table
+                .filter(webhook_deliveries::status.eq(WebhookDeliveryStatuses::Pending.to_string()))
+                .filter(webhook_deliveries::next_attempt_at.le(now))
+                .order_by(webhook_deliveries::id.asc())
+                .limit(batch_size)
+                .select(WebhookDeliveryEntity::as_select())
+                .for_update()
+                .skip_locked()
+                .load::<WebhookDeliveryEntity>(conn)?;
+
+            diesel::update(webhook_deliveries::table)
+                .filter(
+                    webhook_deliveries::id.eq_any(deliveries.iter().map(|delivery| delivery.id)),
+                )
+                .set(webhook_deliveries::next_attempt_at.eq(lease_until))
+                .execute(conn)?;
+
+            let webhooks = webhooks::table
+                .filter(webhooks::id.eq_any(deliveries.iter().map(|delivery| delivery.webhook_id)))
+                .select(WebhookEntity::as_select())
+                .load::<WebhookEntity>(conn)?;
+
+            // a cascade removes the deliveries of a removed webhook, so every one has its webhook
+            let result = deliveries
+                .into_iter()
+                .filter_map(|delivery| {
+                    webhooks
+                        .iter()
+                        .find(|webhook| webhook.id == delivery.webhook_id)
+                        .map(|webhook| (delivery, webhook.clone()))
+                })
+                .collect();
+
+            Ok(result)
+        })
+    }
+    async fn record_delivered(
+        &self,
+        id: i32,
+        response_status: i32,
+        delivered_at: NaiveDateTime,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(webhook_deliveries::table)
+            .filter(webhook_deliveries::id.eq(id))
+            .set((
+                webhook_deliveries::status.eq(WebhookDeliveryStatuses::Delivered.to_string()),
+                webhook_deliveries::attempts.eq(webhook_deliveries::attempts + 1),
+                webhook_deliveries::response_status.eq(response_status),
+                webhook_deliveries::last_error.eq(None::<String>),
+                webhook_deliveries::delivered_at.eq(delivered_at),
+                webhook_deliveries::updated_at.eq(delivered_at),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn record_retry(
+        &self,
+        id: i32,
+        response_status: Option<i32>,
+        next_attempt_at: NaiveDateTime,
+        last_error: String,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(webhook_deliveries::table)
+            .filter(webhook_deliveries::id.eq(id))
+            .set((
+                webhook_deliveries::attempts.eq(webhook_deliveries::attempts + 1),
+                webhook_deliveries::response_status.eq(response_status),
+                webhook_deliveries::last_error.eq(last_error),
+                webhook_deliveries::next_attempt_at.eq(next_attempt_at),
+                webhook_deliveries::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn record_failed(
+        &self,
+        id: i32,
+        response_status: Option<i32>,
+        last_error: String,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(webhook_deliveries::table)
+            .filter(webhook_deliveries::id.eq(id))
+            .set((
+                webhook_deliveries::status.eq(WebhookDeliveryStatuses::Failed.to_string()),
+                webhook_deliveries::attempts.eq(webhook_deliveries::attempts + 1),
+                webhook_deliveries::response_status.eq(response_status),
+                webhook_deliveries::last_error.eq(last_error),
+                webhook_deliveries::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
@@ -1,14 +1,42 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
+diesel::table! {
+    admin_audit_logs (id) {
+        id -> Int4,
+        admin_user_id -> Int4,
+        #[max_length = 255]
+        action -> Varchar,
+        #[max_length = 255]
+        target_type -> Varchar,
+        target_id -> Int4,
+        details -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    adventurer_levels (level) {
+        level -> Int4,
+        min_experience -> Int8,
+    }
+}
+
 diesel::table! {
     adventurers (id) {
         id -> Int4,
         #[max_length = 255]
         username -> Varchar,
-        #[max_length = 255]
-        password -> Varchar,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        experience -> Int8,
+        level -> Int4,
+        user_id -> Int4,
     }
 }
 
@@ -17,21 +45,238 @@ diesel::table! {
         id -> Int4,
         #[max_length = 255]
         username -> Varchar,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        user_id -> Int4,
+    }
+}
+
+diesel::table! {
+    guild_invites (id) {
+        id -> Int4,
+        guild_id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 64]
+        code -> Varchar,
+        max_uses -> Nullable<Int4>,
+        uses -> Int4,
+        expires_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    guild_memberships (id) {
+        id -> Int4,
+        guild_id -> Int4,
+        adventurer_id -> Nullable<Int4>,
+        guild_commander_id -> Nullable<Int4>,
         #[max_length = 255]
-        password -> Varchar,
+        role -> Varchar,
         created_at -> Timestamp,
         updated_at -> Timestamp,
     }
 }
 
+diesel::table! {
+    guilds (id) {
+        id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    notification_preferences (id) {
+        id -> Int4,
+        user_id -> Int4,
+        #[max_length = 255]
+        event_type -> Varchar,
+        enabled -> Bool,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    notifications (id) {
+        id -> Int4,
+        user_id -> Int4,
+        #[max_length = 255]
+        event_type -> Varchar,
+        quest_id -> Int4,
+        guild_id -> Int4,
+        message -> Text,
+        read_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        outbox_event_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    outbox (id) {
+        id -> Int4,
+        #[max_length = 255]
+        event_type -> Varchar,
+        payload -> Jsonb,
+        attempts -> Int4,
+        next_attempt_at -> Timestamp,
+        last_error -> Nullable<Text>,
+        delivered_at -> Nullable<Timestamp>,
+        failed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    outbox_subscriber_deliveries (outbox_event_id, subscriber) {
+        outbox_event_id -> Int4,
+        #[max_length = 255]
+        subscriber -> Varchar,
+        handled_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     quest_adventurer_junction (quest_id, adventurer_id) {
         quest_id -> Int4,
         adventurer_id -> Int4,
+        joined_at -> Timestamp,
+        #[max_length = 255]
+        role -> Nullable<Varchar>,
+        reward_weight -> Int4,
+    }
+}
+
+diesel::table! {
+    quest_applications (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        #[max_length = 255]
+        status -> Varchar,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
 diesel::table! {
+    quest_attempt_crew (attempt_id, adventurer_id) {
+        attempt_id -> Int4,
+        adventurer_id -> Int4,
+    }
+}
+
+diesel::table! {
+    quest_attempts (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        attempt_number -> Int4,
+        #[max_length = 255]
+        outcome -> Nullable<Varchar>,
+        started_at -> Timestamp,
+        ended_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    quest_comments (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        author_id -> Int4,
+        #[max_length = 255]
+        author_role -> Varchar,
+        body -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    quest_invitations (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 255]
+        status -> Varchar,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_milestones (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        position -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        required -> Bool,
+        completed_by -> Nullable<Int4>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_prerequisites (quest_id, prerequisite_id) {
+        quest_id -> Int4,
+        prerequisite_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_tags (quest_id, tag_id) {
+        quest_id -> Int4,
+        tag_id -> Int4,
+    }
+}
+
+diesel::table! {
+    quest_templates (id) {
+        id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        capacity -> Int4,
+        reward -> Int8,
+        tags -> Array<Text>,
+        #[max_length = 255]
+        recurrence -> Varchar,
+        #[max_length = 255]
+        cron_expression -> Nullable<Varchar>,
+        paused -> Bool,
+        next_run_at -> Timestamp,
+        last_run_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Nullable<Timestamp>,
+        guild_id -> Int4,
+    }
+}
+
+diesel::table! {
+    quest_waitlist_entries (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
     quests (id) {
         id -> Int4,
         #[max_length = 255]
@@ -43,16 +288,173 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        join_policy -> Varchar,
+        starts_at -> Nullable<Timestamp>,
+        due_at -> Nullable<Timestamp>,
+        reward -> Int8,
+        experience -> Int8,
+        experience_penalty -> Int8,
+        min_level -> Nullable<Int4>,
+        #[max_length = 255]
+        rank -> Varchar,
+        search_vector -> Nullable<Tsvector>,
+        max_attempts -> Nullable<Int4>,
+        capacity -> Int4,
+        guild_id -> Int4,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Int4,
+        #[max_length = 64]
+        name -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    user_roles (id) {
+        id -> Int4,
+        user_id -> Int4,
+        #[max_length = 255]
+        role -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        #[max_length = 255]
+        username -> Varchar,
+        #[max_length = 255]
+        password -> Varchar,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        disabled_at -> Nullable<Timestamp>,
+        deactivated_at -> Nullable<Timestamp>,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    wallet_transactions (id) {
+        id -> Int4,
+        adventurer_id -> Int4,
+        quest_id -> Nullable<Int4>,
+        amount -> Int8,
+        #[max_length = 255]
+        kind -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Int4,
+        #[max_length = 36]
+        delivery_id -> Varchar,
+        webhook_id -> Int4,
+        #[max_length = 255]
+        event_type -> Varchar,
+        payload -> Jsonb,
+        #[max_length = 255]
+        status -> Varchar,
+        attempts -> Int4,
+        next_attempt_at -> Timestamp,
+        response_status -> Nullable<Int4>,
+        last_error -> Nullable<Text>,
+        delivered_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    webhooks (id) {
+        id -> Int4,
+        guild_id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 2048]
+        url -> Varchar,
+        #[max_length = 255]
+        secret -> Varchar,
+        event_types -> Array<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
+diesel::joinable!(admin_audit_logs -> users (admin_user_id));
+diesel::joinable!(adventurers -> users (user_id));
+diesel::joinable!(guild_commanders -> users (user_id));
+diesel::joinable!(guild_invites -> guild_commanders (guild_commander_id));
+diesel::joinable!(guild_invites -> guilds (guild_id));
+diesel::joinable!(guild_memberships -> adventurers (adventurer_id));
+diesel::joinable!(guild_memberships -> guild_commanders (guild_commander_id));
+diesel::joinable!(guild_memberships -> guilds (guild_id));
+diesel::joinable!(notification_preferences -> users (user_id));
+diesel::joinable!(notifications -> quests (quest_id));
+diesel::joinable!(notifications -> users (user_id));
+diesel::joinable!(outbox_subscriber_deliveries -> outbox (outbox_event_id));
 diesel::joinable!(quest_adventurer_junction -> adventurers (adventurer_id));
 diesel::joinable!(quest_adventurer_junction -> quests (quest_id));
+diesel::joinable!(quest_applications -> adventurers (adventurer_id));
+diesel::joinable!(quest_applications -> quests (quest_id));
+diesel::joinable!(quest_attempt_crew -> adventurers (adventurer_id));
+diesel::joinable!(quest_attempt_crew -> quest_attempts (attempt_id));
+diesel::joinable!(quest_attempts -> quests (quest_id));
+diesel::joinable!(quest_comments -> quests (quest_id));
+diesel::joinable!(quest_invitations -> adventurers (adventurer_id));
+diesel::joinable!(quest_invitations -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_invitations -> quests (quest_id));
+diesel::joinable!(quest_milestones -> adventurers (completed_by));
+diesel::joinable!(quest_milestones -> quests (quest_id));
+diesel::joinable!(quest_tags -> quests (quest_id));
+diesel::joinable!(quest_tags -> tags (tag_id));
+diesel::joinable!(quest_waitlist_entries -> adventurers (adventurer_id));
+diesel::joinable!(quest_waitlist_entries -> quests (quest_id));
+diesel::joinable!(quest_templates -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_templates -> guilds (guild_id));
 diesel::joinable!(quests -> guild_commanders (guild_commander_id));
+diesel::joinable!(quests -> guilds (guild_id));
+diesel::joinable!(user_roles -> users (user_id));
+diesel::joinable!(wallet_transactions -> adventurers (adventurer_id));
+diesel::joinable!(wallet_transactions -> quests (quest_id));
+diesel::joinable!(webhook_deliveries -> webhooks (webhook_id));
+diesel::joinable!(webhooks -> guild_commanders (guild_commander_id));
+diesel::joinable!(webhooks -> guilds (guild_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    admin_audit_logs,
+    adventurer_levels,
     adventurers,
     guild_commanders,
+    guild_invites,
+    guild_memberships,
+    guilds,
+    notification_preferences,
+    notifications,
+    outbox,
+    outbox_subscriber_deliveries,
     quest_adventurer_junction,
+    quest_applications,
+    quest_attempt_crew,
+    quest_attempts,
+    quest_comments,
+    quest_invitations,
+    quest_milestones,
+    quest_prerequisites,
+    quest_tags,
+    quest_templates,
+    quest_waitlist_entries,
     quests,
+    tags,
+    user_roles,
+    users,
+    wallet_transactions,
+    webhook_deliveries,
+    webhooks,
 );
@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::{application::board_events::BoardEventsSender, infrastructure::postgres::board_events};
+
+// feeds the board streams of this replica from postgres, reconnecting after a lost connection
+pub async fn start(
+    database_url: String,
+    board_events: BoardEventsSender,
+    retry_interval: Duration,
+) {
+    info!(
+        "Board events listener is running on channel {}",
+        board_events::BOARD_EVENTS_CHANNEL
+    );
+
+    loop {
+        if let Err(e) = board_events::listen(&database_url, &board_events).await {
+            error!("🔴 Board events listener stopped: {}", e);
+        }
+
+        tokio::time::sleep(retry_interval).await;
+    }
+}
@@ -0,0 +1,5 @@
+pub mod board_events_listener;
+pub mod outbox_dispatcher;
+pub mod quest_deadlines;
+pub mod quest_templates;
+pub mod webhook_dispatcher;
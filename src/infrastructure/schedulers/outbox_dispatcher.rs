@@ -0,0 +1,43 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::{
+        clock::SystemClock, domain_events::DomainEventBus,
+        usecases::outbox_dispatcher::OutboxDispatcherUseCase,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad, repositories::outbox::OutboxPostgres,
+    },
+};
+
+pub async fn start(
+    db_pool: Arc<PgPoolSquad>,
+    domain_event_bus: Arc<DomainEventBus>,
+    interval: Duration,
+) {
+    let outbox_repository = OutboxPostgres::new(Arc::clone(&db_pool));
+
+    let outbox_dispatcher_use_case = OutboxDispatcherUseCase::new(
+        Arc::new(outbox_repository),
+        domain_event_bus,
+        Arc::new(SystemClock),
+    );
+
+    let mut ticker = tokio::time::interval(interval);
+
+    info!("Outbox dispatcher is running every {:?}", interval);
+
+    loop {
+        ticker.tick().await;
+
+        match outbox_dispatcher_use_case.dispatch_due().await {
+            Ok(outbox_event_ids) if !outbox_event_ids.is_empty() => {
+                info!("Outbox events delivered: {:?}", outbox_event_ids)
+            }
+            Ok(_) => {}
+            Err(e) => error!("🔴 Failed to dispatch outbox events: {}", e),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::{
+        clock::SystemClock,
+        usecases::{journey_ledger::JourneyLedgerUseCase, quest_deadlines::QuestDeadlinesUseCase},
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::{
+            journey_ledger::JourneyLedgerPostgres, quest_viewing::QuestViewingPostgres,
+        },
+    },
+};
+
+pub async fn start(db_pool: Arc<PgPoolSquad>, interval: Duration) {
+    let journey_ledger_repository = JourneyLedgerPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool)));
+
+    let journey_ledger_use_case = JourneyLedgerUseCase::new(
+        Arc::new(journey_ledger_repository),
+        Arc::clone(&quest_viewing_repository),
+    );
+
+    let quest_deadlines_use_case = QuestDeadlinesUseCase::new(
+        Arc::new(journey_ledger_use_case),
+        quest_viewing_repository,
+        Arc::new(SystemClock),
+    );
+
+    let mut ticker = tokio::time::interval(interval);
+
+    info!("Quest deadlines scheduler is running every {:?}", interval);
+
+    loop {
+        ticker.tick().await;
+
+        match quest_deadlines_use_case.fail_overdue_quests().await {
+            Ok(failed_quest_ids) if !failed_quest_ids.is_empty() => {
+                info!("Overdue quests moved to Failed: {:?}", failed_quest_ids)
+            }
+            Ok(_) => {}
+            Err(e) => error!("🔴 Failed to check overdue quests: {}", e),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::{
+        clock::SystemClock,
+        usecases::{quest_ops::QuestOpsUseCase, quest_templates::QuestTemplatesUseCase},
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::{
+            guilds::GuildsPostgres, quest_ops::QuestOpsPostgres,
+            quest_templates::QuestTemplatesPostgres, quest_viewing::QuestViewingPostgres,
+        },
+    },
+};
+
+pub async fn start(db_pool: Arc<PgPoolSquad>, interval: Duration) {
+    let quest_templates_repository = QuestTemplatesPostgres::new(Arc::clone(&db_pool));
+    let quest_ops_repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+    let guilds_repository = GuildsPostgres::new(Arc::clone(&db_pool));
+
+    let quest_ops_use_case = QuestOpsUseCase::new(
+        Arc::new(quest_ops_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(guilds_repository),
+    );
+
+    let quest_templates_use_case = QuestTemplatesUseCase::new(
+        Arc::new(quest_templates_repository),
+        Arc::new(quest_ops_use_case),
+        Arc::new(SystemClock),
+    );
+
+    let mut ticker = tokio::time::interval(interval);
+
+    info!("Quest templates scheduler is running every {:?}", interval);
+
+    loop {
+        ticker.tick().await;
+
+        match quest_templates_use_case.instantiate_due_templates().await {
+            Ok(quest_ids) if !quest_ids.is_empty() => {
+                info!("Quests posted from templates: {:?}", quest_ids)
+            }
+            Ok(_) => {}
+            Err(e) => error!("🔴 Failed to post quests from templates: {}", e),
+        }
+    }
+}
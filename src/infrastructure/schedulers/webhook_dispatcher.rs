@@ -0,0 +1,45 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::{clock::SystemClock, usecases::webhook_dispatcher::WebhookDispatcherUseCase},
+    infrastructure::{
+        postgres::{postgres_connection::PgPoolSquad, repositories::webhooks::WebhooksPostgres},
+        webhook_http::HttpWebhookSender,
+    },
+};
+
+pub async fn start(db_pool: Arc<PgPoolSquad>, interval: Duration) {
+    let webhooks_repository = WebhooksPostgres::new(Arc::clone(&db_pool));
+
+    let webhook_sender = match HttpWebhookSender::new() {
+        Ok(webhook_sender) => webhook_sender,
+        Err(e) => {
+            error!("🔴 Failed to build the webhook http client: {}", e);
+            return;
+        }
+    };
+
+    let webhook_dispatcher_use_case = WebhookDispatcherUseCase::new(
+        Arc::new(webhooks_repository),
+        Arc::new(webhook_sender),
+        Arc::new(SystemClock),
+    );
+
+    let mut ticker = tokio::time::interval(interval);
+
+    info!("Webhook dispatcher is running every {:?}", interval);
+
+    loop {
+        ticker.tick().await;
+
+        match webhook_dispatcher_use_case.dispatch_due().await {
+            Ok(delivery_ids) if !delivery_ids.is_empty() => {
+                info!("Webhook deliveries sent: {:?}", delivery_ids)
+            }
+            Ok(_) => {}
+            Err(e) => error!("🔴 Failed to dispatch webhook deliveries: {}", e),
+        }
+    }
+}
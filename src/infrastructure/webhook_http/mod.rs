@@ -0,0 +1,126 @@
+use std::{net::IpAddr, sync::Arc, time::Duration};
+
+use anyhow::Result;
+use axum::async_trait;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use url::{Host, Url};
+
+use crate::{
+    application::{webhook_host_resolver::WebhookHostResolver, webhook_sender::WebhookSender},
+    domain::value_objects::webhook_model::{
+        is_public_webhook_address, WebhookRequestModel, WEBHOOK_DELIVERY_HEADER,
+        WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMEOUT_SECONDS,
+        WEBHOOK_TIMESTAMP_HEADER,
+    },
+};
+
+pub struct DnsWebhookHostResolver;
+
+#[async_trait]
+impl WebhookHostResolver for DnsWebhookHostResolver {
+    async fn resolve(&self, host: &str, port: u16) -> Result<Vec<IpAddr>> {
+        let addresses = tokio::net::lookup_host((host, port))
+            .await?
+            .map(|address| address.ip())
+            .collect();
+
+        Ok(addresses)
+    }
+}
+
+// the client connects only to what this hands back, so a host that was public at registration
+// and points inside the network later is refused at send time
+impl Resolve for DnsWebhookHostResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addresses =
+                WebhookHostResolver::resolve(&DnsWebhookHostResolver, name.as_str(), 0).await?;
+
+            if addresses.is_empty() || !addresses.iter().all(is_public_webhook_address) {
+                return Err(anyhow::anyhow!(
+                    "The webhook host {} does not point at a public address",
+                    name.as_str()
+                )
+                .into());
+            }
+
+            let addrs: Addrs = Box::new(
+                addresses
+                    .into_iter()
+                    .map(|address| std::net::SocketAddr::new(address, 0)),
+            );
+
+            Ok(addrs)
+        })
+    }
+}
+
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+    public_only: bool,
+}
+
+impl HttpWebhookSender {
+    pub fn new() -> Result<Self> {
+        let client = Self::client_builder()
+            .https_only(true)
+            .dns_resolver(Arc::new(DnsWebhookHostResolver))
+            .build()?;
+
+        Ok(Self {
+            client,
+            public_only: true,
+        })
+    }
+
+    // the dispatcher tests post to a plain http receiver on 127.0.0.1
+    #[cfg(test)]
+    pub(crate) fn for_local_receiver() -> Result<Self> {
+        Ok(Self {
+            client: Self::client_builder().build()?,
+            public_only: false,
+        })
+    }
+
+    fn client_builder() -> reqwest::ClientBuilder {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECONDS))
+            // a receiver redirecting elsewhere would get the signed body without being asked
+            .redirect(reqwest::redirect::Policy::none())
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, webhook_request: WebhookRequestModel) -> Result<u16> {
+        // an IP literal never reaches the dns resolver, it is checked here instead
+        let ip = match Url::parse(&webhook_request.url)?.host() {
+            Some(Host::Ipv4(ip)) => Some(IpAddr::V4(ip)),
+            Some(Host::Ipv6(ip)) => Some(IpAddr::V6(ip)),
+            _ => None,
+        };
+
+        if self.public_only && ip.is_some_and(|ip| !is_public_webhook_address(&ip)) {
+            return Err(anyhow::anyhow!(
+                "The webhook url does not point at a public address"
+            ));
+        }
+
+        let response = self
+            .client
+            .post(&webhook_request.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_DELIVERY_HEADER, &webhook_request.delivery_id)
+            .header(WEBHOOK_EVENT_HEADER, &webhook_request.event_type)
+            .header(
+                WEBHOOK_TIMESTAMP_HEADER,
+                webhook_request.timestamp.to_string(),
+            )
+            .header(WEBHOOK_SIGNATURE_HEADER, &webhook_request.signature)
+            .body(webhook_request.body)
+            .send()
+            .await?;
+
+        Ok(response.status().as_u16())
+    }
+}
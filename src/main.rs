@@ -1,9 +1,30 @@
-use std::{process, sync::Arc};
+use std::{process, sync::Arc, time::Duration};
 
+use quests_tracker::domain::value_objects::board_event_model::BOARD_EVENTS_CAPACITY;
 use quests_tracker::{
+    application::{
+        board_events::BoardEventsBroadcaster,
+        domain_events::{DomainEventBus, DomainEventLogger, DomainEventSubscriber},
+        usecases::{
+            notifications::NotificationsUseCase, users::UsersUseCase, webhooks::WebhooksUseCase,
+        },
+    },
     config::config_loader,
-    infrastructure::{axum_http::http_serve::start, postgres::postgres_connection},
+    infrastructure::{
+        axum_http::http_serve::start,
+        postgres::{
+            board_events::BoardEventsNotifier,
+            postgres_connection,
+            repositories::{
+                guilds::GuildsPostgres, notifications::NotificationsPostgres, users::UsersPostgres,
+                webhooks::WebhooksPostgres,
+            },
+        },
+        schedulers,
+        webhook_http::DnsWebhookHostResolver,
+    },
 };
+use tokio::sync::broadcast;
 use tracing::{error, info};
 
 #[tokio::main]
@@ -33,7 +54,76 @@ async fn main() {
 
     info!("Postgres connection has been established");
 
-    start(Arc::new(dotenvy_env), Arc::new(postgres_pool))
+    let postgres_pool = Arc::new(postgres_pool);
+
+    if let Some(username) = dotenvy_env.admin.initial_username.clone() {
+        let users_use_case =
+            UsersUseCase::new(Arc::new(UsersPostgres::new(Arc::clone(&postgres_pool))));
+
+        match users_use_case.grant_initial_admin(username.clone()).await {
+            Ok(true) => info!("{} has been granted the Admin role", username),
+            Ok(false) => info!("{} already has the Admin role", username),
+            Err(e) => error!("🔴 Failed to grant the Admin role to {}: {}", username, e),
+        }
+    }
+
+    let webhooks_use_case = WebhooksUseCase::new(
+        Arc::new(WebhooksPostgres::new(Arc::clone(&postgres_pool))),
+        Arc::new(GuildsPostgres::new(Arc::clone(&postgres_pool))),
+        Arc::new(DnsWebhookHostResolver),
+    );
+
+    let notifications_use_case = NotificationsUseCase::new(Arc::new(NotificationsPostgres::new(
+        Arc::clone(&postgres_pool),
+    )));
+
+    let (board_events, _) = broadcast::channel(BOARD_EVENTS_CAPACITY);
+
+    // with several replicas every one of them has to stream the events the others dispatched
+    let board_events_subscriber: Arc<dyn DomainEventSubscriber + Send + Sync> =
+        if dotenvy_env.board_events.notify {
+            tokio::spawn(schedulers::board_events_listener::start(
+                dotenvy_env.database.url.clone(),
+                board_events.clone(),
+                Duration::from_secs(dotenvy_env.scheduler.interval),
+            ));
+
+            Arc::new(BoardEventsNotifier::new(Arc::clone(&postgres_pool)))
+        } else {
+            Arc::new(BoardEventsBroadcaster::new(board_events.clone()))
+        };
+
+    // subscribers plug in here, they are fed from the outbox by the dispatcher
+    let domain_event_bus = Arc::new(
+        DomainEventBus::new()
+            .subscribe("logger", Arc::new(DomainEventLogger))
+            .subscribe("webhooks", Arc::new(webhooks_use_case))
+            .subscribe("notifications", Arc::new(notifications_use_case))
+            .subscribe("board_events", board_events_subscriber),
+    );
+
+    tokio::spawn(schedulers::quest_deadlines::start(
+        Arc::clone(&postgres_pool),
+        Duration::from_secs(dotenvy_env.scheduler.interval),
+    ));
+
+    tokio::spawn(schedulers::quest_templates::start(
+        Arc::clone(&postgres_pool),
+        Duration::from_secs(dotenvy_env.scheduler.interval),
+    ));
+
+    tokio::spawn(schedulers::outbox_dispatcher::start(
+        Arc::clone(&postgres_pool),
+        domain_event_bus,
+        Duration::from_secs(dotenvy_env.outbox.interval),
+    ));
+
+    tokio::spawn(schedulers::webhook_dispatcher::start(
+        Arc::clone(&postgres_pool),
+        Duration::from_secs(dotenvy_env.webhook.interval),
+    ));
+
+    start(Arc::new(dotenvy_env), postgres_pool, board_events)
         .await
         .expect("🔴 Failed to start server")
 }